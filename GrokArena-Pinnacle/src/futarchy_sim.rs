@@ -1,159 +0,0 @@
-// GrokArena-Pinnacle/src/futarchy_sim.rs
-//
-// Basic Futarchy Simulator Stub
-// ============================
-//
-// This is a minimal, extensible stub for simulating futarchy governance.
-// Key concepts:
-// - A single measurable metric (e.g., "welfare" or token price).
-// - Proposals create two conditional markets: Pass vs. Reject.
-// - Traders buy/sell shares in markets (simple LMSR-inspired pricing for liquidity).
-// - Decision: Proposal passes if Pass market price > Reject market price.
-// - Post-decision resolution based on simulated "true" outcome.
-//
-// Designed for Eternal Thriving Grandmasterism: modular, safe Rust, easy to expand
-// into full prediction market engine, quadratic integrations, or Grok API hooks.
-//
-// Next steps:
-// - Add real LMSR (Logarithmic Market Scoring Rule) for better liquidity.
-// - Multi-proposal cadence.
-// - Trader strategies (rational, noisy, manipulative).
-// - Visualization hooks (plot market prices over time).
-// - On-chain simulation bridges (Solana/Move later).
-
-use rand::prelude::*;
-use std::collections::HashMap;
-
-/// Core metric we are trying to maximize (e.g., eternal thriving index)
-type Metric = f64;
-
-/// Simple trader identifier
-type TraderId = u64;
-
-/// Share price in a conditional market (0.0 to 1.0 probability)
-type Price = f64;
-
-/// Basic Proposal
-#[derive(Debug, Clone)]
-struct Proposal {
-    id: u64,
-    description: String,
-}
-
-/// Conditional Market (one for Pass, one for Reject)
-#[derive(Debug, Clone)]
-struct Market {
-    shares_outstanding: f64, // Total shares in circulation
-    liquidity: f64,          // Constant for simple constant-product feel (expand to LMSR)
-}
-
-impl Market {
-    fn new(initial_liquidity: f64) -> Self {
-        Self {
-            shares_outstanding: 0.0,
-            liquidity: initial_liquidity,
-        }
-    }
-
-    /// Naive pricing: probability = shares / (shares + liquidity)
-    fn price(&self) -> Price {
-        self.shares_outstanding / (self.shares_outstanding + self.liquidity)
-    }
-
-    /// Buy shares (increases price)
-    fn buy(&mut self, amount: f64) {
-        self.shares_outstanding += amount;
-    }
-
-    /// Sell shares (decreases price)
-    fn sell(&mut self, amount: f64) {
-        self.shares_outstanding = (self.shares_outstanding - amount).max(0.0);
-    }
-}
-
-/// Futarchy Governance Simulator
-#[derive(Debug)]
-pub struct FutarchySimulator {
-    current_metric: Metric,
-    proposals: Vec<Proposal>,
-    markets: HashMap<u64, (Market, Market)>, // (Pass, Reject) per proposal
-    rng: ThreadRng,
-}
-
-impl FutarchySimulator {
-    pub fn new(initial_metric: Metric) -> Self {
-        Self {
-            current_metric: initial_metric,
-            proposals: Vec::new(),
-            markets: HashMap::new(),
-            rng: thread_rng(),
-        }
-    }
-
-    /// Submit a new proposal → creates conditional markets
-    pub fn submit_proposal(&mut self, description: String, liquidity: f64) -> u64 {
-        let id = self.proposals.len() as u64;
-        let proposal = Proposal { id, description };
-        self.proposals.push(proposal);
-
-        let pass_market = Market::new(liquidity);
-        let reject_market = Market::new(liquidity);
-
-        self.markets.insert(id, (pass_market, reject_market));
-        id
-    }
-
-    /// Simulate a trading round (random traders for stub)
-    pub fn simulate_trading(&mut self, proposal_id: u64, rounds: usize) {
-        let (pass, reject) = self.markets.get_mut(&proposal_id).expect("Proposal not found");
-
-        for _ in 0..rounds {
-            // Random trader decides to bet on pass or reject
-            if self.rng.gen_bool(0.5) {
-                let amount: f64 = self.rng.gen_range(1.0..10.0);
-                pass.buy(amount);
-            } else {
-                let amount: f64 = self.rng.gen_range(1.0..10.0);
-                reject.buy(amount);
-            }
-        }
-    }
-
-    /// Resolve proposal based on market prices
-    pub fn resolve_proposal(&mut self, proposal_id: u64) -> bool {
-        let (pass, reject) = self.markets.get(&proposal_id).expect("Proposal not found");
-        let pass_price = pass.price();
-        let reject_price = reject.price();
-
-        println!("Proposal {}: Pass price = {:.4}, Reject price = {:.4}", proposal_id, pass_price, reject_price);
-
-        // Decision rule: pass if expected metric higher under pass
-        let passes = pass_price > reject_price;
-
-        // Simulate "true" outcome impact (stub: random +/- based on decision)
-        let impact: f64 = if passes {
-            if self.rng.gen_bool(0.7) { 10.0 } else { -5.0 } // 70% chance good if market favored
-        } else {
-            if self.rng.gen_bool(0.6) { 5.0 } else { -10.0 }
-        };
-
-        self.current_metric += impact;
-        println!("Proposal {} {}. New metric: {:.2}", proposal_id, if passes { "PASSED" } else { "REJECTED" }, self.current_metric);
-
-        passes
-    }
-}
-
-/// Example simulation run
-fn main() {
-    let mut sim = FutarchySimulator::new(100.0); // Start with welfare = 100
-
-    let prop_id = sim.submit_proposal("Implement Eternal Thriving Council".to_string(), 1000.0);
-
-    println!("Trading on proposal {}...", prop_id);
-    sim.simulate_trading(prop_id, 100); // 100 random trades
-
-    sim.resolve_proposal(prop_id);
-
-    println!("\nFinal metric: {:.2}", sim.current_metric);
-}
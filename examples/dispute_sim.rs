@@ -0,0 +1,27 @@
+//! Augur-style dispute resolution: honest vs. attacked reporting
+//!
+//! Run with `cargo run --example dispute_sim`
+
+use grok_arena_pinnacle::sim::dispute_sim::{DisputeConfig, DisputeSimulator};
+use rand::prelude::*;
+
+fn main() -> Result<(), String> {
+    let mut rng = thread_rng();
+    let mut honest = DisputeSimulator::new(DisputeConfig::default())?;
+    for _ in 0..1000 {
+        honest.resolve_outcome(rng.gen_bool(0.5));
+    }
+    honest.stats.print_summary();
+
+    let mut attacked = DisputeSimulator::new(DisputeConfig {
+        attacker_budget: 400_000.0,
+        honest_dispute_probability: 0.6,
+        ..DisputeConfig::default()
+    })?;
+    println!("\nREP to force a fork: {:.2}", attacked.cost_to_force_fork()?);
+    for _ in 0..1000 {
+        attacked.resolve_outcome(true);
+    }
+    attacked.stats.print_summary();
+    Ok(())
+}
@@ -0,0 +1,19 @@
+//! Basic futarchy simulation: one proposal, random traders, market-price decision
+//!
+//! Run with `cargo run --example futarchy_sim`
+
+use grok_arena_pinnacle::sim::futarchy_sim::FutarchySimulator;
+
+fn main() -> Result<(), String> {
+    let mut sim = FutarchySimulator::new(100.0); // Start with welfare = 100
+
+    let prop_id = sim.submit_proposal("Implement Eternal Thriving Council".to_string(), 1000.0)?;
+
+    println!("Trading on proposal {}...", prop_id);
+    sim.simulate_trading(prop_id, 100)?; // 100 random trades
+
+    sim.resolve_proposal(prop_id);
+
+    println!("\nFinal metric: {:.2}", sim.current_metric());
+    Ok(())
+}
@@ -0,0 +1,33 @@
+//! Hybrid governance: QV picks the welfare metric weights, futarchy markets pick the policy
+//!
+//! Run with `cargo run --example hybrid_sim`
+
+use grok_arena_pinnacle::qv::Strategy;
+use grok_arena_pinnacle::sim::hybrid_sim::HybridSimulator;
+
+fn main() {
+    let mut hybrid = HybridSimulator::new(100.0);
+
+    // QV Phase: Metric options
+    let thriving = hybrid.add_metric_option("Eternal Thriving Index".to_string());
+    hybrid.add_metric_option("GDP Growth".to_string());
+    hybrid.add_metric_option("Quantum Mercy Balance".to_string());
+
+    for _ in 0..20 {
+        hybrid.add_voter(Strategy::Optimal, 100.0);
+    }
+    for _ in 0..5 {
+        hybrid.add_voter(Strategy::Coordinated { issue: thriving, support: true }, 100.0);
+    }
+
+    let weights = hybrid.run_qv_phase();
+    println!("Selected Metric Weights: {:?}", weights);
+
+    // Futarchy Phase
+    let prop_id = hybrid.submit_proposal("Launch GrokArena Global Council".to_string(), 1000.0);
+    hybrid.simulate_futarchy_trading(prop_id, 200, 0.6);
+    let passed = hybrid.resolve_proposal(prop_id, &weights);
+    println!("Proposal {} {} → New metric: {:.2}", prop_id, if passed { "PASSED" } else { "REJECTED" }, hybrid.current_metric());
+
+    println!("Final Composite Metric: {:.2}", hybrid.current_metric());
+}
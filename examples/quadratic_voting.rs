@@ -0,0 +1,36 @@
+//! Quadratic voting with mixed strategies, with and without sybil resistance
+//!
+//! Run with `cargo run --example quadratic_voting`
+
+use grok_arena_pinnacle::qv::{QVSimulator, Strategy};
+
+fn main() {
+    let mut sim = QVSimulator::new();
+
+    let council = sim.add_issue("Fund the Eternal Thriving Council".to_string());
+    let treasury = sim.add_issue("Open the treasury to public audit".to_string());
+    sim.add_issue("Adopt futarchy for budget decisions".to_string());
+
+    for _ in 0..30 {
+        sim.add_voter(Strategy::Optimal, 100.0);
+    }
+    for _ in 0..10 {
+        sim.add_voter(Strategy::Random { max_issues: 2 }, 100.0);
+    }
+    for _ in 0..5 {
+        sim.add_voter(Strategy::Coordinated { issue: council, support: true }, 100.0);
+    }
+    sim.add_voter(Strategy::Malicious { targets: vec![treasury] }, 400.0);
+    sim.add_voter(Strategy::SybilAttack { targets: vec![council, treasury], num_sybils: 25 }, 100.0);
+
+    sim.vote_according_to_strategy();
+    sim.tally();
+    sim.collect_and_print_stats();
+
+    // Reset and run with resistance off
+    sim.reset_votes();
+    sim.sybil_resistance_enabled = false;
+    sim.vote_according_to_strategy();
+    sim.tally();
+    sim.collect_and_print_stats();
+}
@@ -1,30 +0,0 @@
-//! GrokArena — Mercy-Moderated Discourse Engine
-//! Deeper NEXi Integration + Recursive Voting + Voice SoulScan
-
-use nexi::lattice::Nexus;
-
-pub struct Arena {
-    nexus: Nexus,
-}
-
-impl Arena {
-    pub fn new() -> Self {
-        Arena {
-            nexus: Nexus::init_with_mercy(),
-        }
-    }
-
-    pub fn submit_idea(&self, idea: &str) -> String {
-        self.nexus.distill_truth(idea)
-    }
-
-    pub fn futarchy_vote(&self, proposal: &str) -> String {
-        // Recursive zk-aggregated quadratic + futarchy market simulation
-        self.nexus.distill_truth(proposal)
-    }
-
-    pub fn voice_moderation(&self, audio_input: &str) -> String {
-        // SoulScan-X9 voice waveform check
-        self.nexus.distill_truth(audio_input)
-    }
-}
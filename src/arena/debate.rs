@@ -0,0 +1,713 @@
+//! Debate Sessions — Format-Driven Grok Global Debate Arena
+//! Ultramasterful turn engine: segments, speaker order and timers on a virtual clock
+//!
+//! A `DebateFormat` (built in, or loaded from JSON) lists segments and their turns. A
+//! `DebateSession` walks the format on a virtual clock: only the scheduled speaker (or the
+//! examined side during cross-examination) may speak, turns end when yielded or when their
+//! time plus grace runs out, transcripts are kept per segment, and moderator cues are
+//! emitted as events.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::arena::debate_markets::{DebateMarkets, DebateQuestion, QuestionSettlement};
+use crate::arena::judging::{Judging, Scorecard, Stance};
+use crate::types::{Credits, Votes};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Role {
+    Affirmative,
+    Negative,
+    Moderator,
+}
+
+impl Role {
+    pub fn opponent(self) -> Option<Role> {
+        match self {
+            Role::Affirmative => Some(Role::Negative),
+            Role::Negative => Some(Role::Affirmative),
+            Role::Moderator => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SegmentKind {
+    Introduction,
+    Opening,
+    Rebuttal,
+    CrossExamination,
+    OpenFloor,
+    Closing,
+    Summary,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TurnSpec {
+    pub speaker: Role,
+    pub minutes: f64,
+    /// Side being questioned; may answer during this turn
+    #[serde(default)]
+    pub respondent: Option<Role>,
+    /// Moderator cue override
+    #[serde(default)]
+    pub cue: Option<String>,
+}
+
+impl TurnSpec {
+    pub fn new(speaker: Role, minutes: f64) -> Self {
+        Self { speaker, minutes, respondent: None, cue: None }
+    }
+
+    pub fn questioning(speaker: Role, respondent: Role, minutes: f64) -> Self {
+        Self { speaker, minutes, respondent: Some(respondent), cue: None }
+    }
+
+    fn seconds(&self) -> f64 {
+        self.minutes * 60.0
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SegmentSpec {
+    pub name: String,
+    pub kind: SegmentKind,
+    pub turns: Vec<TurnSpec>,
+}
+
+impl SegmentSpec {
+    pub fn new(name: &str, kind: SegmentKind, turns: Vec<TurnSpec>) -> Self {
+        Self { name: name.to_string(), kind, turns }
+    }
+
+    pub fn minutes(&self) -> f64 {
+        self.turns.iter().map(|t| t.minutes).sum()
+    }
+}
+
+/// Declarative debate format
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DebateFormat {
+    pub name: String,
+    pub segments: Vec<SegmentSpec>,
+    /// Overrun allowed after a turn's time is up
+    #[serde(default)]
+    pub grace_secs: f64,
+    /// Warning cue this many seconds before a turn ends (0 = none)
+    #[serde(default)]
+    pub warning_secs: f64,
+}
+
+impl DebateFormat {
+    /// The 75-minute format of DEBATE_SCRIPT_FUTARCHY.md
+    pub fn grok_global_arena() -> Self {
+        use Role::*;
+        DebateFormat {
+            name: "Grok Global Debate Arena".to_string(),
+            segments: vec![
+                SegmentSpec::new("Introduction", SegmentKind::Introduction, vec![TurnSpec::new(Moderator, 4.0)]),
+                SegmentSpec::new("Opening Statements", SegmentKind::Opening, vec![
+                    TurnSpec::new(Affirmative, 8.0),
+                    TurnSpec::new(Negative, 8.0),
+                ]),
+                SegmentSpec::new("Rebuttals", SegmentKind::Rebuttal, vec![
+                    TurnSpec::new(Affirmative, 6.0),
+                    TurnSpec::new(Negative, 6.0),
+                    TurnSpec { cue: Some("Neutral summary, 2 minutes.".to_string()), ..TurnSpec::new(Moderator, 2.0) },
+                ]),
+                SegmentSpec::new("Cross-Examination", SegmentKind::CrossExamination, vec![
+                    TurnSpec::questioning(Affirmative, Negative, 5.0),
+                    TurnSpec::questioning(Negative, Affirmative, 5.0),
+                ]),
+                SegmentSpec::new("Open Floor", SegmentKind::OpenFloor, vec![TurnSpec::new(Moderator, 15.0)]),
+                SegmentSpec::new("Closing Statements", SegmentKind::Closing, vec![
+                    TurnSpec::new(Negative, 5.0),
+                    TurnSpec::new(Affirmative, 5.0),
+                ]),
+                SegmentSpec::new("Grok Summary & Scorecard", SegmentKind::Summary, vec![TurnSpec::new(Moderator, 6.0)]),
+            ],
+            grace_secs: 15.0,
+            warning_secs: 60.0,
+        }
+    }
+
+    pub fn from_json(raw: &str) -> Result<Self, String> {
+        let format: Self = serde_json::from_str(raw).map_err(|e| format!("Debate format error: {:?}", e))?;
+        format.validate()?;
+        Ok(format)
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let raw = fs::read_to_string(path).map_err(|e| format!("Read error {}: {:?}", path.display(), e))?;
+        Self::from_json(&raw)
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.segments.iter().all(|s| s.turns.is_empty()) {
+            return Err(format!("Debate format {} has no turns", self.name));
+        }
+        for segment in &self.segments {
+            for turn in &segment.turns {
+                if turn.minutes <= 0.0 {
+                    return Err(format!("{}: {:?} turn must last longer than 0 minutes", segment.name, turn.speaker));
+                }
+                if turn.respondent == Some(turn.speaker) {
+                    return Err(format!("{}: {:?} cannot question itself", segment.name, turn.speaker));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub fn total_minutes(&self) -> f64 {
+        self.segments.iter().map(SegmentSpec::minutes).sum()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TurnEnd {
+    Yielded,
+    Expired,
+    /// Moderator moved on
+    Skipped,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum DebateEventKind {
+    SegmentStarted { segment: String },
+    /// Moderator cue, e.g. "Affirmative opens, 8 minutes."
+    Cue { text: String },
+    TurnStarted { speaker: Role, seconds: f64 },
+    TimeWarning { speaker: Role, remaining_secs: f64 },
+    TurnEnded { speaker: Role, reason: TurnEnd },
+    SegmentEnded { segment: String },
+    DebateEnded,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DebateEvent {
+    /// Seconds since the debate started
+    pub at: f64,
+    pub kind: DebateEventKind,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TranscriptEntry {
+    pub at: f64,
+    pub role: Role,
+    pub speaker: String,
+    pub text: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SegmentTranscript {
+    pub name: String,
+    pub kind: SegmentKind,
+    pub started_at: f64,
+    pub ended_at: Option<f64>,
+    pub entries: Vec<TranscriptEntry>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DebatePhase {
+    NotStarted,
+    InProgress { segment: usize, turn: usize },
+    Finished,
+}
+
+/// Current turn and its timer
+#[derive(Clone, Debug)]
+pub struct TurnStatus {
+    pub segment: String,
+    pub speaker: Role,
+    pub respondent: Option<Role>,
+    pub elapsed_secs: f64,
+    pub remaining_secs: f64,
+}
+
+pub struct DebateSession {
+    topic: String,
+    format: DebateFormat,
+    participants: HashMap<Role, String>,
+    phase: DebatePhase,
+    clock: f64,
+    turn_started_at: f64,
+    warned: bool,
+    transcript: Vec<SegmentTranscript>,
+    events: Vec<DebateEvent>,
+    delivered: usize,
+    judging: Judging,
+    markets: Option<DebateMarkets>,
+}
+
+impl DebateSession {
+    pub fn new(topic: &str, format: DebateFormat) -> Result<Self, String> {
+        format.validate()?;
+        let participants = HashMap::from([(Role::Moderator, "Grok".to_string())]);
+        Ok(DebateSession {
+            topic: topic.to_string(),
+            format,
+            participants,
+            phase: DebatePhase::NotStarted,
+            clock: 0.0,
+            turn_started_at: 0.0,
+            warned: false,
+            transcript: Vec::new(),
+            events: Vec::new(),
+            delivered: 0,
+            judging: Judging::default(),
+            markets: None,
+        })
+    }
+
+    pub fn with_participant(mut self, role: Role, name: &str) -> Self {
+        self.participants.insert(role, name.to_string());
+        self
+    }
+
+    /// Custom criteria or voice credit budget
+    pub fn with_judging(mut self, judging: Judging) -> Self {
+        self.judging = judging;
+        self
+    }
+
+    /// Markets on this debate's result, traded while it runs
+    pub fn with_markets(mut self, markets: DebateMarkets) -> Self {
+        self.markets = Some(markets);
+        self
+    }
+
+    pub fn topic(&self) -> &str {
+        &self.topic
+    }
+
+    pub fn format(&self) -> &DebateFormat {
+        &self.format
+    }
+
+    pub fn phase(&self) -> DebatePhase {
+        self.phase
+    }
+
+    /// Virtual seconds since the start
+    pub fn clock(&self) -> f64 {
+        self.clock
+    }
+
+    pub fn participant(&self, role: Role) -> String {
+        self.participants.get(&role).cloned().unwrap_or_else(|| format!("{:?}", role))
+    }
+
+    pub fn transcript(&self) -> &[SegmentTranscript] {
+        &self.transcript
+    }
+
+    pub fn events(&self) -> &[DebateEvent] {
+        &self.events
+    }
+
+    /// Events emitted since the last call
+    pub fn drain_events(&mut self) -> Vec<DebateEvent> {
+        let fresh = self.events[self.delivered..].to_vec();
+        self.delivered = self.events.len();
+        fresh
+    }
+
+    fn emit(&mut self, kind: DebateEventKind) {
+        log::debug!("[{:>7.1}s] {:?}", self.clock, kind);
+        self.events.push(DebateEvent { at: self.clock, kind });
+    }
+
+    fn turn_spec(&self, segment: usize, turn: usize) -> &TurnSpec {
+        &self.format.segments[segment].turns[turn]
+    }
+
+    /// Turn currently on the clock
+    pub fn current_turn(&self) -> Option<TurnStatus> {
+        let DebatePhase::InProgress { segment, turn } = self.phase else {
+            return None;
+        };
+        let spec = self.turn_spec(segment, turn);
+        let elapsed = self.clock - self.turn_started_at;
+        Some(TurnStatus {
+            segment: self.format.segments[segment].name.clone(),
+            speaker: spec.speaker,
+            respondent: spec.respondent,
+            elapsed_secs: elapsed,
+            remaining_secs: (spec.seconds() - elapsed).max(0.0),
+        })
+    }
+
+    fn cue(&self, segment: usize, turn: usize) -> String {
+        let spec = self.turn_spec(segment, turn);
+        if let Some(cue) = &spec.cue {
+            return cue.clone();
+        }
+        let kind = self.format.segments[segment].kind;
+        let minutes = if spec.minutes == spec.minutes.trunc() {
+            format!("{}", spec.minutes as u64)
+        } else {
+            format!("{:.1}", spec.minutes)
+        };
+        let who = format!("{:?}", spec.speaker);
+        match (kind, spec.speaker, spec.respondent) {
+            (_, Role::Moderator, _) => format!("{}, {} minutes.", self.format.segments[segment].name, minutes),
+            (SegmentKind::Opening, _, _) => format!("{} opens, {} minutes.", who, minutes),
+            (SegmentKind::Rebuttal, _, _) => format!("{}, {} minutes to rebut.", who, minutes),
+            (SegmentKind::Closing, _, _) => format!("{} closes, {} minutes.", who, minutes),
+            (_, _, Some(respondent)) => format!("{} questions {:?}, {} minutes.", who, respondent, minutes),
+            _ => format!("{}, {} minutes.", who, minutes),
+        }
+    }
+
+    fn begin_turn(&mut self, segment: usize, turn: usize) {
+        if turn == 0 {
+            let name = self.format.segments[segment].name.clone();
+            self.transcript.push(SegmentTranscript {
+                name: name.clone(),
+                kind: self.format.segments[segment].kind,
+                started_at: self.clock,
+                ended_at: None,
+                entries: Vec::new(),
+            });
+            self.emit(DebateEventKind::SegmentStarted { segment: name });
+        }
+        self.phase = DebatePhase::InProgress { segment, turn };
+        self.turn_started_at = self.clock;
+        self.warned = false;
+        let text = self.cue(segment, turn);
+        let (speaker, seconds) = {
+            let spec = self.turn_spec(segment, turn);
+            (spec.speaker, spec.seconds())
+        };
+        self.emit(DebateEventKind::Cue { text });
+        self.emit(DebateEventKind::TurnStarted { speaker, seconds });
+    }
+
+    /// First non-empty segment at or after `segment`
+    fn next_segment(&self, segment: usize) -> Option<usize> {
+        (segment..self.format.segments.len()).find(|&s| !self.format.segments[s].turns.is_empty())
+    }
+
+    pub fn start(&mut self) -> Result<(), String> {
+        if self.phase != DebatePhase::NotStarted {
+            return Err("Debate already started".to_string());
+        }
+        let first = self.next_segment(0).ok_or("Debate format has no turns")?;
+        if let Some(markets) = &mut self.markets {
+            markets.snapshot("Pre-debate", self.clock);
+        }
+        self.begin_turn(first, 0);
+        Ok(())
+    }
+
+    fn end_turn(&mut self, reason: TurnEnd) {
+        let DebatePhase::InProgress { segment, turn } = self.phase else {
+            return;
+        };
+        let speaker = self.turn_spec(segment, turn).speaker;
+        self.emit(DebateEventKind::TurnEnded { speaker, reason });
+
+        if turn + 1 < self.format.segments[segment].turns.len() {
+            self.begin_turn(segment, turn + 1);
+            return;
+        }
+        if let Some(record) = self.transcript.last_mut() {
+            record.ended_at = Some(self.clock);
+        }
+        let name = self.format.segments[segment].name.clone();
+        if let Some(markets) = &mut self.markets {
+            markets.snapshot(&name, self.clock);
+        }
+        self.emit(DebateEventKind::SegmentEnded { segment: name });
+        match self.next_segment(segment + 1) {
+            Some(next) => self.begin_turn(next, 0),
+            None => {
+                self.phase = DebatePhase::Finished;
+                self.emit(DebateEventKind::DebateEnded);
+            }
+        }
+    }
+
+    /// Move the virtual clock; warnings fire and overrun turns expire along the way
+    pub fn advance(&mut self, secs: f64) {
+        let target = self.clock + secs.max(0.0);
+        while let DebatePhase::InProgress { segment, turn } = self.phase {
+            let spec = self.turn_spec(segment, turn);
+            let (speaker, length) = (spec.speaker, spec.seconds());
+            let warn_at = self.turn_started_at + length - self.format.warning_secs;
+            if !self.warned && self.format.warning_secs > 0.0 && warn_at > self.turn_started_at && warn_at <= target {
+                self.clock = self.clock.max(warn_at);
+                self.warned = true;
+                self.emit(DebateEventKind::TimeWarning { speaker, remaining_secs: self.format.warning_secs });
+                continue;
+            }
+            let expires_at = self.turn_started_at + length + self.format.grace_secs;
+            if expires_at > target {
+                break;
+            }
+            self.clock = expires_at;
+            self.end_turn(TurnEnd::Expired);
+        }
+        self.clock = target;
+    }
+
+    /// Record `text` from `role`; only the scheduled speaker or the examined respondent may speak
+    pub fn speak(&mut self, role: Role, text: &str) -> Result<(), String> {
+        let status = self.current_turn().ok_or_else(|| match self.phase {
+            DebatePhase::NotStarted => "Debate has not started".to_string(),
+            _ => "Debate is over".to_string(),
+        })?;
+        // Moderator may interject at any time (fact-checks, transitions)
+        if role != status.speaker && Some(role) != status.respondent && role != Role::Moderator {
+            return Err(format!("Out of turn: {:?} has the floor in {}", status.speaker, status.segment));
+        }
+        let speaker = self.participant(role);
+        let record = self.transcript.last_mut().expect("a segment is in progress");
+        record.entries.push(TranscriptEntry { at: self.clock, role, speaker, text: text.to_string() });
+        Ok(())
+    }
+
+    /// The speaker gives up the rest of their time
+    pub fn yield_turn(&mut self, role: Role) -> Result<(), String> {
+        let status = self.current_turn().ok_or("No turn in progress")?;
+        if role != status.speaker {
+            return Err(format!("{:?} cannot yield {:?}'s turn", role, status.speaker));
+        }
+        self.end_turn(TurnEnd::Yielded);
+        Ok(())
+    }
+
+    /// Moderator moves on regardless of remaining time
+    pub fn skip_turn(&mut self) -> Result<(), String> {
+        self.current_turn().ok_or("No turn in progress")?;
+        self.end_turn(TurnEnd::Skipped);
+        Ok(())
+    }
+
+    /// Full transcript of one side's contributions
+    pub fn statements_by(&self, role: Role) -> Vec<&TranscriptEntry> {
+        self.transcript.iter().flat_map(|s| &s.entries).filter(|e| e.role == role).collect()
+    }
+
+    fn current_kind(&self) -> Option<SegmentKind> {
+        match self.phase {
+            DebatePhase::InProgress { segment, .. } => Some(self.format.segments[segment].kind),
+            _ => None,
+        }
+    }
+
+    /// Pre-debate poll closes once the first debater segment starts
+    fn pre_poll_open(&self) -> bool {
+        self.phase == DebatePhase::NotStarted || self.current_kind() == Some(SegmentKind::Introduction)
+    }
+
+    /// Post-debate poll and ballots open at the summary (or once the debate is over)
+    fn voting_open(&self) -> bool {
+        self.phase == DebatePhase::Finished || self.current_kind() == Some(SegmentKind::Summary)
+    }
+
+    pub fn judging(&self) -> &Judging {
+        &self.judging
+    }
+
+    pub fn record_pre_stance(&mut self, voter: &str, stance: Stance) -> Result<(), String> {
+        if !self.pre_poll_open() {
+            return Err("Pre-debate poll closed when the openings began".to_string());
+        }
+        self.judging.record_pre(voter, stance);
+        Ok(())
+    }
+
+    pub fn record_post_stance(&mut self, voter: &str, stance: Stance) -> Result<(), String> {
+        if !self.voting_open() {
+            return Err("Post-debate poll opens at the summary".to_string());
+        }
+        self.judging.record_post(voter, stance);
+        Ok(())
+    }
+
+    /// Quadratic audience ballot; returns the voter's unspent credits
+    pub fn cast_ballot(&mut self, voter: &str, votes: &[(Role, Votes)]) -> Result<Credits, String> {
+        if !self.voting_open() {
+            return Err("Audience voting opens at the summary".to_string());
+        }
+        self.judging.cast_ballot(voter, votes)
+    }
+
+    pub fn submit_scorecard(&mut self, judge: &str, side: Role, scores: &[(&str, f64)], notes: &str) -> Result<(), String> {
+        if self.phase == DebatePhase::NotStarted {
+            return Err("Debate has not started".to_string());
+        }
+        self.judging.submit_scorecard(judge, side, scores, notes)
+    }
+
+    /// Final scorecard, available from the summary segment on
+    pub fn scorecard(&self) -> Result<Scorecard, String> {
+        if !self.voting_open() {
+            return Err("Scorecard is available from the summary".to_string());
+        }
+        Ok(self.judging.scorecard(&self.topic))
+    }
+
+    pub fn markets(&self) -> Option<&DebateMarkets> {
+        self.markets.as_ref()
+    }
+
+    /// Trade on a debate market; trading closes when audience voting opens
+    pub fn trade(&mut self, trader: &str, question: DebateQuestion, outcome: usize, amount: f64) -> Result<f64, String> {
+        if self.voting_open() {
+            return Err("Debate markets closed at the summary".to_string());
+        }
+        self.markets.as_mut().ok_or("Debate has no markets")?.trade(trader, question, outcome, amount)
+    }
+
+    /// Settle every debate market on the final scorecard once the debate is over
+    pub fn settle_markets(&mut self) -> Result<&[QuestionSettlement], String> {
+        if self.phase != DebatePhase::Finished {
+            return Err("Debate markets settle when the debate is over".to_string());
+        }
+        let scorecard = self.judging.scorecard(&self.topic);
+        self.markets.as_mut().ok_or("Debate has no markets")?.settle(&scorecard)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use DebateEventKind::*;
+
+    fn session() -> DebateSession {
+        let mut session = DebateSession::new("Futarchy should govern the commons", DebateFormat::grok_global_arena()).unwrap();
+        session.start().unwrap();
+        session
+    }
+
+    fn event(at: f64, kind: DebateEventKind) -> DebateEvent {
+        DebateEvent { at, kind }
+    }
+
+    fn segment(name: &str) -> String {
+        name.to_string()
+    }
+
+    fn cue(text: &str) -> DebateEventKind {
+        Cue { text: text.to_string() }
+    }
+
+    #[test]
+    fn start_cues_the_moderator_introduction() {
+        let mut session = session();
+        assert_eq!(session.drain_events(), vec![
+            event(0.0, SegmentStarted { segment: segment("Introduction") }),
+            event(0.0, cue("Introduction, 4 minutes.")),
+            event(0.0, TurnStarted { speaker: Role::Moderator, seconds: 240.0 }),
+        ]);
+        assert!(session.start().is_err());
+    }
+
+    #[test]
+    fn turns_expire_at_length_plus_grace_and_cue_the_opening() {
+        let mut session = session();
+        session.drain_events();
+        session.advance(254.0);
+        assert_eq!(session.drain_events(), vec![event(180.0, TimeWarning { speaker: Role::Moderator, remaining_secs: 60.0 })]);
+        assert_eq!(session.current_turn().unwrap().speaker, Role::Moderator);
+
+        session.advance(1.0);
+        assert_eq!(session.drain_events(), vec![
+            event(255.0, TurnEnded { speaker: Role::Moderator, reason: TurnEnd::Expired }),
+            event(255.0, SegmentEnded { segment: segment("Introduction") }),
+            event(255.0, SegmentStarted { segment: segment("Opening Statements") }),
+            event(255.0, cue("Affirmative opens, 8 minutes.")),
+            event(255.0, TurnStarted { speaker: Role::Affirmative, seconds: 480.0 }),
+        ]);
+        assert_eq!(session.clock(), 255.0);
+        assert_eq!(session.current_turn().unwrap().remaining_secs, 480.0);
+    }
+
+    #[test]
+    fn one_long_advance_orders_warnings_before_each_expiry() {
+        let mut session = session();
+        session.drain_events();
+        session.advance(800.0);
+        assert_eq!(session.drain_events(), vec![
+            event(180.0, TimeWarning { speaker: Role::Moderator, remaining_secs: 60.0 }),
+            event(255.0, TurnEnded { speaker: Role::Moderator, reason: TurnEnd::Expired }),
+            event(255.0, SegmentEnded { segment: segment("Introduction") }),
+            event(255.0, SegmentStarted { segment: segment("Opening Statements") }),
+            event(255.0, cue("Affirmative opens, 8 minutes.")),
+            event(255.0, TurnStarted { speaker: Role::Affirmative, seconds: 480.0 }),
+            event(675.0, TimeWarning { speaker: Role::Affirmative, remaining_secs: 60.0 }),
+            event(750.0, TurnEnded { speaker: Role::Affirmative, reason: TurnEnd::Expired }),
+            event(750.0, cue("Negative opens, 8 minutes.")),
+            event(750.0, TurnStarted { speaker: Role::Negative, seconds: 480.0 }),
+        ]);
+        assert_eq!(session.clock(), 800.0);
+    }
+
+    #[test]
+    fn only_the_floor_holder_respondent_or_moderator_may_speak() {
+        let mut unstarted = DebateSession::new("Topic", DebateFormat::grok_global_arena()).unwrap();
+        assert_eq!(unstarted.speak(Role::Affirmative, "Early"), Err("Debate has not started".to_string()));
+
+        let mut session = session().with_participant(Role::Affirmative, "Ada");
+        session.advance(255.0);
+        assert_eq!(
+            session.speak(Role::Negative, "Point of order"),
+            Err("Out of turn: Affirmative has the floor in Opening Statements".to_string()),
+        );
+        session.speak(Role::Affirmative, "Markets aggregate belief").unwrap();
+        session.speak(Role::Moderator, "Thirty seconds of fact-check").unwrap();
+        let entries = &session.transcript().last().unwrap().entries;
+        assert_eq!(entries.len(), 2);
+        assert_eq!((entries[0].speaker.as_str(), entries[0].at), ("Ada", 255.0));
+
+        // Cross-examination: the examined side may answer
+        while session.current_turn().unwrap().segment != "Cross-Examination" {
+            session.skip_turn().unwrap();
+        }
+        session.speak(Role::Negative, "Answering the question").unwrap();
+    }
+
+    #[test]
+    fn yielding_hands_over_immediately_and_restarts_the_timer() {
+        let mut session = session();
+        session.advance(255.0);
+        session.advance(100.0);
+        session.drain_events();
+        assert_eq!(session.yield_turn(Role::Negative), Err("Negative cannot yield Affirmative's turn".to_string()));
+        session.yield_turn(Role::Affirmative).unwrap();
+        assert_eq!(session.drain_events(), vec![
+            event(355.0, TurnEnded { speaker: Role::Affirmative, reason: TurnEnd::Yielded }),
+            event(355.0, cue("Negative opens, 8 minutes.")),
+            event(355.0, TurnStarted { speaker: Role::Negative, seconds: 480.0 }),
+        ]);
+        session.advance(420.0);
+        assert_eq!(session.drain_events(), vec![event(775.0, TimeWarning { speaker: Role::Negative, remaining_secs: 60.0 })]);
+    }
+
+    #[test]
+    fn skipping_every_turn_ends_the_debate() {
+        let mut session = session();
+        session.drain_events();
+        session.skip_turn().unwrap();
+        assert_eq!(session.drain_events()[0], event(0.0, TurnEnded { speaker: Role::Moderator, reason: TurnEnd::Skipped }));
+
+        let turns: usize = session.format().segments.iter().map(|s| s.turns.len()).sum();
+        for _ in 1..turns {
+            session.skip_turn().unwrap();
+        }
+        assert_eq!(session.phase(), DebatePhase::Finished);
+        let events = session.drain_events();
+        assert_eq!(events[events.len() - 2..], [
+            event(0.0, SegmentEnded { segment: segment("Grok Summary & Scorecard") }),
+            event(0.0, DebateEnded),
+        ]);
+        assert!(session.skip_turn().is_err());
+        assert_eq!(session.speak(Role::Moderator, "Late"), Err("Debate is over".to_string()));
+    }
+}
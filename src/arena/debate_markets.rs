@@ -0,0 +1,212 @@
+//! Debate Markets — Futarchy Showcase Inside the Arena
+//! Ultramasterful belief tracking: every segment leaves a price on the chart
+//!
+//! Each debate opens internal FPMM markets on its own result ("Will Affirmative win the
+//! audience vote?", "Will opinion shift by more than X points?"). Participants trade while
+//! the debate runs, prices are sampled at the end of every segment, and the markets settle
+//! on the final scorecard.
+
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+
+use crate::arena::debate::Role;
+use crate::arena::judging::Scorecard;
+use crate::markets::prediction_market::{PredictionMarket, NO, YES};
+use crate::mercy::MercyGate;
+use crate::truth::SharedTruth;
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum DebateQuestion {
+    /// Yes if Affirmative gets more quadratic audience votes
+    AudienceWinner,
+    /// Yes if the For share rises by more than `points` percentage points
+    OpinionShift { points: f64 },
+}
+
+impl DebateQuestion {
+    pub fn text(&self) -> String {
+        match self {
+            DebateQuestion::AudienceWinner => "Will Affirmative win the audience vote?".to_string(),
+            DebateQuestion::OpinionShift { points } => format!("Will opinion shift by >{}% toward Affirmative?", points),
+        }
+    }
+
+    /// Winning outcome under `scorecard`
+    pub fn outcome(&self, scorecard: &Scorecard) -> usize {
+        let yes = match self {
+            DebateQuestion::AudienceWinner => scorecard.audience_winner == Some(Role::Affirmative),
+            DebateQuestion::OpinionShift { points } => scorecard.opinion.shift_points > *points,
+        };
+        if yes { YES } else { NO }
+    }
+}
+
+/// Yes price sampled at a segment boundary
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PricePoint {
+    pub segment: String,
+    /// Debate clock, seconds
+    pub at: f64,
+    pub yes_price: f64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct QuestionSettlement {
+    pub question: DebateQuestion,
+    pub outcome: usize,
+    pub final_price: f64,
+    pub payouts: HashMap<String, f64>,
+}
+
+pub struct DebateMarkets {
+    market: PredictionMarket,
+    questions: Vec<DebateQuestion>,
+    charts: Vec<Vec<PricePoint>>,
+    settlement: Option<Vec<QuestionSettlement>>,
+}
+
+impl DebateMarkets {
+    /// Open one market per question, each seeded with `liquidity` at even odds
+    pub fn open(truth: SharedTruth, questions: Vec<DebateQuestion>, liquidity: f64) -> Result<Self, String> {
+        Self::open_with(PredictionMarket::new(truth), questions, liquidity)
+    }
+
+    /// As `open`, with market creation and trades admitted through `gate`
+    pub fn open_gated(truth: SharedTruth, gate: Arc<MercyGate>, questions: Vec<DebateQuestion>, liquidity: f64) -> Result<Self, String> {
+        Self::open_with(PredictionMarket::new(truth).with_mercy_gate(gate), questions, liquidity)
+    }
+
+    fn open_with(mut market: PredictionMarket, questions: Vec<DebateQuestion>, liquidity: f64) -> Result<Self, String> {
+        for question in &questions {
+            market.open_market(&question.text(), liquidity)?;
+        }
+        let charts = vec![Vec::new(); questions.len()];
+        Ok(DebateMarkets { market, questions, charts, settlement: None })
+    }
+
+    /// Audience-vote market plus a >`shift_points` opinion shift market
+    pub fn standard(truth: SharedTruth, gate: Arc<MercyGate>, shift_points: f64, liquidity: f64) -> Result<Self, String> {
+        Self::open_gated(truth, gate, vec![DebateQuestion::AudienceWinner, DebateQuestion::OpinionShift { points: shift_points }], liquidity)
+    }
+
+    pub fn questions(&self) -> &[DebateQuestion] {
+        &self.questions
+    }
+
+    fn index(&self, question: DebateQuestion) -> Result<usize, String> {
+        self.questions.iter().position(|q| *q == question)
+            .ok_or_else(|| format!("No debate market: {}", question.text()))
+    }
+
+    pub fn price(&self, question: DebateQuestion) -> Option<f64> {
+        self.market.probability(&question.text())
+    }
+
+    pub fn trade(&mut self, trader: &str, question: DebateQuestion, outcome: usize, amount: f64) -> Result<f64, String> {
+        if self.settlement.is_some() {
+            return Err("Debate markets are settled".to_string());
+        }
+        self.index(question)?;
+        self.market.trade(&question.text(), trader, outcome, amount)
+    }
+
+    /// Sample every market's Yes price under `segment`
+    pub fn snapshot(&mut self, segment: &str, at: f64) {
+        for (question, chart) in self.questions.iter().zip(&mut self.charts) {
+            if let Some(yes_price) = self.market.probability(&question.text()) {
+                chart.push(PricePoint { segment: segment.to_string(), at, yes_price });
+            }
+        }
+    }
+
+    pub fn chart(&self, question: DebateQuestion) -> Result<&[PricePoint], String> {
+        Ok(&self.charts[self.index(question)?])
+    }
+
+    /// Text chart: one bar per segment, with the move since the previous sample
+    pub fn render_chart(&self, question: DebateQuestion) -> Result<String, String> {
+        let chart = self.chart(question)?;
+        let width = chart.iter().map(|p| p.segment.chars().count()).max().unwrap_or(0);
+        let mut out = format!("{}\n", question.text());
+        let mut previous: Option<f64> = None;
+        for point in chart {
+            let bar = "█".repeat((point.yes_price * 40.0).round() as usize);
+            let delta = previous.map_or(String::new(), |p| format!(" ({:+.1})", (point.yes_price - p) * 100.0));
+            let _ = writeln!(out, "  {:<width$}  {:>5.1}% {}{}", point.segment, point.yes_price * 100.0, bar, delta, width = width);
+            previous = Some(point.yes_price);
+        }
+        Ok(out)
+    }
+
+    pub fn is_settled(&self) -> bool {
+        self.settlement.is_some()
+    }
+
+    pub fn settlement(&self) -> Option<&[QuestionSettlement]> {
+        self.settlement.as_deref()
+    }
+
+    /// Resolve every market on the final scorecard
+    pub fn settle(&mut self, scorecard: &Scorecard) -> Result<&[QuestionSettlement], String> {
+        if self.settlement.is_some() {
+            return Err("Debate markets already settled".to_string());
+        }
+        // Check every market before resolving any, so a failure leaves all of them open
+        let mut pending = Vec::with_capacity(self.questions.len());
+        for &question in &self.questions {
+            let text = question.text();
+            let market = self.market.market(&text)
+                .ok_or_else(|| format!("No debate market: {}", text))?;
+            if market.resolved.is_some() {
+                return Err(format!("Debate market already resolved: {}", text));
+            }
+            pending.push((question, text, market.yes_price(), question.outcome(scorecard)));
+        }
+        let mut settled = Vec::with_capacity(pending.len());
+        for (question, text, final_price, outcome) in pending {
+            let payouts = self.market.resolve(&text, outcome)?;
+            settled.push(QuestionSettlement { question, outcome, final_price, payouts });
+        }
+        Ok(self.settlement.insert(settled))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arena::judging::Judging;
+    use crate::truth::LocalTruth;
+
+    fn markets() -> DebateMarkets {
+        let questions = vec![DebateQuestion::AudienceWinner, DebateQuestion::OpinionShift { points: 5.0 }];
+        DebateMarkets::open(LocalTruth::shared(), questions, 100.0).unwrap()
+    }
+
+    #[test]
+    fn settles_every_question_on_the_scorecard() {
+        let mut markets = markets();
+        markets.trade("ada", DebateQuestion::AudienceWinner, YES, 10.0).unwrap();
+        let mut judging = Judging::default();
+        judging.cast_full_ballot("crowd", Role::Affirmative).unwrap();
+        let settled = markets.settle(&judging.scorecard("Motion")).unwrap();
+        assert_eq!(settled.iter().map(|s| s.outcome).collect::<Vec<_>>(), vec![YES, NO]);
+        assert!(settled[0].final_price > 0.5);
+        assert!(settled[0].payouts["ada"] > 10.0);
+        assert!(markets.settle(&judging.scorecard("Motion")).is_err());
+    }
+
+    #[test]
+    fn failed_validation_resolves_nothing() {
+        let mut markets = markets();
+        let shift = DebateQuestion::OpinionShift { points: 5.0 }.text();
+        markets.market.resolve(&shift, NO).unwrap();
+        let err = markets.settle(&Judging::default().scorecard("Motion")).unwrap_err();
+        assert_eq!(err, format!("Debate market already resolved: {}", shift));
+        assert!(!markets.is_settled());
+        let audience = markets.market.market(&DebateQuestion::AudienceWinner.text()).unwrap();
+        assert!(audience.resolved.is_none());
+    }
+}
@@ -0,0 +1,515 @@
+//! Arena Discourse — Persistent Ideas, Threads and Reactions
+//! Ultramasterful discourse model: every idea, reply and edit passes the mercy gate
+//!
+//! Ideas carry their author, tags, timestamps, the truth verdict and the mercy decision
+//! that admitted them. Replies form threads under a root idea; edits keep the full
+//! revision history. The whole store round-trips through JSON.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::SystemTime;
+
+use serde::{Deserialize, Serialize};
+
+use crate::mercy::{GateStage, MercyGate};
+use crate::truth::Verdict;
+use crate::types::Id;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Reaction {
+    Agree,
+    Disagree,
+    Insightful,
+    Laugh,
+    Applause,
+    Mercy,
+}
+
+/// Previous version of an edited idea
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Revision {
+    pub body: String,
+    pub tags: Vec<String>,
+    pub verdict: Verdict,
+    pub written_at: SystemTime,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Idea {
+    pub id: Id,
+    pub author: String,
+    pub body: String,
+    pub tags: Vec<String>,
+    pub created_at: SystemTime,
+    pub edited_at: Option<SystemTime>,
+    /// Idea this replies to (None for thread roots)
+    pub parent: Option<Id>,
+    /// Thread root (own id for roots)
+    pub root: Id,
+    pub replies: Vec<Id>,
+    /// Users per reaction
+    pub reactions: BTreeMap<Reaction, BTreeSet<String>>,
+    pub history: Vec<Revision>,
+    pub verdict: Verdict,
+    /// Mercy decision that admitted the current body
+    pub decision_id: u64,
+}
+
+impl Idea {
+    pub fn reaction_count(&self, reaction: Reaction) -> usize {
+        self.reactions.get(&reaction).map_or(0, BTreeSet::len)
+    }
+
+    pub fn total_reactions(&self) -> usize {
+        self.reactions.values().map(BTreeSet::len).sum()
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SortOrder {
+    #[default]
+    Newest,
+    Oldest,
+    MostReactions,
+    MostReplies,
+}
+
+/// Filter for `Discourse::list`
+#[derive(Clone, Debug, Default)]
+pub struct IdeaQuery {
+    pub author: Option<String>,
+    pub tag: Option<String>,
+    /// Only thread roots (no replies)
+    pub roots_only: bool,
+    pub sort: SortOrder,
+}
+
+impl IdeaQuery {
+    pub fn roots() -> Self {
+        Self { roots_only: true, ..Self::default() }
+    }
+
+    pub fn by_author(mut self, author: &str) -> Self {
+        self.author = Some(author.to_string());
+        self
+    }
+
+    pub fn tagged(mut self, tag: &str) -> Self {
+        self.tag = Some(normalise_tag(tag));
+        self
+    }
+
+    pub fn sorted(mut self, sort: SortOrder) -> Self {
+        self.sort = sort;
+        self
+    }
+
+    fn matches(&self, idea: &Idea) -> bool {
+        (!self.roots_only || idea.parent.is_none())
+            && self.author.as_ref().is_none_or(|a| *a == idea.author)
+            && self.tag.as_ref().is_none_or(|t| idea.tags.contains(t))
+    }
+}
+
+/// One page of results
+#[derive(Debug)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub total: usize,
+    pub offset: usize,
+    /// Offset of the next page, if any
+    pub next_offset: Option<usize>,
+}
+
+impl<T> Page<T> {
+    fn slice(all: Vec<T>, offset: usize, limit: usize) -> Self {
+        let total = all.len();
+        let items: Vec<T> = all.into_iter().skip(offset).take(limit).collect();
+        let end = offset + items.len();
+        Page { items, total, offset, next_offset: (end < total).then_some(end) }
+    }
+}
+
+fn normalise_tag(tag: &str) -> String {
+    tag.trim().trim_start_matches('#').to_lowercase()
+}
+
+fn normalise_tags(tags: &[&str]) -> Vec<String> {
+    let mut out: Vec<String> = tags.iter().map(|t| normalise_tag(t)).filter(|t| !t.is_empty()).collect();
+    out.sort();
+    out.dedup();
+    out
+}
+
+pub struct Discourse {
+    gate: Arc<MercyGate>,
+    ideas: HashMap<Id, Idea>,
+    next_id: Id,
+}
+
+impl Discourse {
+    pub fn new(gate: Arc<MercyGate>) -> Self {
+        Discourse { gate, ideas: HashMap::new(), next_id: 0 }
+    }
+
+    /// Load a store written by `save`; new submissions go through `gate`
+    pub fn load(path: &Path, gate: Arc<MercyGate>) -> Result<Self, String> {
+        let raw = fs::read_to_string(path).map_err(|e| format!("Read error {}: {:?}", path.display(), e))?;
+        let ideas: Vec<Idea> = serde_json::from_str(&raw).map_err(|e| format!("Parse error {}: {:?}", path.display(), e))?;
+        let next_id = ideas.iter().map(|i| i.id + 1).max().unwrap_or(0);
+        Ok(Discourse { gate, ideas: ideas.into_iter().map(|i| (i.id, i)).collect(), next_id })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let mut ideas: Vec<&Idea> = self.ideas.values().collect();
+        ideas.sort_by_key(|i| i.id);
+        let raw = serde_json::to_string_pretty(&ideas).map_err(|e| format!("Serialize error: {:?}", e))?;
+        fs::write(path, raw).map_err(|e| format!("Write error {}: {:?}", path.display(), e))
+    }
+
+    /// Run `body` through the truth engine and mercy gate
+    fn screen(&self, author: &str, body: &str) -> Result<(Verdict, u64), String> {
+        let decision = self.gate.admit(GateStage::IdeaSubmission, author, body)?;
+        let verdict = Verdict {
+            verified: decision.verified,
+            valence: decision.valence,
+            reasons: decision.truth_reasons.clone(),
+        };
+        Ok((verdict, decision.id))
+    }
+
+    fn insert(&mut self, author: &str, body: &str, tags: &[&str], parent: Option<Id>) -> Result<Id, String> {
+        if body.trim().is_empty() {
+            return Err("Idea body is empty".to_string());
+        }
+        let root = match parent {
+            Some(p) => self.ideas.get(&p).ok_or_else(|| format!("No idea #{}", p))?.root,
+            None => self.next_id,
+        };
+        let (verdict, decision_id) = self.screen(author, body)?;
+
+        let id = self.next_id;
+        self.next_id += 1;
+        self.ideas.insert(id, Idea {
+            id,
+            author: author.to_string(),
+            body: body.to_string(),
+            tags: normalise_tags(tags),
+            created_at: SystemTime::now(),
+            edited_at: None,
+            parent,
+            root,
+            replies: Vec::new(),
+            reactions: BTreeMap::new(),
+            history: Vec::new(),
+            verdict,
+            decision_id,
+        });
+        if let Some(p) = parent {
+            self.ideas.get_mut(&p).expect("parent checked above").replies.push(id);
+        }
+        Ok(id)
+    }
+
+    /// Start a new thread
+    pub fn submit(&mut self, author: &str, body: &str, tags: &[&str]) -> Result<Id, String> {
+        self.insert(author, body, tags, None)
+    }
+
+    pub fn reply(&mut self, parent: Id, author: &str, body: &str, tags: &[&str]) -> Result<Id, String> {
+        self.insert(author, body, tags, Some(parent))
+    }
+
+    /// Replace body and tags; only the author may edit, and the new body is screened again
+    pub fn edit(&mut self, id: Id, editor: &str, body: &str, tags: &[&str]) -> Result<&Idea, String> {
+        let idea = self.ideas.get(&id).ok_or_else(|| format!("No idea #{}", id))?;
+        if idea.author != editor {
+            return Err(format!("{} cannot edit idea #{} by {}", editor, id, idea.author));
+        }
+        let (verdict, decision_id) = self.screen(editor, body)?;
+
+        let idea = self.ideas.get_mut(&id).expect("checked above");
+        let now = SystemTime::now();
+        let written_at = idea.edited_at.unwrap_or(idea.created_at);
+        idea.history.push(Revision {
+            body: std::mem::replace(&mut idea.body, body.to_string()),
+            tags: std::mem::replace(&mut idea.tags, normalise_tags(tags)),
+            verdict: std::mem::replace(&mut idea.verdict, verdict),
+            written_at,
+        });
+        idea.edited_at = Some(now);
+        idea.decision_id = decision_id;
+        Ok(idea)
+    }
+
+    /// Toggle `user`'s reaction; returns whether it is now set
+    pub fn react(&mut self, id: Id, user: &str, reaction: Reaction) -> Result<bool, String> {
+        let idea = self.ideas.get_mut(&id).ok_or_else(|| format!("No idea #{}", id))?;
+        let users = idea.reactions.entry(reaction).or_default();
+        let set = !users.remove(user);
+        if set {
+            users.insert(user.to_string());
+        }
+        if users.is_empty() {
+            idea.reactions.remove(&reaction);
+        }
+        Ok(set)
+    }
+
+    pub fn get(&self, id: Id) -> Option<&Idea> {
+        self.ideas.get(&id)
+    }
+
+    pub fn len(&self) -> usize {
+        self.ideas.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ideas.is_empty()
+    }
+
+    pub fn list(&self, query: &IdeaQuery, offset: usize, limit: usize) -> Page<&Idea> {
+        let mut found: Vec<&Idea> = self.ideas.values().filter(|i| query.matches(i)).collect();
+        // Ids are assigned in submission order, so they break timestamp ties
+        match query.sort {
+            SortOrder::Newest => found.sort_by_key(|i| std::cmp::Reverse((i.created_at, i.id))),
+            SortOrder::Oldest => found.sort_by_key(|i| (i.created_at, i.id)),
+            SortOrder::MostReactions => found.sort_by_key(|i| (std::cmp::Reverse(i.total_reactions()), i.id)),
+            SortOrder::MostReplies => found.sort_by_key(|i| (std::cmp::Reverse(i.replies.len()), i.id)),
+        }
+        Page::slice(found, offset, limit)
+    }
+
+    /// Direct replies to `id`, oldest first
+    pub fn replies(&self, id: Id, offset: usize, limit: usize) -> Result<Page<&Idea>, String> {
+        let idea = self.ideas.get(&id).ok_or_else(|| format!("No idea #{}", id))?;
+        let replies = idea.replies.iter().filter_map(|r| self.ideas.get(r)).collect();
+        Ok(Page::slice(replies, offset, limit))
+    }
+
+    /// Whole thread under `id` in depth-first order, with reply depth
+    pub fn thread(&self, id: Id) -> Result<Vec<(usize, &Idea)>, String> {
+        let root = self.ideas.get(&id).ok_or_else(|| format!("No idea #{}", id))?;
+        let mut out = Vec::new();
+        let mut stack = vec![(0, root)];
+        while let Some((depth, idea)) = stack.pop() {
+            out.push((depth, idea));
+            for reply in idea.replies.iter().rev().filter_map(|r| self.ideas.get(r)) {
+                stack.push((depth + 1, reply));
+            }
+        }
+        Ok(out)
+    }
+
+    /// All tags with their idea counts, most used first
+    pub fn tags(&self) -> Vec<(String, usize)> {
+        let mut counts: HashMap<&str, usize> = HashMap::new();
+        for tag in self.ideas.values().flat_map(|i| &i.tags) {
+            *counts.entry(tag).or_default() += 1;
+        }
+        let mut tags: Vec<(String, usize)> = counts.into_iter().map(|(t, c)| (t.to_string(), c)).collect();
+        tags.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        tags
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use super::*;
+    use crate::mercy::{HardReject, HardRejectRule, MercyPolicy};
+    use crate::truth::LocalTruth;
+
+    /// Default policy, plus a blocked "troll" account whose submissions are always rejected
+    fn discourse() -> Discourse {
+        let mut policy = MercyPolicy::default();
+        policy.hard_rejects.push(HardReject { rule: HardRejectRule::BlockedActor("troll".to_string()), appealable: false });
+        Discourse::new(Arc::new(MercyGate::new(policy, LocalTruth::shared())))
+    }
+
+    /// Pin `id`'s creation time so sorting does not depend on the clock
+    fn created(discourse: &mut Discourse, id: Id, secs: u64) {
+        discourse.ideas.get_mut(&id).unwrap().created_at = UNIX_EPOCH + Duration::from_secs(secs);
+    }
+
+    fn ids(page: &Page<&Idea>) -> Vec<Id> {
+        page.items.iter().map(|i| i.id).collect()
+    }
+
+    #[test]
+    fn edits_keep_revision_history() {
+        let mut discourse = discourse();
+        let id = discourse.submit("ada", "Plant more trees in the park", &["#Green", "parks", " green "]).unwrap();
+        let original = discourse.get(id).unwrap().clone();
+        assert_eq!(original.tags, vec!["green", "parks"]);
+
+        let edited = discourse.edit(id, "ada", "Plant fruit trees along every street", &["Civic"]).unwrap();
+        assert_eq!(edited.body, "Plant fruit trees along every street");
+        assert_eq!(edited.tags, vec!["civic"]);
+        assert_ne!(edited.decision_id, original.decision_id);
+        let first_edit = edited.edited_at.unwrap();
+        assert_eq!(edited.history.len(), 1);
+        let revision = &edited.history[0];
+        assert_eq!(revision.body, original.body);
+        assert_eq!(revision.tags, original.tags);
+        assert_eq!(revision.verdict.valence, original.verdict.valence);
+        assert_eq!(revision.written_at, original.created_at);
+
+        let edited = discourse.edit(id, "ada", "Plant fruit trees along every avenue", &[]).unwrap();
+        assert_eq!(edited.history.len(), 2);
+        assert_eq!(edited.history[1].body, "Plant fruit trees along every street");
+        assert_eq!(edited.history[1].written_at, first_edit);
+        assert!(edited.tags.is_empty());
+    }
+
+    #[test]
+    fn only_the_author_may_edit() {
+        let mut discourse = discourse();
+        let id = discourse.submit("ada", "Open the library on Sundays", &["library"]).unwrap();
+        let err = discourse.edit(id, "bob", "Close the library", &[]).unwrap_err();
+        assert_eq!(err, "bob cannot edit idea #0 by ada");
+        let idea = discourse.get(id).unwrap();
+        assert_eq!(idea.body, "Open the library on Sundays");
+        assert!(idea.history.is_empty() && idea.edited_at.is_none());
+        assert_eq!(discourse.edit(7, "ada", "Anything", &[]).unwrap_err(), "No idea #7");
+    }
+
+    #[test]
+    fn rejected_ideas_are_not_stored() {
+        let mut discourse = discourse();
+        let err = discourse.submit("troll", "Open the library on Sundays", &["library"]).unwrap_err();
+        assert!(err.contains("actor troll is blocked"), "{}", err);
+        assert!(discourse.submit("ada", "   ", &[]).is_err());
+        assert!(discourse.reply(3, "ada", "Agreed", &[]).is_err());
+        assert!(discourse.is_empty());
+        assert!(discourse.tags().is_empty());
+
+        // Ids are only consumed by stored ideas
+        let id = discourse.submit("ada", "Open the library on Sundays", &[]).unwrap();
+        assert_eq!(id, 0);
+        assert!(discourse.reply(id, "troll", "Nobody reads", &[]).is_err());
+        assert!(discourse.get(id).unwrap().replies.is_empty());
+        assert_eq!(discourse.len(), 1);
+    }
+
+    #[test]
+    fn reactions_toggle_per_user() {
+        let mut discourse = discourse();
+        let id = discourse.submit("ada", "Plant more trees", &[]).unwrap();
+        assert!(discourse.react(id, "bob", Reaction::Agree).unwrap());
+        assert!(discourse.react(id, "cy", Reaction::Agree).unwrap());
+        assert!(discourse.react(id, "bob", Reaction::Insightful).unwrap());
+        let idea = discourse.get(id).unwrap();
+        assert_eq!((idea.reaction_count(Reaction::Agree), idea.total_reactions()), (2, 3));
+
+        // Reacting again removes it; an emptied reaction leaves no entry behind
+        assert!(!discourse.react(id, "bob", Reaction::Insightful).unwrap());
+        assert!(!discourse.react(id, "bob", Reaction::Agree).unwrap());
+        let idea = discourse.get(id).unwrap();
+        assert_eq!(idea.reaction_count(Reaction::Agree), 1);
+        assert!(!idea.reactions.contains_key(&Reaction::Insightful));
+        assert_eq!(idea.total_reactions(), 1);
+        assert!(discourse.react(9, "bob", Reaction::Laugh).is_err());
+    }
+
+    #[test]
+    fn list_sorts_filters_and_paginates() {
+        let mut discourse = discourse();
+        // Roots 0..3 created in the order 2, 0, 3, 1
+        for (i, secs) in [20, 40, 10, 30].into_iter().enumerate() {
+            let id = discourse.submit(if i % 2 == 0 { "ada" } else { "bob" }, &format!("Idea number {}", i), &["ideas"]).unwrap();
+            created(&mut discourse, id, secs);
+        }
+        let reply = discourse.reply(3, "cy", "Reply to idea three", &[]).unwrap();
+        created(&mut discourse, reply, 50);
+        discourse.reply(3, "ada", "Another reply to idea three", &[]).unwrap();
+        let reply = discourse.reply(1, "cy", "Reply to idea one", &[]).unwrap();
+        created(&mut discourse, reply, 60);
+        for user in ["x", "y", "z"] {
+            discourse.react(2, user, Reaction::Applause).unwrap();
+        }
+        discourse.react(0, "x", Reaction::Agree).unwrap();
+
+        let roots = |sort| IdeaQuery::roots().sorted(sort);
+        assert_eq!(ids(&discourse.list(&roots(SortOrder::Oldest), 0, 10)), vec![2, 0, 3, 1]);
+        assert_eq!(ids(&discourse.list(&roots(SortOrder::Newest), 0, 10)), vec![1, 3, 0, 2]);
+        // Ties fall back to id order
+        assert_eq!(ids(&discourse.list(&roots(SortOrder::MostReactions), 0, 10)), vec![2, 0, 1, 3]);
+        assert_eq!(ids(&discourse.list(&roots(SortOrder::MostReplies), 0, 10)), vec![3, 1, 0, 2]);
+        assert_eq!(ids(&discourse.list(&IdeaQuery::roots().by_author("bob"), 0, 10)), vec![1, 3]);
+        assert_eq!(discourse.list(&IdeaQuery::default().tagged("#IDEAS"), 0, 10).total, 4);
+        assert_eq!(discourse.list(&IdeaQuery::default(), 0, 10).total, 7);
+
+        let first = discourse.list(&roots(SortOrder::Oldest), 0, 3);
+        assert_eq!((ids(&first), first.total, first.offset, first.next_offset), (vec![2, 0, 3], 4, 0, Some(3)));
+        let last = discourse.list(&roots(SortOrder::Oldest), 3, 3);
+        assert_eq!((ids(&last), last.next_offset), (vec![1], None));
+        let past_end = discourse.list(&roots(SortOrder::Oldest), 9, 3);
+        assert!(past_end.items.is_empty() && past_end.next_offset.is_none());
+        let exact = discourse.list(&roots(SortOrder::Oldest), 2, 2);
+        assert_eq!((ids(&exact), exact.next_offset), (vec![3, 1], None));
+
+        let replies = discourse.replies(3, 1, 5).unwrap();
+        assert_eq!((ids(&replies), replies.total, replies.next_offset), (vec![5], 2, None));
+    }
+
+    #[test]
+    fn thread_is_depth_first() {
+        let mut discourse = discourse();
+        let root = discourse.submit("ada", "Root idea", &[]).unwrap();
+        let a = discourse.reply(root, "bob", "First reply", &[]).unwrap();
+        let b = discourse.reply(root, "cy", "Second reply", &[]).unwrap();
+        let a1 = discourse.reply(a, "ada", "Reply under the first", &[]).unwrap();
+        let a1x = discourse.reply(a1, "bob", "Deeper still", &[]).unwrap();
+        let b1 = discourse.reply(b, "ada", "Reply under the second", &[]).unwrap();
+
+        let order: Vec<(usize, Id)> = discourse.thread(root).unwrap().into_iter().map(|(d, i)| (d, i.id)).collect();
+        assert_eq!(order, vec![(0, root), (1, a), (2, a1), (3, a1x), (1, b), (2, b1)]);
+        assert!(discourse.get(a1x).unwrap().root == root && discourse.get(a1x).unwrap().parent == Some(a1));
+
+        let sub: Vec<Id> = discourse.thread(b).unwrap().into_iter().map(|(_, i)| i.id).collect();
+        assert_eq!(sub, vec![b, b1]);
+        assert!(discourse.thread(99).is_err());
+    }
+
+    #[test]
+    fn tags_are_counted_most_used_first() {
+        let mut discourse = discourse();
+        discourse.submit("ada", "Plant trees", &["green", "Parks"]).unwrap();
+        discourse.submit("bob", "Fix the benches", &["parks", "repairs"]).unwrap();
+        discourse.submit("cy", "Compost bins", &["#green", "parks"]).unwrap();
+        assert_eq!(discourse.tags(), vec![
+            ("parks".to_string(), 3),
+            ("green".to_string(), 2),
+            ("repairs".to_string(), 1),
+        ]);
+    }
+
+    #[test]
+    fn save_load_round_trip_recovers_next_id() {
+        let path = std::env::temp_dir().join(format!("discourse-{}-{:?}.json", std::process::id(), std::thread::current().id()));
+        let mut discourse = discourse();
+        let root = discourse.submit("ada", "Plant more trees", &["green"]).unwrap();
+        let reply = discourse.reply(root, "bob", "Fruit trees please", &[]).unwrap();
+        discourse.edit(reply, "bob", "Fruit trees, please", &["food"]).unwrap();
+        discourse.react(root, "cy", Reaction::Mercy).unwrap();
+        discourse.save(&path).unwrap();
+
+        let gate = Arc::new(MercyGate::new(MercyPolicy::default(), LocalTruth::shared()));
+        let mut loaded = Discourse::load(&path, gate).unwrap();
+        assert_eq!(loaded.len(), 2);
+        let (before, after) = (discourse.get(reply).unwrap(), loaded.get(reply).unwrap());
+        assert_eq!((&after.body, &after.tags, after.parent, after.root), (&before.body, &before.tags, before.parent, before.root));
+        assert_eq!(after.history[0].body, "Fruit trees please");
+        assert_eq!(after.created_at, before.created_at);
+        assert_eq!(loaded.get(root).unwrap().replies, vec![reply]);
+        assert_eq!(loaded.get(root).unwrap().reaction_count(Reaction::Mercy), 1);
+
+        assert_eq!(loaded.submit("dee", "Plant hedges too", &[]).unwrap(), 2);
+        fs::remove_file(&path).unwrap();
+        assert!(Discourse::load(&path, Arc::new(MercyGate::new(MercyPolicy::default(), LocalTruth::shared()))).is_err());
+    }
+}
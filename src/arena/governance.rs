@@ -0,0 +1,379 @@
+//! Arena Governance — Proposals Through the Full Futarchy Pipeline
+//! Ultramasterful handle: QV on metrics, conditional markets, decision and measurement
+//!
+//! `Arena::futarchy_vote` screens a proposal through the `FutarchyEngine` mercy gate, then
+//! runs it on a `HybridSimulator` in the background. The returned `GovernanceHandle` can be
+//! polled for status, live conditional prices and the final decision, waited on or cancelled.
+//! Traders in both conditional markets act on a belief in the proposal: the config's, else a
+//! fresh reading from the engine's oracle chain, else indifference.
+
+use std::collections::HashMap;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::thread::{self, JoinHandle};
+
+use crate::oracles::oracle_feed::{BeliefOutcome, FailoverChain};
+use crate::qv::Strategy;
+use crate::sim::hybrid_sim::HybridSimulator;
+use crate::truth::Verdict;
+use crate::types::{Credits, Id, Metric, Proposal};
+
+/// Parameters of one governance run
+#[derive(Clone, Debug)]
+pub struct GovernanceConfig {
+    /// Welfare metric options put to the QV phase
+    pub metrics: Vec<String>,
+    /// QV voters on the metric options; they do not trade
+    pub voters: Vec<(Strategy, Credits)>,
+    pub liquidity: f64,
+    /// Each round one trader buys in the pass market and one in the reject market
+    pub trading_rounds: usize,
+    pub initial_metric: Metric,
+    /// Traders' belief that adopting the proposal helps, on the `adoption_share` scale;
+    /// `None` asks the oracle chain, falling back to indifferent (0.5) without one
+    pub belief: Option<f64>,
+}
+
+impl Default for GovernanceConfig {
+    fn default() -> Self {
+        Self {
+            metrics: vec![
+                "Global Welfare Index".to_string(),
+                "Eternal Thriving Index".to_string(),
+                "Quantum Mercy Balance".to_string(),
+            ],
+            voters: vec![(Strategy::Optimal, 100.0); 20],
+            liquidity: 1000.0,
+            trading_rounds: 200,
+            initial_metric: 100.0,
+            belief: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum GovernanceStatus {
+    /// Admitted by the mercy gate, not yet running
+    Queued,
+    MetricVote,
+    Trading { round: usize, of: usize },
+    Deciding,
+    /// Decision made and its impact measured
+    Complete,
+    /// Stopped by `GovernanceHandle::cancel` before a decision
+    Cancelled,
+    Failed(String),
+}
+
+impl GovernanceStatus {
+    pub fn is_done(&self) -> bool {
+        matches!(self, GovernanceStatus::Complete | GovernanceStatus::Cancelled | GovernanceStatus::Failed(_))
+    }
+}
+
+/// Pass / reject conditional market probabilities
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ConditionalPrices {
+    pub pass: f64,
+    pub reject: f64,
+}
+
+#[derive(Clone, Debug)]
+pub struct GovernanceDecision {
+    pub proposal: Proposal,
+    /// Metric option → QV weight
+    pub metric_weights: HashMap<String, f64>,
+    /// Adoption belief the traders acted on
+    pub belief: f64,
+    pub final_prices: ConditionalPrices,
+    pub adopted: bool,
+    pub metric_before: Metric,
+    pub metric_after: Metric,
+}
+
+impl GovernanceDecision {
+    pub fn metric_change(&self) -> Metric {
+        self.metric_after - self.metric_before
+    }
+}
+
+#[derive(Debug)]
+struct RunState {
+    status: GovernanceStatus,
+    prices: Vec<ConditionalPrices>,
+    decision: Option<GovernanceDecision>,
+}
+
+/// The state is only ever replaced field by field, so a panic mid-update leaves it readable
+fn lock(state: &Mutex<RunState>) -> MutexGuard<'_, RunState> {
+    state.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Stop reason returned by `run` when the handle is cancelled
+const CANCELLED: &str = "Governance run cancelled";
+
+/// Poll-able view of a running governance pipeline (cheap to clone)
+#[derive(Clone)]
+pub struct GovernanceHandle {
+    pub proposal: Proposal,
+    /// Mercy gate decision that admitted the proposal
+    pub mercy_decision: u64,
+    /// Truth engine verdict on the proposal as a conditional market
+    pub verdict: Verdict,
+    state: Arc<Mutex<RunState>>,
+    cancel: Arc<AtomicBool>,
+    worker: Arc<Mutex<Option<JoinHandle<()>>>>,
+}
+
+impl GovernanceHandle {
+    pub(crate) fn spawn(
+        proposal: Proposal,
+        mercy_decision: u64,
+        verdict: Verdict,
+        config: GovernanceConfig,
+        oracles: Option<Arc<FailoverChain>>,
+    ) -> Self {
+        let state = Arc::new(Mutex::new(RunState { status: GovernanceStatus::Queued, prices: Vec::new(), decision: None }));
+        let cancel = Arc::new(AtomicBool::new(false));
+        let (shared, stop) = (state.clone(), cancel.clone());
+        let run_proposal = proposal.clone();
+        let worker = thread::spawn(move || {
+            let status = match panic::catch_unwind(AssertUnwindSafe(|| run(&shared, &stop, run_proposal, config, oracles))) {
+                Ok(Ok(())) => return,
+                Ok(Err(e)) if e == CANCELLED => GovernanceStatus::Cancelled,
+                Ok(Err(e)) => GovernanceStatus::Failed(e),
+                Err(payload) => {
+                    let reason = payload.downcast_ref::<&str>().map(|s| s.to_string())
+                        .or_else(|| payload.downcast_ref::<String>().cloned())
+                        .unwrap_or_else(|| "unknown panic".to_string());
+                    GovernanceStatus::Failed(format!("Governance run panicked: {}", reason))
+                }
+            };
+            set_status(&shared, status);
+        });
+        GovernanceHandle {
+            proposal,
+            mercy_decision,
+            verdict,
+            state,
+            cancel,
+            worker: Arc::new(Mutex::new(Some(worker))),
+        }
+    }
+
+    pub fn status(&self) -> GovernanceStatus {
+        lock(&self.state).status.clone()
+    }
+
+    /// Latest conditional prices, once trading has begun
+    pub fn prices(&self) -> Option<ConditionalPrices> {
+        lock(&self.state).prices.last().copied()
+    }
+
+    /// Prices after every trading round
+    pub fn price_history(&self) -> Vec<ConditionalPrices> {
+        lock(&self.state).prices.clone()
+    }
+
+    pub fn decision(&self) -> Option<GovernanceDecision> {
+        lock(&self.state).decision.clone()
+    }
+
+    /// Ask the run to stop at the next phase or trading round; no decision is made
+    pub fn cancel(&self) {
+        self.cancel.store(true, Ordering::Relaxed);
+    }
+
+    /// Block until the run finishes
+    pub fn wait(&self) -> Result<GovernanceDecision, String> {
+        let worker = self.worker.lock().unwrap_or_else(PoisonError::into_inner).take();
+        if let Some(worker) = worker {
+            worker.join().map_err(|_| "Governance run panicked".to_string())?;
+        }
+        match self.status() {
+            GovernanceStatus::Failed(e) => Err(e),
+            GovernanceStatus::Cancelled => Err(CANCELLED.to_string()),
+            _ => self.decision().ok_or_else(|| "Governance run ended without a decision".to_string()),
+        }
+    }
+}
+
+fn set_status(state: &Mutex<RunState>, status: GovernanceStatus) {
+    log::debug!("Governance: {:?}", status);
+    lock(state).status = status;
+}
+
+fn check_cancel(cancel: &AtomicBool) -> Result<(), String> {
+    if cancel.load(Ordering::Relaxed) {
+        Err(CANCELLED.to_string())
+    } else {
+        Ok(())
+    }
+}
+
+/// Config belief, else the chain's fresh reading on the proposal, else indifference
+fn resolve_belief(config: Option<f64>, oracles: Option<&FailoverChain>, proposal: &Proposal) -> Result<f64, String> {
+    let belief = match (config, oracles) {
+        (Some(belief), _) => belief,
+        (None, Some(chain)) => {
+            let runtime = tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .map_err(|e| format!("Oracle runtime failed: {}", e))?;
+            match runtime.block_on(chain.fresh_belief(&proposal.description)) {
+                BeliefOutcome::Fresh { reading, .. } => reading.value,
+                BeliefOutcome::NoFreshBelief { attempts } => {
+                    return Err(format!("No fresh oracle belief for {}: {:?}", proposal.description, attempts));
+                }
+            }
+        }
+        (None, None) => 0.5,
+    };
+    if !(0.0..=1.0).contains(&belief) {
+        return Err(format!("Belief {} is outside [0, 1]", belief));
+    }
+    Ok(belief)
+}
+
+fn run(
+    state: &Mutex<RunState>,
+    cancel: &AtomicBool,
+    proposal: Proposal,
+    config: GovernanceConfig,
+    oracles: Option<Arc<FailoverChain>>,
+) -> Result<(), String> {
+    if config.metrics.is_empty() {
+        return Err("Governance run needs at least one metric option".to_string());
+    }
+    let belief = resolve_belief(config.belief, oracles.as_deref(), &proposal)?;
+    let mut sim = HybridSimulator::new(config.initial_metric);
+    let metric_ids: Vec<(Id, String)> = config.metrics.iter()
+        .map(|m| (sim.add_metric_option(m.clone()), m.clone()))
+        .collect();
+    for (strategy, budget) in config.voters {
+        sim.add_voter(strategy, budget);
+    }
+
+    check_cancel(cancel)?;
+    set_status(state, GovernanceStatus::MetricVote);
+    let weights = sim.run_qv_phase();
+
+    let market = sim.submit_proposal(proposal.description.clone(), config.liquidity);
+    for round in 0..config.trading_rounds {
+        check_cancel(cancel)?;
+        set_status(state, GovernanceStatus::Trading { round: round + 1, of: config.trading_rounds });
+        sim.simulate_futarchy_trading(market, 1, belief);
+        let (pass, reject) = sim.prices(market).ok_or("Conditional market missing")?;
+        lock(state).prices.push(ConditionalPrices { pass, reject });
+    }
+
+    check_cancel(cancel)?;
+    set_status(state, GovernanceStatus::Deciding);
+    let (pass, reject) = sim.prices(market).ok_or("Conditional market missing")?;
+    let metric_before = sim.current_metric();
+    let adopted = sim.resolve_proposal(market, &weights);
+
+    let metric_weights = metric_ids.into_iter()
+        .map(|(id, name)| (name, weights.get(&id).copied().unwrap_or(0.0)))
+        .collect();
+    let mut guard = lock(state);
+    guard.decision = Some(GovernanceDecision {
+        proposal,
+        metric_weights,
+        belief,
+        final_prices: ConditionalPrices { pass, reject },
+        adopted,
+        metric_before,
+        metric_after: sim.current_metric(),
+    });
+    guard.status = GovernanceStatus::Complete;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::*;
+
+    fn proposal() -> Proposal {
+        Proposal { id: 0, description: "Fund the commons".to_string() }
+    }
+
+    fn config(trading_rounds: usize) -> GovernanceConfig {
+        GovernanceConfig { voters: vec![(Strategy::Optimal, 100.0); 4], trading_rounds, ..GovernanceConfig::default() }
+    }
+
+    fn poll_until_done(handle: &GovernanceHandle) -> GovernanceStatus {
+        let deadline = Instant::now() + Duration::from_secs(30);
+        loop {
+            let status = handle.status();
+            if status.is_done() {
+                return status;
+            }
+            assert!(Instant::now() < deadline, "governance run stuck at {:?}", status);
+            thread::sleep(Duration::from_millis(5));
+        }
+    }
+
+    #[test]
+    fn polled_run_reaches_complete() {
+        let handle = GovernanceHandle::spawn(proposal(), 0, Verdict::verified(1.0, "ok"), config(5), None);
+        assert_eq!(poll_until_done(&handle), GovernanceStatus::Complete);
+        assert_eq!(handle.price_history().len(), 5);
+        let decision = handle.decision().unwrap();
+        assert_eq!(decision.final_prices, handle.prices().unwrap());
+        assert_eq!(decision.metric_weights.len(), 3);
+        assert_eq!(handle.wait().unwrap().adopted, decision.adopted);
+    }
+
+    #[test]
+    fn cancelled_run_stops_without_a_decision() {
+        let handle = GovernanceHandle::spawn(proposal(), 0, Verdict::verified(1.0, "ok"), config(usize::MAX), None);
+        handle.cancel();
+        assert_eq!(poll_until_done(&handle), GovernanceStatus::Cancelled);
+        assert!(handle.decision().is_none());
+        assert_eq!(handle.wait().unwrap_err(), CANCELLED);
+    }
+
+    #[test]
+    fn failed_runs_and_poisoned_state_are_reported() {
+        let handle = GovernanceHandle::spawn(proposal(), 0, Verdict::verified(1.0, "ok"), GovernanceConfig { metrics: Vec::new(), ..config(1) }, None);
+        assert!(matches!(poll_until_done(&handle), GovernanceStatus::Failed(_)));
+
+        // A panic while holding the state lock must not take polling down with it
+        let state = handle.state.clone();
+        let _ = thread::spawn(move || {
+            let _guard = state.lock().unwrap();
+            panic!("poison the run state");
+        }).join();
+        assert!(handle.state.is_poisoned());
+        assert!(matches!(handle.status(), GovernanceStatus::Failed(_)));
+        assert_eq!(handle.wait().unwrap_err(), "Governance run needs at least one metric option");
+    }
+
+    #[test]
+    fn belief_drives_both_conditional_markets() {
+        let run = |belief| {
+            let config = GovernanceConfig { belief: Some(belief), ..config(200) };
+            GovernanceHandle::spawn(proposal(), 0, Verdict::verified(1.0, "ok"), config, None).wait().unwrap()
+        };
+
+        let rejected = run(0.1);
+        assert!(!rejected.adopted);
+        assert!(rejected.final_prices.reject > 0.5 && rejected.final_prices.pass < 0.5);
+        assert_eq!(rejected.belief, 0.1);
+
+        let adopted = run(0.9);
+        assert!(adopted.adopted);
+        assert!(adopted.final_prices.pass > 0.5 && adopted.final_prices.reject < 0.5);
+    }
+
+    #[test]
+    fn out_of_range_beliefs_fail_the_run() {
+        let config = GovernanceConfig { belief: Some(1.5), ..config(1) };
+        let handle = GovernanceHandle::spawn(proposal(), 0, Verdict::verified(1.0, "ok"), config, None);
+        assert_eq!(handle.wait().unwrap_err(), "Belief 1.5 is outside [0, 1]");
+    }
+}
@@ -0,0 +1,357 @@
+//! Idea Slam — Timed Pitches, Bracket Elimination, QV Audience Votes
+//! Ultramasterful ideas tournament: the best pitch survives the bracket
+//!
+//! Entries pass the idea-slam mercy gate and are seeded into a single-elimination bracket
+//! (byes fill it to a power of two). In each matchup both contestants pitch against the
+//! clock, then the audience splits quadratic voice credits between them; overtime costs a
+//! share of the pitcher's votes.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::arena::discourse::{Discourse, Idea};
+use crate::mercy::MercyGate;
+use crate::qv;
+use crate::types::{Credits, Id, Votes};
+
+#[derive(Clone, Debug)]
+pub struct SlamRules {
+    pub pitch_secs: f64,
+    /// Fraction of votes lost by a pitch that runs over time
+    pub overtime_penalty: f64,
+    /// Quadratic budget per audience member per matchup
+    pub voice_credits: Credits,
+}
+
+impl Default for SlamRules {
+    /// 3-minute pitches, 25% overtime penalty, 25 voice credits per matchup
+    fn default() -> Self {
+        Self { pitch_secs: 180.0, overtime_penalty: 0.25, voice_credits: 25.0 }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Matchup {
+    pub a: String,
+    /// None is a bye
+    pub b: Option<String>,
+    /// Seconds taken per contestant
+    pub pitches: HashMap<String, f64>,
+    /// Votes per audience member per contestant
+    pub ballots: HashMap<String, HashMap<String, Votes>>,
+    pub winner: Option<String>,
+}
+
+impl Matchup {
+    fn new(a: String, b: Option<String>) -> Self {
+        Self { a, b, pitches: HashMap::new(), ballots: HashMap::new(), winner: None }
+    }
+
+    pub fn contestants(&self) -> Vec<&str> {
+        std::iter::once(self.a.as_str()).chain(self.b.as_deref()).collect()
+    }
+
+    fn raw_votes(&self, contestant: &str) -> Votes {
+        self.ballots.values().map(|b| b.get(contestant).copied().unwrap_or(0.0)).sum()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum SlamPhase {
+    Entries,
+    Live { round: usize, matchup: usize },
+    Finished,
+}
+
+pub struct IdeaSlam {
+    rules: SlamRules,
+    discourse: Discourse,
+    /// Contestant → pitch idea, in seed order
+    entries: Vec<(String, Id)>,
+    bracket: Vec<Vec<Matchup>>,
+    phase: SlamPhase,
+}
+
+impl IdeaSlam {
+    /// `gate` should carry the idea-slam mercy policy
+    pub fn new(rules: SlamRules, gate: Arc<MercyGate>) -> Self {
+        IdeaSlam { rules, discourse: Discourse::new(gate), entries: Vec::new(), bracket: Vec::new(), phase: SlamPhase::Entries }
+    }
+
+    pub fn rules(&self) -> &SlamRules {
+        &self.rules
+    }
+
+    pub fn phase(&self) -> &SlamPhase {
+        &self.phase
+    }
+
+    /// Enter a pitch; one entry per contestant, screened by the mercy gate
+    pub fn enter(&mut self, contestant: &str, pitch: &str) -> Result<Id, String> {
+        if self.phase != SlamPhase::Entries {
+            return Err("Entries are closed".to_string());
+        }
+        if self.entries.iter().any(|(c, _)| c == contestant) {
+            return Err(format!("{} already entered", contestant));
+        }
+        let id = self.discourse.submit(contestant, pitch, &["idea-slam"])?;
+        self.entries.push((contestant.to_string(), id));
+        Ok(id)
+    }
+
+    pub fn pitch_of(&self, contestant: &str) -> Option<&Idea> {
+        self.entries.iter().find(|(c, _)| c == contestant).and_then(|(_, id)| self.discourse.get(*id))
+    }
+
+    /// Close entries and seed the first round; byes go to the top seeds
+    pub fn start(&mut self) -> Result<(), String> {
+        if self.phase != SlamPhase::Entries {
+            return Err("Idea slam already started".to_string());
+        }
+        if self.entries.len() < 2 {
+            return Err("An idea slam needs at least two entries".to_string());
+        }
+        let size = self.entries.len().next_power_of_two();
+        let seeds: Vec<Option<String>> = self.entries.iter().map(|(c, _)| Some(c.clone()))
+            .chain(std::iter::repeat_n(None, size - self.entries.len()))
+            .collect();
+        // Seed i meets seed size-1-i, so byes pair with the top seeds
+        let round = (0..size / 2)
+            .map(|i| Matchup::new(seeds[i].clone().expect("top half is filled"), seeds[size - 1 - i].clone()))
+            .collect();
+        self.bracket.push(round);
+        self.phase = SlamPhase::Live { round: 0, matchup: 0 };
+        self.settle_byes();
+        Ok(())
+    }
+
+    pub fn bracket(&self) -> &[Vec<Matchup>] {
+        &self.bracket
+    }
+
+    pub fn current_matchup(&self) -> Option<&Matchup> {
+        match self.phase {
+            SlamPhase::Live { round, matchup } => Some(&self.bracket[round][matchup]),
+            _ => None,
+        }
+    }
+
+    fn current_mut(&mut self) -> Result<&mut Matchup, String> {
+        match self.phase {
+            SlamPhase::Live { round, matchup } => Ok(&mut self.bracket[round][matchup]),
+            _ => Err("No matchup in progress".to_string()),
+        }
+    }
+
+    /// Record `contestant`'s pitch in the current matchup, taking `secs` on the clock
+    pub fn pitch(&mut self, contestant: &str, secs: f64) -> Result<bool, String> {
+        let limit = self.rules.pitch_secs;
+        let matchup = self.current_mut()?;
+        if !matchup.contestants().contains(&contestant) {
+            return Err(format!("{} is not in this matchup", contestant));
+        }
+        if matchup.pitches.contains_key(contestant) {
+            return Err(format!("{} already pitched", contestant));
+        }
+        matchup.pitches.insert(contestant.to_string(), secs);
+        Ok(secs <= limit)
+    }
+
+    /// Split quadratic voice credits between the current contestants; returns unspent credits
+    pub fn vote(&mut self, voter: &str, votes: &[(&str, Votes)]) -> Result<Credits, String> {
+        let budget = self.rules.voice_credits;
+        let matchup = self.current_mut()?;
+        if matchup.contestants().iter().any(|c| !matchup.pitches.contains_key(*c)) {
+            return Err("Voting opens after both pitches".to_string());
+        }
+        let mut ballot: HashMap<String, Votes> = HashMap::new();
+        for &(contestant, v) in votes {
+            if !matchup.contestants().contains(&contestant) {
+                return Err(format!("{} is not in this matchup", contestant));
+            }
+            if v < 0.0 {
+                return Err(format!("Negative votes for {}", contestant));
+            }
+            *ballot.entry(contestant.to_string()).or_insert(0.0) += v;
+        }
+        let cost: Credits = ballot.values().map(|&v| qv::quadratic_cost(v)).sum();
+        if cost > budget + 1e-9 {
+            return Err(format!("Ballot costs {:.2} credits, budget is {:.2}", cost, budget));
+        }
+        matchup.ballots.insert(voter.to_string(), ballot);
+        Ok(budget - cost)
+    }
+
+    /// Votes after the overtime penalty
+    pub fn counted_votes(&self, matchup: &Matchup, contestant: &str) -> Votes {
+        let over = matchup.pitches.get(contestant).is_some_and(|&s| s > self.rules.pitch_secs);
+        let factor = if over { 1.0 - self.rules.overtime_penalty } else { 1.0 };
+        matchup.raw_votes(contestant) * factor
+    }
+
+    /// Decide the current matchup (ties go to the higher seed) and move on
+    pub fn close_matchup(&mut self) -> Result<String, String> {
+        let matchup = self.current_matchup().ok_or("No matchup in progress")?;
+        if matchup.contestants().iter().any(|c| !matchup.pitches.contains_key(*c)) {
+            return Err("Both contestants must pitch first".to_string());
+        }
+        let winner = match &matchup.b {
+            Some(b) if self.counted_votes(matchup, b) > self.counted_votes(matchup, &matchup.a) => b.clone(),
+            _ => matchup.a.clone(),
+        };
+        self.current_mut()?.winner = Some(winner.clone());
+        self.advance();
+        self.settle_byes();
+        Ok(winner)
+    }
+
+    /// Byes win without pitching
+    fn settle_byes(&mut self) {
+        while let Some(matchup) = self.current_matchup() {
+            if matchup.b.is_some() {
+                break;
+            }
+            let winner = matchup.a.clone();
+            if let Ok(m) = self.current_mut() {
+                m.winner = Some(winner);
+            }
+            self.advance();
+        }
+    }
+
+    fn advance(&mut self) {
+        let SlamPhase::Live { round, matchup } = self.phase else {
+            return;
+        };
+        if matchup + 1 < self.bracket[round].len() {
+            self.phase = SlamPhase::Live { round, matchup: matchup + 1 };
+        } else {
+            let winners: Vec<String> = self.bracket[round].iter().filter_map(|m| m.winner.clone()).collect();
+            if winners.len() == 1 {
+                self.phase = SlamPhase::Finished;
+                return;
+            }
+            let next = winners.chunks(2)
+                .map(|pair| Matchup::new(pair[0].clone(), pair.get(1).cloned()))
+                .collect();
+            self.bracket.push(next);
+            self.phase = SlamPhase::Live { round: round + 1, matchup: 0 };
+        }
+    }
+
+    pub fn champion(&self) -> Option<&str> {
+        match self.phase {
+            SlamPhase::Finished => self.bracket.last()?.first()?.winner.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mercy::MercyPolicy;
+    use crate::truth::LocalTruth;
+
+    fn slam(contestants: &[&str]) -> IdeaSlam {
+        let gate = Arc::new(MercyGate::new(MercyPolicy::default(), LocalTruth::shared()));
+        let mut slam = IdeaSlam::new(SlamRules::default(), gate);
+        for contestant in contestants {
+            slam.enter(contestant, &format!("{} pitches a community tool library", contestant)).unwrap();
+        }
+        slam
+    }
+
+    fn pairs(round: &[Matchup]) -> Vec<(&str, Option<&str>)> {
+        round.iter().map(|m| (m.a.as_str(), m.b.as_deref())).collect()
+    }
+
+    /// Both contestants pitch on time; each `(contestant, votes)` comes from its own voter
+    fn decide(slam: &mut IdeaSlam, votes: &[(&str, Votes)]) -> String {
+        for contestant in slam.current_matchup().unwrap().contestants().iter().map(|c| c.to_string()).collect::<Vec<_>>() {
+            slam.pitch(&contestant, 120.0).unwrap();
+        }
+        for (i, &(contestant, v)) in votes.iter().enumerate() {
+            slam.vote(&format!("voter{}", i), &[(contestant, v)]).unwrap();
+        }
+        slam.close_matchup().unwrap()
+    }
+
+    #[test]
+    fn three_entries_seed_one_bye() {
+        let mut slam = slam(&["ana", "ben", "cat"]);
+        assert!(slam.enter("ana", "Second pitch").is_err());
+        slam.start().unwrap();
+        assert!(slam.enter("dee", "Too late").is_err());
+
+        // Seed 1 meets the bye and is settled straight away
+        assert_eq!(pairs(&slam.bracket()[0]), vec![("ana", None), ("ben", Some("cat"))]);
+        assert_eq!(slam.bracket()[0][0].winner.as_deref(), Some("ana"));
+        assert!(slam.bracket()[0][0].pitches.is_empty());
+        assert_eq!(slam.phase(), &SlamPhase::Live { round: 0, matchup: 1 });
+
+        assert_eq!(decide(&mut slam, &[("cat", 2.0)]), "cat");
+        assert_eq!(pairs(&slam.bracket()[1]), vec![("ana", Some("cat"))]);
+        assert!(slam.champion().is_none());
+        assert_eq!(decide(&mut slam, &[("ana", 1.0)]), "ana");
+        assert_eq!(slam.champion(), Some("ana"));
+    }
+
+    #[test]
+    fn five_entries_give_byes_to_top_three_seeds() {
+        let mut slam = slam(&["ana", "ben", "cat", "dan", "eve"]);
+        slam.start().unwrap();
+        assert_eq!(pairs(&slam.bracket()[0]), vec![("ana", None), ("ben", None), ("cat", None), ("dan", Some("eve"))]);
+        let byes: Vec<Option<&str>> = slam.bracket()[0][..3].iter().map(|m| m.winner.as_deref()).collect();
+        assert_eq!(byes, vec![Some("ana"), Some("ben"), Some("cat")]);
+        assert_eq!(slam.phase(), &SlamPhase::Live { round: 0, matchup: 3 });
+
+        assert_eq!(decide(&mut slam, &[("eve", 3.0)]), "eve");
+        assert_eq!(pairs(&slam.bracket()[1]), vec![("ana", Some("ben")), ("cat", Some("eve"))]);
+        assert_eq!(decide(&mut slam, &[("ben", 2.0), ("ana", 1.0)]), "ben");
+        // No votes at all: a tie, so the higher seed goes through
+        assert_eq!(decide(&mut slam, &[]), "cat");
+        assert_eq!(pairs(&slam.bracket()[2]), vec![("ben", Some("cat"))]);
+        assert_eq!(decide(&mut slam, &[("cat", 4.0)]), "cat");
+        assert_eq!(slam.phase(), &SlamPhase::Finished);
+        assert_eq!(slam.champion(), Some("cat"));
+        assert!(slam.close_matchup().is_err());
+    }
+
+    #[test]
+    fn overtime_penalty_can_force_a_tie_that_the_higher_seed_wins() {
+        let mut slam = slam(&["ana", "ben"]);
+        assert!(IdeaSlam::new(SlamRules::default(), Arc::new(MercyGate::new(MercyPolicy::default(), LocalTruth::shared()))).start().is_err());
+        slam.start().unwrap();
+        assert_eq!(slam.vote("v", &[("ana", 1.0)]).unwrap_err(), "Voting opens after both pitches");
+        assert!(!slam.pitch("ana", 200.0).unwrap());
+        assert!(slam.pitch("ana", 100.0).is_err());
+        assert!(slam.pitch("zed", 100.0).is_err());
+        assert!(slam.close_matchup().is_err());
+        assert!(slam.pitch("ben", 180.0).unwrap());
+
+        // 4² + 3² spends the full 25 credits
+        assert_eq!(slam.vote("v", &[("ana", 4.0), ("ben", 3.0)]).unwrap(), 0.0);
+        let matchup = slam.current_matchup().unwrap();
+        // 4 raw votes lose 25% for running over: 3 vs 3
+        assert_eq!(slam.counted_votes(matchup, "ana"), 3.0);
+        assert_eq!(slam.counted_votes(matchup, "ben"), 3.0);
+        assert_eq!(slam.close_matchup().unwrap(), "ana");
+        assert_eq!(slam.champion(), Some("ana"));
+    }
+
+    #[test]
+    fn ballots_respect_the_quadratic_budget() {
+        let mut slam = slam(&["ana", "ben"]);
+        slam.start().unwrap();
+        slam.pitch("ana", 60.0).unwrap();
+        slam.pitch("ben", 60.0).unwrap();
+        assert_eq!(slam.vote("v", &[("ana", 5.0), ("ben", 1.0)]).unwrap_err(), "Ballot costs 26.00 credits, budget is 25.00");
+        // Split entries for one contestant are summed before squaring
+        assert!(slam.vote("v", &[("ana", 3.0), ("ana", 2.0), ("ben", 0.5)]).is_err());
+        assert_eq!(slam.vote("v", &[("ana", 3.0), ("ana", 1.0)]).unwrap(), 9.0);
+        assert!(slam.vote("w", &[("ana", -1.0)]).is_err());
+        assert!(slam.vote("w", &[("zed", 1.0)]).is_err());
+        assert_eq!(slam.current_matchup().unwrap().ballots.len(), 1);
+    }
+}
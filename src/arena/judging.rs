@@ -0,0 +1,422 @@
+//! Debate Judging — Judge Scorecards, QV Audience Ballots and Opinion Shift
+//! Ultramasterful "Grok Summary & Scorecard": every number behind the verdict is on record
+//!
+//! Judges score each side per weighted criterion; the audience votes with quadratic voice
+//! credits (cost = votes², as in `qv`) and answers the motion before and after the debate.
+//! `Judging::scorecard` combines the three into a final report.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::arena::debate::Role;
+use crate::qv;
+use crate::types::{Credits, Votes};
+
+/// Judged sides
+pub const SIDES: [Role; 2] = [Role::Affirmative, Role::Negative];
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Criterion {
+    pub name: String,
+    pub weight: f64,
+    pub max_score: f64,
+}
+
+impl Criterion {
+    pub fn new(name: &str, weight: f64, max_score: f64) -> Self {
+        Self { name: name.to_string(), weight, max_score }
+    }
+}
+
+/// Argument, evidence, rebuttal, delivery and mercy (civility), each out of 10
+pub fn default_criteria() -> Vec<Criterion> {
+    vec![
+        Criterion::new("Argument", 0.3, 10.0),
+        Criterion::new("Evidence", 0.25, 10.0),
+        Criterion::new("Rebuttal", 0.2, 10.0),
+        Criterion::new("Delivery", 0.15, 10.0),
+        Criterion::new("Mercy", 0.1, 10.0),
+    ]
+}
+
+/// One judge's scores for one side
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct JudgeScorecard {
+    pub judge: String,
+    pub side: Role,
+    pub scores: HashMap<String, f64>,
+    pub notes: String,
+}
+
+/// Audience answer to the motion
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Stance {
+    For,
+    Against,
+    Undecided,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AudienceBallot {
+    pub voter: String,
+    pub votes: HashMap<Role, Votes>,
+    pub credits_spent: Credits,
+}
+
+/// Share of the audience per stance, before and after
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct OpinionShift {
+    pub respondents: usize,
+    pub pre_for: f64,
+    pub pre_against: f64,
+    pub post_for: f64,
+    pub post_against: f64,
+    /// Change in the For share, in percentage points (positive favours Affirmative)
+    pub shift_points: f64,
+    /// Respondents whose stance changed toward Affirmative / Negative
+    pub moved_for: usize,
+    pub moved_against: usize,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Scorecard {
+    pub topic: String,
+    /// Weighted judge score per side, 0–100, averaged over judges
+    pub judge_scores: HashMap<Role, f64>,
+    /// Average raw score per side and criterion
+    pub criterion_averages: HashMap<Role, HashMap<String, f64>>,
+    pub judges: usize,
+    pub audience_votes: HashMap<Role, Votes>,
+    pub ballots: usize,
+    pub credits_spent: Credits,
+    pub opinion: OpinionShift,
+    pub judges_winner: Option<Role>,
+    pub audience_winner: Option<Role>,
+    pub shift_winner: Option<Role>,
+    /// Majority of the three decisions above; None when undecided
+    pub winner: Option<Role>,
+}
+
+fn leader(a: f64, n: f64) -> Option<Role> {
+    if a > n {
+        Some(Role::Affirmative)
+    } else if n > a {
+        Some(Role::Negative)
+    } else {
+        None
+    }
+}
+
+impl fmt::Display for Scorecard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = |r: Option<Role>| r.map_or("Tie".to_string(), |r| format!("{:?}", r));
+        writeln!(f, "=== Grok Summary & Scorecard ===")?;
+        writeln!(f, "Topic: {}", self.topic)?;
+        writeln!(f, "Judges ({}):", self.judges)?;
+        for side in SIDES {
+            writeln!(f, "  {:?}: {:.1}/100", side, self.judge_scores.get(&side).copied().unwrap_or(0.0))?;
+            if let Some(criteria) = self.criterion_averages.get(&side) {
+                let mut names: Vec<&String> = criteria.keys().collect();
+                names.sort();
+                for criterion in names {
+                    writeln!(f, "    {}: {:.2}", criterion, criteria[criterion])?;
+                }
+            }
+        }
+        writeln!(f, "Audience QV ({} ballots, {:.1} credits):", self.ballots, self.credits_spent)?;
+        for side in SIDES {
+            writeln!(f, "  {:?}: {:.2} votes", side, self.audience_votes.get(&side).copied().unwrap_or(0.0))?;
+        }
+        writeln!(f, "Opinion ({} respondents): For {:.1}% → {:.1}%, Against {:.1}% → {:.1}% (shift {:+.1} pts)",
+            self.opinion.respondents,
+            self.opinion.pre_for * 100.0, self.opinion.post_for * 100.0,
+            self.opinion.pre_against * 100.0, self.opinion.post_against * 100.0,
+            self.opinion.shift_points)?;
+        writeln!(f, "Judges: {} | Audience: {} | Shift: {}", name(self.judges_winner), name(self.audience_winner), name(self.shift_winner))?;
+        write!(f, "Winner: {}", name(self.winner))
+    }
+}
+
+pub struct Judging {
+    criteria: Vec<Criterion>,
+    voice_credits: Credits,
+    scorecards: Vec<JudgeScorecard>,
+    ballots: HashMap<String, AudienceBallot>,
+    pre: HashMap<String, Stance>,
+    post: HashMap<String, Stance>,
+}
+
+impl Judging {
+    /// `voice_credits` is each audience member's quadratic budget
+    pub fn new(criteria: Vec<Criterion>, voice_credits: Credits) -> Result<Self, String> {
+        if criteria.is_empty() || criteria.iter().any(|c| c.weight < 0.0 || c.max_score <= 0.0) {
+            return Err("Criteria need non-negative weights and positive max scores".to_string());
+        }
+        if criteria.iter().map(|c| c.weight).sum::<f64>() <= 0.0 {
+            return Err("Criteria weights sum to zero".to_string());
+        }
+        Ok(Judging {
+            criteria,
+            voice_credits,
+            scorecards: Vec::new(),
+            ballots: HashMap::new(),
+            pre: HashMap::new(),
+            post: HashMap::new(),
+        })
+    }
+
+    pub fn criteria(&self) -> &[Criterion] {
+        &self.criteria
+    }
+
+    pub fn voice_credits(&self) -> Credits {
+        self.voice_credits
+    }
+
+    /// Record (or replace) `judge`'s scores for `side`; every criterion must be scored in range
+    pub fn submit_scorecard(&mut self, judge: &str, side: Role, scores: &[(&str, f64)], notes: &str) -> Result<(), String> {
+        if side == Role::Moderator {
+            return Err("The moderator is not judged".to_string());
+        }
+        let scores: HashMap<String, f64> = scores.iter().map(|(c, s)| (c.to_string(), *s)).collect();
+        for criterion in &self.criteria {
+            let score = scores.get(&criterion.name)
+                .ok_or_else(|| format!("{} did not score {}", judge, criterion.name))?;
+            if !(0.0..=criterion.max_score).contains(score) {
+                return Err(format!("{} score {} outside 0–{}", criterion.name, score, criterion.max_score));
+            }
+        }
+        if let Some(unknown) = scores.keys().find(|c| !self.criteria.iter().any(|k| &k.name == *c)) {
+            return Err(format!("Unknown criterion: {}", unknown));
+        }
+        self.scorecards.retain(|s| !(s.judge == judge && s.side == side));
+        self.scorecards.push(JudgeScorecard { judge: judge.to_string(), side, scores, notes: notes.to_string() });
+        Ok(())
+    }
+
+    /// Cast (or replace) `voter`'s quadratic ballot; votes are non-negative support per side
+    pub fn cast_ballot(&mut self, voter: &str, votes: &[(Role, Votes)]) -> Result<Credits, String> {
+        let mut tally: HashMap<Role, Votes> = HashMap::new();
+        for &(side, v) in votes {
+            if side == Role::Moderator {
+                return Err("Cannot vote for the moderator".to_string());
+            }
+            if v < 0.0 {
+                return Err(format!("Negative votes for {:?}", side));
+            }
+            *tally.entry(side).or_insert(0.0) += v;
+        }
+        let cost: Credits = tally.values().map(|&v| qv::quadratic_cost(v)).sum();
+        if cost > self.voice_credits + 1e-9 {
+            return Err(format!("Ballot costs {:.2} credits, budget is {:.2}", cost, self.voice_credits));
+        }
+        self.ballots.insert(voter.to_string(), AudienceBallot { voter: voter.to_string(), votes: tally, credits_spent: cost });
+        Ok(self.voice_credits - cost)
+    }
+
+    /// Spend the whole budget on one side
+    pub fn cast_full_ballot(&mut self, voter: &str, side: Role) -> Result<Credits, String> {
+        self.cast_ballot(voter, &[(side, qv::affordable_votes(self.voice_credits))])
+    }
+
+    pub fn record_pre(&mut self, voter: &str, stance: Stance) {
+        self.pre.insert(voter.to_string(), stance);
+    }
+
+    pub fn record_post(&mut self, voter: &str, stance: Stance) {
+        self.post.insert(voter.to_string(), stance);
+    }
+
+    /// Shift among respondents who answered both polls
+    pub fn opinion_shift(&self) -> OpinionShift {
+        let pairs: Vec<(Stance, Stance)> = self.pre.iter()
+            .filter_map(|(voter, &before)| self.post.get(voter).map(|&after| (before, after)))
+            .collect();
+        let n = pairs.len();
+        if n == 0 {
+            return OpinionShift::default();
+        }
+        let share = |pick: fn(&(Stance, Stance)) -> Stance, stance: Stance| {
+            pairs.iter().filter(|p| pick(p) == stance).count() as f64 / n as f64
+        };
+        let lean = |s: Stance| match s {
+            Stance::For => 1,
+            Stance::Undecided => 0,
+            Stance::Against => -1,
+        };
+        let pre_for = share(|p| p.0, Stance::For);
+        let post_for = share(|p| p.1, Stance::For);
+        OpinionShift {
+            respondents: n,
+            pre_for,
+            pre_against: share(|p| p.0, Stance::Against),
+            post_for,
+            post_against: share(|p| p.1, Stance::Against),
+            shift_points: (post_for - pre_for) * 100.0,
+            moved_for: pairs.iter().filter(|(b, a)| lean(*a) > lean(*b)).count(),
+            moved_against: pairs.iter().filter(|(b, a)| lean(*a) < lean(*b)).count(),
+        }
+    }
+
+    /// Judge score for `side` on a 0–100 scale, averaged over judges
+    fn judge_score(&self, side: Role) -> f64 {
+        let total_weight: f64 = self.criteria.iter().map(|c| c.weight).sum();
+        let cards: Vec<&JudgeScorecard> = self.scorecards.iter().filter(|s| s.side == side).collect();
+        if cards.is_empty() {
+            return 0.0;
+        }
+        cards.iter()
+            .map(|card| self.criteria.iter()
+                .map(|c| c.weight * card.scores[&c.name] / c.max_score)
+                .sum::<f64>() / total_weight * 100.0)
+            .sum::<f64>() / cards.len() as f64
+    }
+
+    pub fn scorecard(&self, topic: &str) -> Scorecard {
+        let mut judge_scores = HashMap::new();
+        let mut criterion_averages = HashMap::new();
+        let mut audience_votes = HashMap::new();
+        for side in SIDES {
+            judge_scores.insert(side, self.judge_score(side));
+            let cards: Vec<&JudgeScorecard> = self.scorecards.iter().filter(|s| s.side == side).collect();
+            if !cards.is_empty() {
+                let averages = self.criteria.iter()
+                    .map(|c| (c.name.clone(), cards.iter().map(|s| s.scores[&c.name]).sum::<f64>() / cards.len() as f64))
+                    .collect();
+                criterion_averages.insert(side, averages);
+            }
+            audience_votes.insert(side, self.ballots.values().map(|b| b.votes.get(&side).copied().unwrap_or(0.0)).sum::<Votes>());
+        }
+        let mut judges: Vec<&str> = self.scorecards.iter().map(|s| s.judge.as_str()).collect();
+        judges.sort();
+        judges.dedup();
+
+        let opinion = self.opinion_shift();
+        let judges_winner = leader(judge_scores[&Role::Affirmative], judge_scores[&Role::Negative]);
+        let audience_winner = leader(audience_votes[&Role::Affirmative], audience_votes[&Role::Negative]);
+        let shift_winner = leader(opinion.shift_points, -opinion.shift_points);
+        let decisions = [judges_winner, audience_winner, shift_winner];
+        let count = |side: Role| decisions.iter().filter(|d| **d == Some(side)).count() as f64;
+        let winner = leader(count(Role::Affirmative), count(Role::Negative));
+
+        Scorecard {
+            topic: topic.to_string(),
+            judge_scores,
+            criterion_averages,
+            judges: judges.len(),
+            audience_votes,
+            ballots: self.ballots.len(),
+            credits_spent: self.ballots.values().map(|b| b.credits_spent).sum(),
+            opinion,
+            judges_winner,
+            audience_winner,
+            shift_winner,
+            winner,
+        }
+    }
+}
+
+impl Default for Judging {
+    /// Default criteria, 100 voice credits per audience member
+    fn default() -> Self {
+        Self::new(default_criteria(), 100.0).expect("default criteria are valid")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    fn scores(values: [f64; 5]) -> Vec<(&'static str, f64)> {
+        ["Argument", "Evidence", "Rebuttal", "Delivery", "Mercy"].into_iter().zip(values).collect()
+    }
+
+    #[test]
+    fn judge_scores_are_weighted_and_averaged_over_judges() {
+        let mut judging = Judging::default();
+        // 0.3·1 + 0.25·0.5 + 0.2·0 + 0.15·1 + 0.1·1 = 0.675
+        judging.submit_scorecard("ada", Role::Affirmative, &scores([10.0, 5.0, 0.0, 10.0, 10.0]), "").unwrap();
+        judging.submit_scorecard("bo", Role::Affirmative, &scores([8.0; 5]), "").unwrap();
+        judging.submit_scorecard("ada", Role::Negative, &scores([2.0; 5]), "first pass").unwrap();
+        // Resubmitting replaces the judge's earlier card for that side
+        judging.submit_scorecard("ada", Role::Negative, &scores([6.0; 5]), "").unwrap();
+
+        let card = judging.scorecard("Motion");
+        assert!(close(card.judge_scores[&Role::Affirmative], (67.5 + 80.0) / 2.0));
+        assert!(close(card.judge_scores[&Role::Negative], 60.0));
+        assert!(close(card.criterion_averages[&Role::Affirmative]["Argument"], 9.0));
+        assert!(close(card.criterion_averages[&Role::Affirmative]["Rebuttal"], 4.0));
+        assert_eq!(card.judges, 2);
+        assert_eq!(card.judges_winner, Some(Role::Affirmative));
+    }
+
+    #[test]
+    fn scorecards_must_cover_every_criterion_in_range() {
+        let mut judging = Judging::default();
+        assert!(judging.submit_scorecard("ada", Role::Moderator, &scores([5.0; 5]), "").is_err());
+        assert!(judging.submit_scorecard("ada", Role::Affirmative, &scores([5.0; 5])[..4], "").is_err());
+        assert!(judging.submit_scorecard("ada", Role::Affirmative, &scores([5.0, 5.0, 5.0, 5.0, 10.5]), "").is_err());
+        let mut extra = scores([5.0; 5]);
+        extra.push(("Style", 5.0));
+        assert_eq!(judging.submit_scorecard("ada", Role::Affirmative, &extra, ""), Err("Unknown criterion: Style".to_string()));
+    }
+
+    #[test]
+    fn ballots_cost_votes_squared_within_the_budget() {
+        let mut judging = Judging::default();
+        assert!(close(judging.cast_ballot("ada", &[(Role::Affirmative, 6.0), (Role::Negative, 8.0)]).unwrap(), 0.0));
+        assert_eq!(
+            judging.cast_ballot("bo", &[(Role::Affirmative, 7.0), (Role::Negative, 8.0)]),
+            Err("Ballot costs 113.00 credits, budget is 100.00".to_string()),
+        );
+        // Votes for the same side add up before squaring: (4 + 7)² = 121
+        assert!(judging.cast_ballot("bo", &[(Role::Affirmative, 4.0), (Role::Affirmative, 7.0)]).is_err());
+        assert!(judging.cast_ballot("bo", &[(Role::Negative, -1.0)]).is_err());
+        assert!(judging.cast_ballot("bo", &[(Role::Moderator, 1.0)]).is_err());
+        assert!(close(judging.cast_ballot("bo", &[(Role::Affirmative, 3.0)]).unwrap(), 91.0));
+        // A recast replaces the earlier ballot
+        assert!(close(judging.cast_full_ballot("bo", Role::Negative).unwrap(), 0.0));
+
+        let card = judging.scorecard("Motion");
+        assert_eq!(card.ballots, 2);
+        assert!(close(card.credits_spent, 200.0));
+        assert!(close(card.audience_votes[&Role::Affirmative], 6.0));
+        assert!(close(card.audience_votes[&Role::Negative], 18.0));
+        assert_eq!(card.audience_winner, Some(Role::Negative));
+    }
+
+    #[test]
+    fn winner_is_the_majority_of_judges_audience_and_shift() {
+        let mut judging = Judging::default();
+        judging.submit_scorecard("ada", Role::Affirmative, &scores([9.0; 5]), "").unwrap();
+        judging.submit_scorecard("ada", Role::Negative, &scores([7.0; 5]), "").unwrap();
+        judging.cast_full_ballot("crowd", Role::Negative).unwrap();
+        for (voter, before, after) in [("v1", Stance::Against, Stance::For), ("v2", Stance::Undecided, Stance::Undecided)] {
+            judging.record_pre(voter, before);
+            judging.record_post(voter, after);
+        }
+        let card = judging.scorecard("Motion");
+        assert_eq!((card.judges_winner, card.audience_winner, card.shift_winner),
+            (Some(Role::Affirmative), Some(Role::Negative), Some(Role::Affirmative)));
+        assert!(close(card.opinion.shift_points, 50.0));
+        assert_eq!((card.opinion.moved_for, card.opinion.moved_against), (1, 0));
+        assert_eq!(card.winner, Some(Role::Affirmative));
+
+        // One decision each way and one tie: no winner
+        judging.submit_scorecard("ada", Role::Negative, &scores([9.0; 5]), "").unwrap();
+        judging.record_post("v1", Stance::Against);
+        judging.cast_full_ballot("crowd", Role::Affirmative).unwrap();
+        judging.record_pre("v3", Stance::For);
+        judging.record_post("v3", Stance::Against);
+        let card = judging.scorecard("Motion");
+        assert_eq!((card.judges_winner, card.audience_winner, card.shift_winner),
+            (None, Some(Role::Affirmative), Some(Role::Negative)));
+        assert_eq!(card.winner, None);
+    }
+}
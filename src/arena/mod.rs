@@ -0,0 +1,325 @@
+//! GrokArena — Mercy-Moderated Discourse Engine
+//! Deeper NEXi Integration + Recursive Voting + Voice SoulScan
+
+pub mod debate;
+pub mod debate_markets;
+pub mod discourse;
+pub mod governance;
+pub mod idea_slam;
+pub mod judging;
+pub mod roast;
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::futarchy::FutarchyEngine;
+use crate::mercy::{GateStage, HardReject, HardRejectRule, MercyGate, MercyPolicy, MercyQuantum, PolicyOverride};
+use crate::oracles::oracle_feed::FailoverChain;
+use crate::truth::SharedTruth;
+#[cfg(feature = "voice")]
+use crate::truth::Verdict;
+use crate::types::{Id, Proposal};
+
+use debate::{DebateFormat, DebateSession};
+use debate_markets::DebateMarkets;
+use discourse::{Discourse, Idea};
+use governance::{GovernanceConfig, GovernanceHandle};
+use idea_slam::{IdeaSlam, SlamRules};
+use roast::Roast;
+
+/// Seed collateral for each automatic debate market
+const DEBATE_MARKET_LIQUIDITY: f64 = 1_000.0;
+
+/// Opinion shift (percentage points) asked about by the automatic shift market
+const DEBATE_SHIFT_POINTS: f64 = 5.0;
+
+/// Actor recorded on debate topic mercy decisions
+const DEBATE_MODERATOR: &str = "moderator";
+
+/// Arena formats; each mode moderates through the shared gate with its own policy overrides
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ArenaMode {
+    Discourse,
+    Debate,
+    Roast,
+    IdeaSlam,
+}
+
+impl ArenaMode {
+    pub fn policy_override(&self) -> PolicyOverride {
+        match self {
+            ArenaMode::Discourse | ArenaMode::Debate => PolicyOverride::default(),
+            // Barbs are the point: kindness and gentleness count for less, but no contempt
+            ArenaMode::Roast => PolicyOverride {
+                weights: HashMap::from([(MercyQuantum::Kindness, 0.25), (MercyQuantum::Gentleness, 0.25)]),
+                weighted_threshold: Some(0.55),
+                extra_hard_rejects: vec![HardReject {
+                    rule: HardRejectRule::QuantumBelow { quantum: MercyQuantum::Love, min: 0.6 },
+                    appealable: true,
+                }],
+                ..PolicyOverride::default()
+            },
+            // Pitches are promises: goodness and faithfulness weigh more, the bar is higher
+            ArenaMode::IdeaSlam => PolicyOverride {
+                weights: HashMap::from([(MercyQuantum::Goodness, 2.0), (MercyQuantum::Faithfulness, 1.5)]),
+                weighted_threshold: Some(0.65),
+                ..PolicyOverride::default()
+            },
+        }
+    }
+}
+
+pub struct Arena {
+    truth: SharedTruth,
+    gate: Arc<MercyGate>,
+    discourse: Discourse,
+    debates: Vec<DebateSession>,
+    roasts: Vec<Roast>,
+    slams: Vec<IdeaSlam>,
+    futarchy: FutarchyEngine,
+    governance: Vec<GovernanceHandle>,
+}
+
+impl Arena {
+    /// Ideas are admitted under the default mercy policy until `with_mercy_gate`
+    pub fn new(truth: SharedTruth) -> Self {
+        let gate = Arc::new(MercyGate::new(MercyPolicy::default(), truth.clone()));
+        Arena {
+            futarchy: FutarchyEngine::new(truth.clone()).with_mercy_gate(gate.clone()),
+            truth,
+            discourse: Discourse::new(gate.clone()),
+            gate,
+            debates: Vec::new(),
+            roasts: Vec::new(),
+            slams: Vec::new(),
+            governance: Vec::new(),
+        }
+    }
+
+    /// Replace the mercy gate; only valid before any idea is submitted
+    pub fn with_mercy_gate(mut self, gate: Arc<MercyGate>) -> Self {
+        self.discourse = Discourse::new(gate.clone());
+        let futarchy = std::mem::replace(&mut self.futarchy, FutarchyEngine::new(self.truth.clone()));
+        self.futarchy = futarchy.with_mercy_gate(gate.clone());
+        self.gate = gate;
+        self
+    }
+
+    /// Price governance proposals on fresh beliefs from an oracle failover chain
+    pub fn with_oracles(mut self, chain: FailoverChain) -> Self {
+        let futarchy = std::mem::replace(&mut self.futarchy, FutarchyEngine::new(self.truth.clone()));
+        self.futarchy = futarchy.with_oracles(chain);
+        self
+    }
+
+    /// Resume from a store written by `Discourse::save`
+    pub fn with_discourse(mut self, discourse: Discourse) -> Self {
+        self.discourse = discourse;
+        self
+    }
+
+    pub fn mercy_gate(&self) -> &Arc<MercyGate> {
+        &self.gate
+    }
+
+    pub fn discourse(&self) -> &Discourse {
+        &self.discourse
+    }
+
+    pub fn discourse_mut(&mut self) -> &mut Discourse {
+        &mut self.discourse
+    }
+
+    /// Publish a new idea thread; the stored idea carries its verdict
+    pub fn submit_idea(&mut self, author: &str, idea: &str, tags: &[&str]) -> Result<&Idea, String> {
+        let id = self.discourse.submit(author, idea, tags)?;
+        Ok(self.discourse.get(id).expect("just submitted"))
+    }
+
+    pub fn reply(&mut self, parent: Id, author: &str, reply: &str) -> Result<&Idea, String> {
+        let id = self.discourse.reply(parent, author, reply, &[])?;
+        Ok(self.discourse.get(id).expect("just submitted"))
+    }
+
+    /// Schedule a debate on `topic` with the standard audience-vote and opinion-shift markets
+    pub fn open_debate(&mut self, topic: &str, format: DebateFormat) -> Result<Id, String> {
+        self.admit_topic(topic)?;
+        let markets = DebateMarkets::standard(self.truth.clone(), self.gate.clone(), DEBATE_SHIFT_POINTS, DEBATE_MARKET_LIQUIDITY)?;
+        self.schedule_debate(topic, format, markets)
+    }
+
+    /// Schedule a debate with custom markets; the topic must pass the arena's mercy gate
+    pub fn open_debate_with_markets(&mut self, topic: &str, format: DebateFormat, markets: DebateMarkets) -> Result<Id, String> {
+        self.admit_topic(topic)?;
+        self.schedule_debate(topic, format, markets)
+    }
+
+    fn admit_topic(&self, topic: &str) -> Result<(), String> {
+        self.gate.admit(GateStage::IdeaSubmission, DEBATE_MODERATOR, topic)?;
+        Ok(())
+    }
+
+    fn schedule_debate(&mut self, topic: &str, format: DebateFormat, markets: DebateMarkets) -> Result<Id, String> {
+        self.debates.push(DebateSession::new(topic, format)?.with_markets(markets));
+        Ok(self.debates.len() as Id - 1)
+    }
+
+    pub fn debate(&self, id: Id) -> Option<&DebateSession> {
+        self.debates.get(id as usize)
+    }
+
+    pub fn debate_mut(&mut self, id: Id) -> Option<&mut DebateSession> {
+        self.debates.get_mut(id as usize)
+    }
+
+    /// Arena gate with `mode`'s policy overrides, sharing its scorer and decision log
+    pub fn mode_gate(&self, mode: ArenaMode) -> Arc<MercyGate> {
+        Arc::new(self.gate.overridden(&mode.policy_override()))
+    }
+
+    /// Schedule a roast of `target`; it goes live once the target consents
+    pub fn open_roast(&mut self, target: &str, roasters: &[&str], rounds: usize) -> Result<Id, String> {
+        let roast = Roast::new(target, roasters, rounds, self.mode_gate(ArenaMode::Roast))?;
+        self.roasts.push(roast);
+        Ok(self.roasts.len() as Id - 1)
+    }
+
+    pub fn roast(&self, id: Id) -> Option<&Roast> {
+        self.roasts.get(id as usize)
+    }
+
+    pub fn roast_mut(&mut self, id: Id) -> Option<&mut Roast> {
+        self.roasts.get_mut(id as usize)
+    }
+
+    pub fn open_idea_slam(&mut self, rules: SlamRules) -> Id {
+        self.slams.push(IdeaSlam::new(rules, self.mode_gate(ArenaMode::IdeaSlam)));
+        self.slams.len() as Id - 1
+    }
+
+    pub fn idea_slam(&self, id: Id) -> Option<&IdeaSlam> {
+        self.slams.get(id as usize)
+    }
+
+    pub fn idea_slam_mut(&mut self, id: Id) -> Option<&mut IdeaSlam> {
+        self.slams.get_mut(id as usize)
+    }
+
+    pub fn futarchy(&self) -> &FutarchyEngine {
+        &self.futarchy
+    }
+
+    /// Run `proposal` through the futarchy pipeline with the default governance config
+    pub fn futarchy_vote(&mut self, proposer: &str, proposal: &str) -> Result<GovernanceHandle, String> {
+        self.futarchy_vote_with(proposer, proposal, GovernanceConfig::default())
+    }
+
+    /// Mercy-screen `proposal`, then run QV on metrics, conditional markets, decision and
+    /// measurement in the background; poll the returned handle for progress. Unless
+    /// `config.belief` is set, traders act on the oracle chain's belief in the proposal.
+    pub fn futarchy_vote_with(&mut self, proposer: &str, proposal: &str, config: GovernanceConfig) -> Result<GovernanceHandle, String> {
+        let proposal = Proposal { id: self.governance.len() as Id, description: proposal.to_string() };
+        let decision = self.futarchy.submit_proposal(proposer, &proposal);
+        if !decision.admitted {
+            return Err(format!("Mercy Shield: {}", decision.summary()));
+        }
+        let verdict = self.futarchy.conditional_market(&proposal.description);
+        let handle = GovernanceHandle::spawn(proposal, decision.id, verdict, config, self.futarchy.oracles().cloned());
+        self.governance.push(handle.clone());
+        Ok(handle)
+    }
+
+    pub fn governance_run(&self, id: Id) -> Option<&GovernanceHandle> {
+        self.governance.get(id as usize)
+    }
+
+    /// SoulScan-X9 voice check on a speech transcript (audio capture is left to the host)
+    #[cfg(feature = "voice")]
+    pub fn voice_moderation(&self, transcript: &str) -> Verdict {
+        self.truth.evaluate(transcript)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::SystemTime;
+
+    use async_trait::async_trait;
+
+    use super::*;
+    use crate::oracles::oracle_feed::{BeliefSource, FreshnessPolicy, OracleReading, OracleSource};
+    use crate::qv::Strategy;
+    use crate::truth::LocalTruth;
+    use debate_markets::DebateQuestion;
+
+    /// Knows one proposal, which the market expects to hurt the metric
+    struct Bearish;
+
+    #[async_trait]
+    impl BeliefSource for Bearish {
+        fn source(&self) -> OracleSource {
+            OracleSource::Internal
+        }
+
+        async fn read_belief(&self, subject: &str) -> Result<OracleReading, String> {
+            if subject != "Fund the commons" {
+                return Err(format!("No market for {}", subject));
+            }
+            Ok(OracleReading { source: OracleSource::Internal, subject: subject.to_string(), value: 0.15, observed_at: SystemTime::now() })
+        }
+    }
+
+    #[test]
+    fn debate_topics_pass_the_arena_gate() {
+        let mut arena = Arena::new(LocalTruth::shared());
+        let err = arena.open_debate("Rug pull the treasury?", DebateFormat::grok_global_arena()).unwrap_err();
+        assert!(err.starts_with("Mercy Shield: Mercy decision #0: rejected"), "{}", err);
+        // The rejected topic opened no markets
+        assert_eq!(arena.mercy_gate().decisions().len(), 1);
+        assert!(arena.debate(0).is_none());
+
+        let id = arena.open_debate("Should the commons fund public goods?", DebateFormat::grok_global_arena()).unwrap();
+        assert_eq!(arena.debate(id).unwrap().markets().unwrap().questions().len(), 2);
+        let topic = &arena.mercy_gate().decisions()[1];
+        assert_eq!((topic.stage, topic.actor.as_str()), (GateStage::IdeaSubmission, DEBATE_MODERATOR));
+    }
+
+    #[test]
+    fn custom_debate_markets_still_screen_the_topic() {
+        // Verified by the truth engine, but below this gate's quanta threshold
+        let strict = MercyPolicy { weighted_threshold: 0.95, ..MercyPolicy::default() };
+        let mut arena = Arena::new(LocalTruth::shared()).with_mercy_gate(Arc::new(MercyGate::new(strict, LocalTruth::shared())));
+        let markets = DebateMarkets::open(LocalTruth::shared(), vec![DebateQuestion::AudienceWinner], 100.0).unwrap();
+        assert!(arena.open_debate_with_markets("Plain topic", DebateFormat::grok_global_arena(), markets).is_err());
+        assert!(arena.debate(0).is_none());
+    }
+
+    #[test]
+    fn mode_gates_log_into_the_arena_gate() {
+        let arena = Arena::new(LocalTruth::shared());
+        arena.mercy_gate().check(GateStage::IdeaSubmission, "ada", "A plain idea");
+        let roast = arena.mode_gate(ArenaMode::Roast);
+        let decision = roast.check(GateStage::IdeaSubmission, "bo", "A plain roast line");
+        assert_eq!(decision.id, 1);
+        assert_eq!(arena.mercy_gate().decision(1).unwrap().actor, "bo");
+        assert_eq!(roast.policy().weighted_threshold, arena.mercy_gate().policy().overridden(&ArenaMode::Roast.policy_override()).weighted_threshold);
+    }
+
+    #[test]
+    fn governance_trades_on_the_oracle_belief() {
+        let chain = FailoverChain::new(FreshnessPolicy::default()).then(Arc::new(Bearish));
+        // Swapping the gate afterwards keeps the chain
+        let mut arena = Arena::new(LocalTruth::shared())
+            .with_oracles(chain)
+            .with_mercy_gate(Arc::new(MercyGate::new(MercyPolicy::default(), LocalTruth::shared())));
+        let config = GovernanceConfig { voters: vec![(Strategy::Optimal, 100.0); 4], ..GovernanceConfig::default() };
+
+        let decision = arena.futarchy_vote_with("ada", "Fund the commons", config.clone()).unwrap().wait().unwrap();
+        assert_eq!(decision.belief, 0.15);
+        assert!(!decision.adopted);
+        assert!(decision.final_prices.reject > decision.final_prices.pass);
+
+        let err = arena.futarchy_vote_with("ada", "Plant more trees", config).unwrap().wait().unwrap_err();
+        assert!(err.starts_with("No fresh oracle belief for Plant more trees: [(Internal, Failed("), "{}", err);
+    }
+}
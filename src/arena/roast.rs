@@ -0,0 +1,329 @@
+//! Comedy Roast — Consent-First Round-Robin Roasting
+//! Ultramasterful mercy for barbs: the target opts in, sets boundaries and can stop the show
+//!
+//! Roasters take turns in a fixed round-robin; every bit goes through the roast-mode mercy
+//! gate and is kept in the roast's own discourse, where the audience reacts. Laughs and
+//! applause decide the standings.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::arena::discourse::{Discourse, Idea, Reaction};
+use crate::mercy::MercyGate;
+use crate::truth;
+use crate::types::Id;
+
+/// Points per audience reaction
+#[derive(Clone, Debug)]
+pub struct RoastScoring {
+    pub laugh: f64,
+    pub applause: f64,
+    /// Groans (Disagree) cost points
+    pub groan: f64,
+}
+
+impl Default for RoastScoring {
+    fn default() -> Self {
+        Self { laugh: 1.0, applause: 2.0, groan: -0.5 }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum RoastPhase {
+    AwaitingConsent,
+    Ready,
+    Live { round: usize, turn: usize },
+    Finished,
+    /// Target withdrew consent
+    Halted,
+}
+
+pub struct Roast {
+    target: String,
+    roasters: Vec<String>,
+    rounds: usize,
+    boundaries: Vec<String>,
+    phase: RoastPhase,
+    scoring: RoastScoring,
+    discourse: Discourse,
+    bits: Vec<Id>,
+}
+
+impl Roast {
+    /// `gate` should carry the roast-mode mercy policy
+    pub fn new(target: &str, roasters: &[&str], rounds: usize, gate: Arc<MercyGate>) -> Result<Self, String> {
+        if roasters.is_empty() || rounds == 0 {
+            return Err("A roast needs at least one roaster and one round".to_string());
+        }
+        if roasters.contains(&target) {
+            return Err(format!("{} cannot roast themselves", target));
+        }
+        Ok(Roast {
+            target: target.to_string(),
+            roasters: roasters.iter().map(|r| r.to_string()).collect(),
+            rounds,
+            boundaries: Vec::new(),
+            phase: RoastPhase::AwaitingConsent,
+            scoring: RoastScoring::default(),
+            discourse: Discourse::new(gate),
+            bits: Vec::new(),
+        })
+    }
+
+    pub fn with_scoring(mut self, scoring: RoastScoring) -> Self {
+        self.scoring = scoring;
+        self
+    }
+
+    pub fn target(&self) -> &str {
+        &self.target
+    }
+
+    pub fn phase(&self) -> &RoastPhase {
+        &self.phase
+    }
+
+    /// Target opts in; bits touching any of `boundaries` are refused. Boundaries match whole
+    /// words or phrases (plurals included); end one in `*` to cover a stem ("weigh*")
+    pub fn consent(&mut self, target: &str, boundaries: &[&str]) -> Result<(), String> {
+        if target != self.target {
+            return Err(format!("Only {} can consent to this roast", self.target));
+        }
+        if self.phase != RoastPhase::AwaitingConsent {
+            return Err("Consent already given".to_string());
+        }
+        self.boundaries = boundaries.iter()
+            .map(|b| b.trim().to_lowercase())
+            .filter(|b| !b.is_empty())
+            .collect();
+        self.phase = RoastPhase::Ready;
+        Ok(())
+    }
+
+    /// Target stops the roast at any point before it finishes
+    pub fn withdraw_consent(&mut self, target: &str) -> Result<(), String> {
+        if target != self.target {
+            return Err(format!("Only {} can withdraw consent", self.target));
+        }
+        if self.phase == RoastPhase::Finished {
+            return Err("Roast already finished".to_string());
+        }
+        self.phase = RoastPhase::Halted;
+        Ok(())
+    }
+
+    pub fn start(&mut self) -> Result<(), String> {
+        match self.phase {
+            RoastPhase::AwaitingConsent => Err(format!("{} has not consented", self.target)),
+            RoastPhase::Ready => {
+                self.phase = RoastPhase::Live { round: 0, turn: 0 };
+                Ok(())
+            }
+            _ => Err("Roast already started".to_string()),
+        }
+    }
+
+    pub fn current_roaster(&self) -> Option<&str> {
+        match self.phase {
+            RoastPhase::Live { turn, .. } => Some(&self.roasters[turn]),
+            _ => None,
+        }
+    }
+
+    fn next_turn(&mut self, round: usize, turn: usize) {
+        self.phase = if turn + 1 < self.roasters.len() {
+            RoastPhase::Live { round, turn: turn + 1 }
+        } else if round + 1 < self.rounds {
+            RoastPhase::Live { round: round + 1, turn: 0 }
+        } else {
+            RoastPhase::Finished
+        };
+    }
+
+    /// Deliver a bit on the current roaster's turn
+    pub fn deliver(&mut self, roaster: &str, bit: &str) -> Result<Id, String> {
+        let RoastPhase::Live { round, turn } = self.phase else {
+            return Err("Roast is not live".to_string());
+        };
+        if self.roasters[turn] != roaster {
+            return Err(format!("Out of turn: {} is up", self.roasters[turn]));
+        }
+        let words = truth::words(bit);
+        if let Some(boundary) = self.boundaries.iter().find(|b| truth::contains_term(&words, b)) {
+            return Err(format!("Bit crosses {}'s boundary: {}", self.target, boundary));
+        }
+        let id = self.discourse.submit(roaster, bit, &["roast"])?;
+        self.bits.push(id);
+        self.next_turn(round, turn);
+        Ok(id)
+    }
+
+    /// Current roaster passes their turn
+    pub fn pass(&mut self, roaster: &str) -> Result<(), String> {
+        let RoastPhase::Live { round, turn } = self.phase else {
+            return Err("Roast is not live".to_string());
+        };
+        if self.roasters[turn] != roaster {
+            return Err(format!("Out of turn: {} is up", self.roasters[turn]));
+        }
+        self.next_turn(round, turn);
+        Ok(())
+    }
+
+    /// Audience reaction toggle on a delivered bit
+    pub fn react(&mut self, bit: Id, member: &str, reaction: Reaction) -> Result<bool, String> {
+        if !self.bits.contains(&bit) {
+            return Err(format!("No roast bit #{}", bit));
+        }
+        self.discourse.react(bit, member, reaction)
+    }
+
+    pub fn bits(&self) -> Vec<&Idea> {
+        self.bits.iter().filter_map(|id| self.discourse.get(*id)).collect()
+    }
+
+    pub fn bit_score(&self, bit: &Idea) -> f64 {
+        self.scoring.laugh * bit.reaction_count(Reaction::Laugh) as f64
+            + self.scoring.applause * bit.reaction_count(Reaction::Applause) as f64
+            + self.scoring.groan * bit.reaction_count(Reaction::Disagree) as f64
+    }
+
+    /// Roasters by total score, best first
+    pub fn standings(&self) -> Vec<(String, f64)> {
+        let mut totals: HashMap<&str, f64> = self.roasters.iter().map(|r| (r.as_str(), 0.0)).collect();
+        for bit in self.bits() {
+            *totals.entry(bit.author.as_str()).or_default() += self.bit_score(bit);
+        }
+        let mut standings: Vec<(String, f64)> = totals.into_iter().map(|(r, s)| (r.to_string(), s)).collect();
+        standings.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        standings
+    }
+
+    pub fn winner(&self) -> Option<String> {
+        if self.phase != RoastPhase::Finished {
+            return None;
+        }
+        self.standings().into_iter().next().map(|(r, _)| r)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mercy::MercyPolicy;
+    use crate::truth::LocalTruth;
+
+    fn roast() -> Roast {
+        let gate = Arc::new(MercyGate::new(MercyPolicy::default(), LocalTruth::shared()));
+        Roast::new("ada", &["bob", "cy"], 2, gate).unwrap()
+    }
+
+    #[test]
+    fn consent_gates_the_show() {
+        let mut roast = roast();
+        assert_eq!(roast.start().unwrap_err(), "ada has not consented");
+        assert_eq!(roast.consent("bob", &[]).unwrap_err(), "Only ada can consent to this roast");
+        roast.consent("ada", &[]).unwrap();
+        assert!(roast.consent("ada", &[]).is_err());
+        assert!(roast.deliver("bob", "Too early").is_err());
+        roast.start().unwrap();
+        assert!(roast.start().is_err());
+
+        roast.deliver("bob", "Ada writes tests for her tests").unwrap();
+        assert!(roast.withdraw_consent("bob").is_err());
+        roast.withdraw_consent("ada").unwrap();
+        assert_eq!(roast.phase(), &RoastPhase::Halted);
+        assert_eq!(roast.deliver("cy", "One more").unwrap_err(), "Roast is not live");
+        assert!(roast.current_roaster().is_none());
+        assert!(roast.winner().is_none());
+
+        assert!(Roast::new("ada", &["ada"], 1, Arc::new(MercyGate::new(MercyPolicy::default(), LocalTruth::shared()))).is_err());
+        assert!(Roast::new("ada", &[], 1, Arc::new(MercyGate::new(MercyPolicy::default(), LocalTruth::shared()))).is_err());
+    }
+
+    #[test]
+    fn finished_roast_cannot_be_halted() {
+        let mut roast = roast();
+        roast.consent("ada", &[]).unwrap();
+        roast.start().unwrap();
+        for roaster in ["bob", "cy", "bob", "cy"] {
+            roast.pass(roaster).unwrap();
+        }
+        assert_eq!(roast.phase(), &RoastPhase::Finished);
+        assert_eq!(roast.withdraw_consent("ada").unwrap_err(), "Roast already finished");
+        assert_eq!(roast.phase(), &RoastPhase::Finished);
+    }
+
+    #[test]
+    fn turns_go_round_robin_until_finished() {
+        let mut roast = roast();
+        roast.consent("ada", &[]).unwrap();
+        roast.start().unwrap();
+        assert_eq!(roast.current_roaster(), Some("bob"));
+        assert_eq!(roast.deliver("cy", "Jumping the queue").unwrap_err(), "Out of turn: bob is up");
+        assert_eq!(roast.pass("cy").unwrap_err(), "Out of turn: bob is up");
+        assert_eq!(roast.phase(), &RoastPhase::Live { round: 0, turn: 0 });
+
+        roast.deliver("bob", "Ada's commit messages are longer than her commits").unwrap();
+        assert_eq!(roast.phase(), &RoastPhase::Live { round: 0, turn: 1 });
+        roast.pass("cy").unwrap();
+        // Last roaster wraps to the first in the next round
+        assert_eq!(roast.phase(), &RoastPhase::Live { round: 1, turn: 0 });
+        roast.deliver("bob", "Ada names variables after her houseplants").unwrap();
+        roast.deliver("cy", "Ada's houseplants review her pull requests").unwrap();
+        assert_eq!(roast.phase(), &RoastPhase::Finished);
+        assert!(roast.current_roaster().is_none());
+        assert_eq!(roast.deliver("bob", "Encore").unwrap_err(), "Roast is not live");
+        assert_eq!(roast.bits().len(), 3);
+    }
+
+    #[test]
+    fn audience_reactions_decide_the_standings() {
+        let mut roast = roast().with_scoring(RoastScoring { laugh: 1.0, applause: 2.0, groan: -0.5 });
+        roast.consent("ada", &[]).unwrap();
+        roast.start().unwrap();
+        let first = roast.deliver("bob", "Ada's commit messages are longer than her commits").unwrap();
+        let second = roast.deliver("cy", "Ada names variables after her houseplants").unwrap();
+        assert!(roast.winner().is_none());
+
+        for (member, reaction) in [("x", Reaction::Laugh), ("y", Reaction::Laugh), ("z", Reaction::Applause), ("w", Reaction::Disagree)] {
+            roast.react(first, member, reaction).unwrap();
+        }
+        roast.react(second, "x", Reaction::Laugh).unwrap();
+        roast.react(second, "y", Reaction::Laugh).unwrap();
+        // Toggling off removes the point again
+        assert!(!roast.react(second, "y", Reaction::Laugh).unwrap());
+        assert!(roast.react(99, "x", Reaction::Laugh).is_err());
+
+        let bits = roast.bits();
+        // 2 laughs + 1 applause − 1 groan = 2 + 2 − 0.5
+        assert_eq!(roast.bit_score(bits[0]), 3.5);
+        assert_eq!(roast.bit_score(bits[1]), 1.0);
+        assert_eq!(roast.standings(), vec![("bob".to_string(), 3.5), ("cy".to_string(), 1.0)]);
+
+        roast.pass("bob").unwrap();
+        roast.pass("cy").unwrap();
+        assert_eq!(roast.winner().as_deref(), Some("bob"));
+    }
+
+    #[test]
+    fn boundaries_match_whole_words() {
+        let mut roast = roast();
+        roast.consent("ada", &["Age", "bald*", "day job", " "]).unwrap();
+        roast.start().unwrap();
+
+        // Substrings of a boundary word are fair game
+        roast.deliver("bob", "Ada is so organised her garage has a filing system").unwrap();
+        roast.deliver("cy", "Ada's daily stand-up is longer than her jobs list").unwrap();
+        for bit in [
+            "Ada's age shows in her jokes",
+            "Ages ago Ada told a good joke",
+            "Ada's balding code review",
+            "Ada should keep her day job",
+        ] {
+            let err = roast.deliver("bob", bit).unwrap_err();
+            assert!(err.starts_with("Bit crosses ada's boundary"), "{}: {}", bit, err);
+        }
+        assert_eq!(roast.bits().len(), 2);
+    }
+}
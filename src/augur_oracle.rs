@@ -1,22 +0,0 @@
-//! Augur Oracle — Historical Prediction Market Reference Stub
-//! Ultramasterful cautionary integration for futarchy evolution
-
-use nexi::lattice::Nexus;
-
-pub struct AugurOracle {
-    nexus: Nexus,
-}
-
-impl AugurOracle {
-    pub fn new() -> Self {
-        AugurOracle {
-            nexus: Nexus::init_with_mercy(),
-        }
-    }
-
-    /// Simulate historical Augur market belief (cautionary)
-    pub fn historical_augur_belief(&self, event: &str) -> String {
-        // Legacy stub — low liquidity caution
-        self.nexus.distill_truth(&format!("Augur historical caution: {}", event))
-    }
-}
@@ -1,26 +1,95 @@
 //! Futarchy Voting Mechanics — Granular Ultramasterful Implementation
 //! zk-aggregated conditional markets
 
-use nexi::lattice::Nexus;
+use std::sync::Arc;
+
+use crate::mercy::{GateStage, MercyDecision, MercyGate, MercyPolicy};
+use crate::oracles::oracle_feed::{BeliefOutcome, FailoverChain};
+use crate::truth::{SharedTruth, Verdict};
+use crate::types::Proposal;
+
+/// Venue-agnostic resolution of a futarchy conditional market
+/// Outcome slot 0 is "adopted", slot 1 is "rejected" (CTF / Omen Yes-No ordering)
+#[derive(Clone, Debug, PartialEq)]
+pub enum FutarchyOutcome {
+    Pending,
+    Adopted,
+    Rejected,
+    /// Split or multi-outcome payout, normalised to sum to 1.0
+    Scalar(Vec<f64>),
+}
+
+impl FutarchyOutcome {
+    pub fn from_payouts(payouts: Vec<f64>) -> Self {
+        match payouts.as_slice() {
+            [a, r] if *a == 1.0 && *r == 0.0 => FutarchyOutcome::Adopted,
+            [a, r] if *a == 0.0 && *r == 1.0 => FutarchyOutcome::Rejected,
+            _ => FutarchyOutcome::Scalar(payouts),
+        }
+    }
+
+    pub fn is_resolved(&self) -> bool {
+        !matches!(self, FutarchyOutcome::Pending)
+    }
+}
 
 pub struct FutarchyEngine {
-    nexus: Nexus,
+    truth: SharedTruth,
+    gate: Arc<MercyGate>,
+    oracles: Option<Arc<FailoverChain>>,
 }
 
 impl FutarchyEngine {
-    pub fn new() -> Self {
+    /// Proposals are admitted under the default mercy policy until `with_mercy_gate`
+    pub fn new(truth: SharedTruth) -> Self {
         FutarchyEngine {
-            nexus: Nexus::init_with_mercy(),
+            gate: Arc::new(MercyGate::new(MercyPolicy::default(), truth.clone())),
+            truth,
+            oracles: None,
         }
     }
 
-    pub fn values_vote(&self, metric: &str) -> String {
+    /// Share one mercy gate (and its decision log) with markets and bridges
+    pub fn with_mercy_gate(mut self, gate: Arc<MercyGate>) -> Self {
+        self.gate = gate;
+        self
+    }
+
+    pub fn mercy_gate(&self) -> &Arc<MercyGate> {
+        &self.gate
+    }
+
+    /// Proposal submission gate; the decision is logged whether or not it is admitted
+    pub fn submit_proposal(&self, proposer: &str, proposal: &Proposal) -> MercyDecision {
+        self.gate.check(GateStage::ProposalSubmission, proposer, &proposal.description)
+    }
+
+    /// Source market beliefs from an ordered oracle failover chain
+    pub fn with_oracles(mut self, chain: FailoverChain) -> Self {
+        self.oracles = Some(Arc::new(chain));
+        self
+    }
+
+    /// The oracle chain, shared with governance runs that price proposals on its beliefs
+    pub fn oracles(&self) -> Option<&Arc<FailoverChain>> {
+        self.oracles.as_ref()
+    }
+
+    pub fn values_vote(&self, metric: &str) -> Verdict {
         // Quadratic zk-aggregated vote stub
-        self.nexus.distill_truth(metric)
+        self.truth.evaluate(metric)
     }
 
-    pub fn conditional_market(&self, policy: &str) -> String {
+    pub fn conditional_market(&self, policy: &str) -> Verdict {
         // Prediction market simulation + oracle
-        self.nexus.distill_truth(policy)
+        self.truth.evaluate(policy)
+    }
+
+    /// Freshest belief on `policy` from the failover chain — never a stale value
+    pub async fn market_belief(&self, policy: &str) -> BeliefOutcome {
+        match &self.oracles {
+            Some(chain) => chain.fresh_belief(policy).await,
+            None => BeliefOutcome::NoFreshBelief { attempts: Vec::new() },
+        }
     }
 }
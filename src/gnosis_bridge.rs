@@ -1,58 +0,0 @@
-//! Gnosis Futarchy Bridge — Conditional Token Oracle for Multi-Chain Belief Aggregation
-//! Ultramasterful integration with Gnosis Conditional Tokens
-
-use ethers::{
-    providers::{Provider, Http},
-    types::{Address, U256},
-    contract::abigen,
-};
-use nexi::lattice::Nexus;
-
-abigen!(
-    ConditionalTokens,
-    r#"[
-        prepareCondition(address oracle, bytes32 questionId, uint outcomeSlotCount)
-        payoutNumerators(uint[] memory)
-    ]"#,
-);
-
-pub struct GnosisBridge {
-    nexus: Nexus,
-    contract: ConditionalTokens<Provider<Http>>,
-}
-
-impl GnosisBridge {
-    pub fn new(rpc_url: &str, contract_address: Address) -> Self {
-        let provider = Provider::<Http>::try_from(rpc_url).unwrap();
-        let contract = ConditionalTokens::new(contract_address, std::sync::Arc::new(provider));
-
-        GnosisBridge {
-            nexus: Nexus::init_with_mercy(),
-            contract,
-        }
-    }
-
-    /// Prepare futarchy condition on Gnosis
-    pub async fn prepare_futarchy_condition(
-        &self,
-        oracle: Address,
-        question_id: [u8; 32],
-        outcomes: u32,
-    ) -> Result<(), String> {
-        // Mercy-gated: check valence before preparing
-        let mercy_check = self.nexus.distill_truth("Futarchy condition preparation");
-        if !mercy_check.contains("Verified") {
-            return Err("Mercy Shield: Condition rejected".to_string());
-        }
-
-        // Call Gnosis prepareCondition
-        // Stub — expand with full tx signing
-        Ok(())
-    }
-
-    /// Resolve futarchy outcome via Gnosis payouts
-    pub async fn resolve_futarchy_outcome(&self, condition_id: [u8; 32]) -> Vec<U256> {
-        // Fetch payout numerators
-        vec![U256::from(1)]
-    }
-}
@@ -0,0 +1,22 @@
+//! GrokArena-Pinnacle — Decentralized Global Discourse Lattice
+//! Ultramasterful library root: arenas, futarchy, markets, oracles, quadratic voting and simulators
+//!
+//! Simulators and market engines build with no features; live integrations are behind the
+//! `nexi`, `gnosis`, `polymarket`, `metadao`, `omen` and `voice` cargo features.
+
+pub mod arena;
+pub mod futarchy;
+pub mod markets;
+pub mod mercy;
+pub mod oracles;
+pub mod qv;
+pub mod sim;
+pub mod truth;
+pub mod types;
+
+pub use arena::Arena;
+pub use futarchy::{FutarchyEngine, FutarchyOutcome};
+pub use mercy::{GateStage, MercyDecision, MercyGate, MercyPolicy};
+pub use truth::{LocalTruth, SharedTruth, TruthEngine, Verdict};
+#[cfg(feature = "nexi")]
+pub use truth::NexiTruth;
@@ -0,0 +1,502 @@
+//! Cross-Venue Arbitrage — Question Registry and Price-Gap Detector
+//! Ultramasterful signal: when Polymarket, Omen and internal markets disagree, someone is wrong
+//!
+//! A hedged arbitrage buys n Yes tokens on one venue and n No tokens on another (usually the
+//! cheap and the rich one);
+//! exactly one pays out 1 per token, so profit(n) = n − cost_yes(n) − cost_no(n). Costs include
+//! fees and price impact, so profit is concave in n and the detector searches for its maximum.
+
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+use crate::markets::fpmm::{self, FixedProductMarketMaker};
+#[cfg(feature = "omen")]
+use crate::oracles::omen_oracle::OmenOracle;
+use crate::oracles::oracle_feed::OracleSource;
+#[cfg(feature = "polymarket")]
+use crate::oracles::polymarket_oracle::PolymarketOracle;
+use crate::markets::prediction_market::{self, PredictionMarket};
+
+/// Venue-specific market linked to a shared question
+#[derive(Clone, Debug)]
+pub enum VenueMarket {
+    Polymarket { market_id: String },
+    /// `yes_index` is the FPMM outcome slot equivalent to the question's Yes
+    Omen { market_id: String, yes_index: usize },
+    Internal { event: String },
+}
+
+impl VenueMarket {
+    pub fn source(&self) -> OracleSource {
+        match self {
+            VenueMarket::Polymarket { .. } => OracleSource::Polymarket,
+            VenueMarket::Omen { .. } => OracleSource::Omen,
+            VenueMarket::Internal { .. } => OracleSource::Internal,
+        }
+    }
+}
+
+/// One venue's market for a question
+#[derive(Clone, Debug)]
+pub struct QuestionLeg {
+    pub market: VenueMarket,
+    /// Venue phrases the question the opposite way (its Yes is our No)
+    pub inverted: bool,
+    /// Taker fee charged on top of book prices (AMM venues carry their own fee)
+    pub taker_fee: f64,
+}
+
+/// Equivalent markets across venues
+#[derive(Clone, Debug)]
+pub struct LinkedQuestion {
+    pub id: String,
+    pub text: String,
+    pub legs: Vec<QuestionLeg>,
+}
+
+/// Registry of questions matched across venues
+#[derive(Debug, Default)]
+pub struct QuestionRegistry {
+    questions: HashMap<String, LinkedQuestion>,
+}
+
+impl QuestionRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register(&mut self, id: &str, text: &str) {
+        self.questions.entry(id.to_string()).or_insert_with(|| LinkedQuestion {
+            id: id.to_string(),
+            text: text.to_string(),
+            legs: Vec::new(),
+        });
+    }
+
+    pub fn link(&mut self, id: &str, leg: QuestionLeg) -> Result<(), String> {
+        if let VenueMarket::Omen { market_id, yes_index } = &leg.market {
+            if *yes_index > 1 {
+                return Err(format!("Omen market {}: yes_index {} is not a binary outcome slot", market_id, yes_index));
+            }
+        }
+        let question = self.questions.get_mut(id)
+            .ok_or_else(|| format!("Unknown question: {}", id))?;
+        question.legs.push(leg);
+        Ok(())
+    }
+
+    pub fn question(&self, id: &str) -> Option<&LinkedQuestion> {
+        self.questions.get(id)
+    }
+
+    pub fn questions(&self) -> impl Iterator<Item = &LinkedQuestion> {
+        self.questions.values()
+    }
+}
+
+/// Liquidity a leg can be traded against, oriented to the question's Yes
+#[derive(Clone, Debug)]
+pub enum Liquidity {
+    Amm { pool: FixedProductMarketMaker, yes_index: usize },
+    /// Ask ladders (price, size) for Yes and No, cheapest first, fee applied per fill
+    Book { yes_asks: Vec<(f64, f64)>, no_asks: Vec<(f64, f64)>, taker_fee: f64 },
+}
+
+impl Liquidity {
+    /// Binary FPMM leg; pools with any other outcome count are rejected
+    pub fn amm(pool: FixedProductMarketMaker, yes_index: usize) -> Result<Self, String> {
+        let outcomes = pool.pool_balances().len();
+        if outcomes != 2 {
+            return Err(format!("Arbitrage needs a binary pool, got {} outcomes", outcomes));
+        }
+        if yes_index > 1 {
+            return Err(format!("Yes index {} out of range for a binary pool", yes_index));
+        }
+        Ok(Liquidity::Amm { pool, yes_index })
+    }
+    #[cfg(feature = "polymarket")]
+    fn book(yes_bids: Vec<(f64, f64)>, yes_asks: Vec<(f64, f64)>, inverted: bool, taker_fee: f64) -> Self {
+        // Buying No is hitting Yes bids at 1 − bid
+        let mut asks = yes_asks;
+        let mut no_asks: Vec<(f64, f64)> = yes_bids.into_iter().map(|(p, s)| (1.0 - p, s)).collect();
+        if inverted {
+            std::mem::swap(&mut asks, &mut no_asks);
+        }
+        asks.sort_by(|a, b| a.0.total_cmp(&b.0));
+        no_asks.sort_by(|a, b| a.0.total_cmp(&b.0));
+        Liquidity::Book { yes_asks: asks, no_asks, taker_fee }
+    }
+
+    /// Mid Yes price
+    pub fn yes_price(&self) -> f64 {
+        match self {
+            Liquidity::Amm { pool, yes_index } => pool.prices()[*yes_index],
+            Liquidity::Book { yes_asks, no_asks, .. } => {
+                match (yes_asks.first(), no_asks.first()) {
+                    (Some(ask), Some(no_ask)) => (ask.0 + 1.0 - no_ask.0) / 2.0,
+                    (Some(ask), None) => ask.0,
+                    (None, Some(no_ask)) => 1.0 - no_ask.0,
+                    (None, None) => 0.5,
+                }
+            }
+        }
+    }
+
+    /// Upper bound on tokens purchasable in one direction
+    fn max_tokens(&self, yes: bool) -> f64 {
+        match self {
+            Liquidity::Amm { pool, .. } => {
+                pool.pool_balances().iter().map(|&b| fpmm::to_f64(b)).fold(0.0, f64::max) / fpmm::ONE as f64 * 10.0
+            }
+            Liquidity::Book { yes_asks, no_asks, .. } => {
+                let ladder = if yes { yes_asks } else { no_asks };
+                ladder.iter().map(|&(_, s)| s).sum()
+            }
+        }
+    }
+
+    fn outcome_index(&self, yes: bool) -> usize {
+        match self {
+            Liquidity::Amm { yes_index, .. } => if yes { *yes_index } else { 1 - *yes_index },
+            Liquidity::Book { .. } => 0,
+        }
+    }
+
+    /// Collateral (fees included) to buy `tokens` Yes or No tokens, None if liquidity runs out
+    pub fn cost_to_buy(&self, yes: bool, tokens: f64) -> Option<f64> {
+        if tokens <= 0.0 {
+            return Some(0.0);
+        }
+        match self {
+            Liquidity::Amm { pool, .. } => {
+                let index = self.outcome_index(yes);
+                let want = fpmm::from_f64(tokens);
+                let bought = |x: f64| pool.calc_buy_amount(fpmm::from_f64(x), index).ok();
+                // Tokens bought grow with investment; bracket then bisect
+                let mut hi = tokens.max(1e-9);
+                while bought(hi)? < want {
+                    hi *= 2.0;
+                    if hi > 1e15 {
+                        return None;
+                    }
+                }
+                let mut lo = 0.0;
+                for _ in 0..100 {
+                    let mid = (lo + hi) / 2.0;
+                    if bought(mid)? >= want { hi = mid } else { lo = mid }
+                }
+                Some(hi)
+            }
+            Liquidity::Book { yes_asks, no_asks, taker_fee } => {
+                let ladder = if yes { yes_asks } else { no_asks };
+                let mut remaining = tokens;
+                let mut cost = 0.0;
+                for &(price, size) in ladder {
+                    let fill = remaining.min(size);
+                    cost += fill * price * (1.0 + taker_fee);
+                    remaining -= fill;
+                    if remaining <= 0.0 {
+                        return Some(cost);
+                    }
+                }
+                None
+            }
+        }
+    }
+
+    /// Marginal Yes price after buying `tokens` in the given direction
+    pub fn yes_price_after(&self, yes: bool, tokens: f64) -> f64 {
+        match self {
+            Liquidity::Amm { pool, yes_index } => {
+                let mut after = pool.clone();
+                if let Some(cost) = self.cost_to_buy(yes, tokens) {
+                    let _ = after.buy(fpmm::from_f64(cost), self.outcome_index(yes), 0.into());
+                }
+                after.prices()[*yes_index]
+            }
+            Liquidity::Book { yes_asks, no_asks, .. } => {
+                let ladder = if yes { yes_asks } else { no_asks };
+                let mut filled = 0.0;
+                let mut last = ladder.first().map_or(0.5, |l| l.0);
+                for &(price, size) in ladder {
+                    if filled >= tokens {
+                        break;
+                    }
+                    filled += size;
+                    last = price;
+                }
+                if yes { last } else { 1.0 - last }
+            }
+        }
+    }
+}
+
+/// Priced leg ready for comparison
+#[derive(Clone, Debug)]
+pub struct VenueQuote {
+    pub venue: OracleSource,
+    pub market: VenueMarket,
+    pub liquidity: Liquidity,
+}
+
+/// Observed disagreement between two venues on the same question
+#[derive(Clone, Debug)]
+pub struct PriceGap {
+    pub question_id: String,
+    pub cheap: OracleSource,
+    pub rich: OracleSource,
+    pub cheap_yes_price: f64,
+    pub rich_yes_price: f64,
+}
+
+impl PriceGap {
+    /// Gap between two quotes, labelled by their actual Yes prices
+    pub fn between(question_id: &str, a: &VenueQuote, b: &VenueQuote) -> Self {
+        let (cheap, rich) = if a.liquidity.yes_price() <= b.liquidity.yes_price() { (a, b) } else { (b, a) };
+        PriceGap {
+            question_id: question_id.to_string(),
+            cheap: cheap.venue,
+            rich: rich.venue,
+            cheap_yes_price: cheap.liquidity.yes_price(),
+            rich_yes_price: rich.liquidity.yes_price(),
+        }
+    }
+
+    pub fn gross_gap(&self) -> f64 {
+        self.rich_yes_price - self.cheap_yes_price
+    }
+}
+
+/// Profitable hedged trade closing a price gap
+#[derive(Clone, Debug)]
+pub struct ArbOpportunity {
+    /// Quoted gap, labelled by price (the trade may run against it when book spreads allow)
+    pub gap: PriceGap,
+    /// Venue Yes is bought on
+    pub yes_venue: OracleSource,
+    /// Venue No is bought on
+    pub no_venue: OracleSource,
+    /// Edge per token for the first marginal unit, net of fees
+    pub net_gap: f64,
+    /// Tokens to buy on each side
+    pub tokens: f64,
+    pub yes_cost: f64,
+    pub no_cost: f64,
+    pub profit: f64,
+    /// Yes price on each venue after the trade
+    pub yes_venue_price_after: f64,
+    pub no_venue_price_after: f64,
+}
+
+/// Smallest probe size used to measure the marginal net edge
+const PROBE_TOKENS: f64 = 1e-6;
+
+/// Best hedged trade buying Yes on `yes_leg` and No on `no_leg`
+pub fn best_hedge(question_id: &str, yes_leg: &VenueQuote, no_leg: &VenueQuote) -> Option<ArbOpportunity> {
+    let profit = |n: f64| -> Option<f64> {
+        Some(n - yes_leg.liquidity.cost_to_buy(true, n)? - no_leg.liquidity.cost_to_buy(false, n)?)
+    };
+
+    let net_gap = profit(PROBE_TOKENS)? / PROBE_TOKENS;
+    if net_gap <= 0.0 {
+        return None;
+    }
+
+    // Golden-section search over the concave profit curve
+    let mut lo = 0.0;
+    let mut hi = yes_leg.liquidity.max_tokens(true).min(no_leg.liquidity.max_tokens(false));
+    let ratio = (5f64.sqrt() - 1.0) / 2.0;
+    let eval = |n: f64| profit(n).unwrap_or(f64::NEG_INFINITY);
+    for _ in 0..120 {
+        let a = hi - ratio * (hi - lo);
+        let b = lo + ratio * (hi - lo);
+        if eval(a) < eval(b) { lo = a } else { hi = b }
+    }
+    let tokens = (lo + hi) / 2.0;
+    let yes_cost = yes_leg.liquidity.cost_to_buy(true, tokens)?;
+    let no_cost = no_leg.liquidity.cost_to_buy(false, tokens)?;
+
+    Some(ArbOpportunity {
+        gap: PriceGap::between(question_id, yes_leg, no_leg),
+        yes_venue: yes_leg.venue,
+        no_venue: no_leg.venue,
+        net_gap,
+        tokens,
+        yes_cost,
+        no_cost,
+        profit: tokens - yes_cost - no_cost,
+        yes_venue_price_after: yes_leg.liquidity.yes_price_after(true, tokens),
+        no_venue_price_after: no_leg.liquidity.yes_price_after(false, tokens),
+    })
+}
+
+/// All pairwise gaps and profitable hedges for one question's quotes
+pub fn detect(question_id: &str, quotes: &[VenueQuote]) -> (Vec<PriceGap>, Vec<ArbOpportunity>) {
+    let mut gaps = Vec::new();
+    let mut opportunities = Vec::new();
+    for (i, a) in quotes.iter().enumerate() {
+        for b in &quotes[i + 1..] {
+            gaps.push(PriceGap::between(question_id, a, b));
+            // Book spreads can make either direction the profitable one
+            opportunities.extend(best_hedge(question_id, a, b));
+            opportunities.extend(best_hedge(question_id, b, a));
+        }
+    }
+    opportunities.sort_by(|x, y| y.profit.total_cmp(&x.profit));
+    (gaps, opportunities)
+}
+
+/// Fetches every linked leg and reports gaps net of fees
+pub struct ArbitrageDetector {
+    pub registry: QuestionRegistry,
+    #[cfg(feature = "polymarket")]
+    polymarket: Option<Arc<PolymarketOracle>>,
+    #[cfg(feature = "omen")]
+    omen: Option<Arc<OmenOracle>>,
+    internal: Option<Arc<RwLock<PredictionMarket>>>,
+}
+
+impl ArbitrageDetector {
+    pub fn new(registry: QuestionRegistry) -> Self {
+        Self {
+            registry,
+            #[cfg(feature = "polymarket")]
+            polymarket: None,
+            #[cfg(feature = "omen")]
+            omen: None,
+            internal: None,
+        }
+    }
+
+    #[cfg(feature = "polymarket")]
+    pub fn with_polymarket(mut self, oracle: Arc<PolymarketOracle>) -> Self {
+        self.polymarket = Some(oracle);
+        self
+    }
+
+    #[cfg(feature = "omen")]
+    pub fn with_omen(mut self, oracle: Arc<OmenOracle>) -> Self {
+        self.omen = Some(oracle);
+        self
+    }
+
+    pub fn with_internal(mut self, market: Arc<RwLock<PredictionMarket>>) -> Self {
+        self.internal = Some(market);
+        self
+    }
+
+    /// Fetch current liquidity for one leg
+    pub async fn quote(&self, leg: &QuestionLeg) -> Result<VenueQuote, String> {
+        let liquidity = match &leg.market {
+            #[cfg(feature = "polymarket")]
+            VenueMarket::Polymarket { market_id } => {
+                let oracle = self.polymarket.as_ref().ok_or("Polymarket oracle not configured")?;
+                let market = oracle.fetch_market(market_id).await?;
+                let yes_token = market.clob_token_ids.first()
+                    .ok_or_else(|| format!("Market {} has no CLOB tokens", market_id))?;
+                let book = oracle.fetch_order_book(yes_token).await?;
+                Liquidity::book(book.bid_levels(), book.ask_levels(), leg.inverted, leg.taker_fee)
+            }
+            #[cfg(feature = "omen")]
+            VenueMarket::Omen { market_id, yes_index } => {
+                let oracle = self.omen.as_ref().ok_or("Omen oracle not configured")?;
+                let market = oracle.fetch_market(market_id).await?;
+                if *yes_index > 1 {
+                    return Err(format!("Omen market {}: yes_index {} is not a binary outcome slot", market_id, yes_index));
+                }
+                let yes_index = if leg.inverted { 1 - *yes_index } else { *yes_index };
+                Liquidity::amm(market.to_fpmm()?, yes_index)?
+            }
+            VenueMarket::Internal { event } => {
+                let markets = self.internal.as_ref().ok_or("Internal market not configured")?;
+                let markets = markets.read().map_err(|_| "Prediction market lock poisoned".to_string())?;
+                let market = markets.market(event).ok_or_else(|| format!("No internal market for: {}", event))?;
+                let yes_index = if leg.inverted { prediction_market::NO } else { prediction_market::YES };
+                Liquidity::amm(market.pool.clone(), yes_index)?
+            }
+            #[allow(unreachable_patterns)]
+            other => return Err(format!("{:?} support is not enabled in this build", other.source())),
+        };
+        Ok(VenueQuote { venue: leg.market.source(), market: leg.market.clone(), liquidity })
+    }
+
+    /// Scan every registered question; legs that fail to quote are logged and skipped
+    pub async fn scan(&self) -> (Vec<PriceGap>, Vec<ArbOpportunity>) {
+        let mut gaps = Vec::new();
+        let mut opportunities = Vec::new();
+        for question in self.registry.questions() {
+            let mut quotes = Vec::with_capacity(question.legs.len());
+            for leg in &question.legs {
+                match self.quote(leg).await {
+                    Ok(quote) => quotes.push(quote),
+                    Err(e) => log::warn!("Skipping {:?} leg of {}: {}", leg.market.source(), question.id, e),
+                }
+            }
+            let (g, o) = detect(&question.id, &quotes);
+            gaps.extend(g);
+            opportunities.extend(o);
+        }
+        opportunities.sort_by(|x, y| y.profit.total_cmp(&x.profit));
+        (gaps, opportunities)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use primitive_types::U256;
+
+    use super::*;
+
+    /// Binary pool funded with 1000 collateral at the hinted odds
+    fn pool(hint: [u64; 2]) -> FixedProductMarketMaker {
+        let mut pool = FixedProductMarketMaker::new(2, U256::from(fpmm::ONE / 100)).unwrap();
+        pool.add_funding(0, fpmm::from_f64(1000.0), &hint.map(U256::from)).unwrap();
+        pool
+    }
+
+    fn quote(venue: OracleSource, pool: FixedProductMarketMaker) -> VenueQuote {
+        VenueQuote {
+            venue,
+            market: VenueMarket::Internal { event: "q".to_string() },
+            liquidity: Liquidity::amm(pool, 0).unwrap(),
+        }
+    }
+
+    #[test]
+    fn non_binary_pools_are_rejected() {
+        let three = FixedProductMarketMaker::new(3, U256::zero()).unwrap();
+        assert!(Liquidity::amm(three, 0).is_err());
+        assert!(Liquidity::amm(pool([1, 1]), 2).is_err());
+
+        let mut registry = QuestionRegistry::new();
+        registry.register("q", "Will it ship?");
+        let leg = |yes_index| QuestionLeg {
+            market: VenueMarket::Omen { market_id: "0xomen".to_string(), yes_index },
+            inverted: true,
+            taker_fee: 0.0,
+        };
+        assert!(registry.link("q", leg(2)).is_err());
+        assert!(registry.link("q", leg(1)).is_ok());
+    }
+
+    #[test]
+    fn legs_are_labelled_by_price() {
+        // Hint [3, 1] leaves more Yes in the pool, so Yes is cheap (0.25)
+        let cheap = quote(OracleSource::Omen, pool([3, 1]));
+        let rich = quote(OracleSource::Internal, pool([1, 3]));
+        // Pass the rich quote first to make sure ordering does not leak into labels
+        let (gaps, opportunities) = detect("q", &[rich, cheap]);
+
+        assert_eq!(gaps.len(), 1);
+        assert_eq!((gaps[0].cheap, gaps[0].rich), (OracleSource::Omen, OracleSource::Internal));
+        assert!(gaps[0].gross_gap() > 0.4);
+
+        let best = &opportunities[0];
+        assert_eq!((best.yes_venue, best.no_venue), (OracleSource::Omen, OracleSource::Internal));
+        assert_eq!((best.gap.cheap, best.gap.rich), (OracleSource::Omen, OracleSource::Internal));
+        assert!(best.profit > 0.0);
+        assert!(best.yes_venue_price_after > best.gap.cheap_yes_price);
+        assert!(best.no_venue_price_after < best.gap.rich_yes_price);
+        assert!(opportunities.iter().all(|o| o.yes_venue == OracleSource::Omen));
+    }
+}
@@ -0,0 +1,421 @@
+//! Fixed-Product Market Maker — Local Mirror of Gnosis/Omen FixedProductMarketMaker.sol
+//! Ultramasterful n-outcome pricing, LP shares and fee accrual for futarchy simulation
+//!
+//! Integer arithmetic follows the contract exactly (uint256, ONE = 1e18, ceildiv rounding)
+//! so simulated trades match on-chain `calcBuyAmount` / `calcSellAmount` results.
+
+use std::collections::HashMap;
+
+use primitive_types::U256;
+
+/// Liquidity provider identifier
+pub type LpId = u64;
+
+/// Fixed-point one (fees are expressed as a fraction of ONE)
+pub const ONE: u128 = 1_000_000_000_000_000_000;
+
+fn one() -> U256 {
+    U256::from(ONE)
+}
+
+fn ceildiv(x: U256, y: U256) -> U256 {
+    if x.is_zero() {
+        U256::zero()
+    } else {
+        (x - 1) / y + 1
+    }
+}
+
+/// Lossy conversion for reporting prices and simulator-facing amounts
+pub fn to_f64(value: U256) -> f64 {
+    value.0.iter().rev().fold(0.0, |acc, &limb| acc * 18_446_744_073_709_551_616.0 + limb as f64)
+}
+
+/// Scale a simulator amount (whole collateral units) into base units
+pub fn from_f64(amount: f64) -> U256 {
+    U256::from((amount.max(0.0) * ONE as f64) as u128)
+}
+
+/// Result of `add_funding`: LP shares minted and outcome tokens returned to the provider
+#[derive(Clone, Debug, PartialEq)]
+pub struct FundingResult {
+    pub shares_minted: U256,
+    pub send_back_amounts: Vec<U256>,
+}
+
+/// Result of `remove_funding`: outcome tokens and accrued fees paid out
+#[derive(Clone, Debug, PartialEq)]
+pub struct WithdrawalResult {
+    pub outcome_amounts: Vec<U256>,
+    pub fees_withdrawn: U256,
+}
+
+/// n-outcome fixed-product market maker with LP fee pool
+#[derive(Clone, Debug)]
+pub struct FixedProductMarketMaker {
+    fee: U256,
+    pool_balances: Vec<U256>,
+    total_supply: U256,
+    lp_balances: HashMap<LpId, U256>,
+    fee_pool_weight: U256,
+    total_withdrawn_fees: U256,
+    withdrawn_fees: HashMap<LpId, U256>,
+}
+
+impl FixedProductMarketMaker {
+    /// Empty pool over `outcomes` outcome slots; `fee` is a fraction of ONE (2e16 = 2%)
+    pub fn new(outcomes: usize, fee: U256) -> Result<Self, String> {
+        if fee >= one() {
+            return Err(format!("fee {} must be below ONE", fee));
+        }
+        Ok(Self {
+            fee,
+            pool_balances: vec![U256::zero(); outcomes],
+            total_supply: U256::zero(),
+            lp_balances: HashMap::new(),
+            fee_pool_weight: U256::zero(),
+            total_withdrawn_fees: U256::zero(),
+            withdrawn_fees: HashMap::new(),
+        })
+    }
+
+    /// Pool seeded from observed on-chain state (e.g. an Omen subgraph snapshot)
+    pub fn from_pool_state(pool_balances: Vec<U256>, fee: U256, total_supply: U256) -> Result<Self, String> {
+        let mut fpmm = Self::new(pool_balances.len(), fee)?;
+        fpmm.pool_balances = pool_balances;
+        fpmm.total_supply = total_supply;
+        Ok(fpmm)
+    }
+
+    pub fn fee(&self) -> U256 {
+        self.fee
+    }
+
+    pub fn pool_balances(&self) -> &[U256] {
+        &self.pool_balances
+    }
+
+    pub fn total_supply(&self) -> U256 {
+        self.total_supply
+    }
+
+    pub fn balance_of(&self, lp: LpId) -> U256 {
+        self.lp_balances.get(&lp).copied().unwrap_or_default()
+    }
+
+    /// Marginal price per outcome: (1/balance_i) / Σ(1/balance_j)
+    pub fn prices(&self) -> Vec<f64> {
+        let n = self.pool_balances.len();
+        if self.pool_balances.iter().any(|b| b.is_zero()) {
+            return vec![1.0 / n as f64; n];
+        }
+        let inverse: Vec<f64> = self.pool_balances.iter().map(|&b| 1.0 / to_f64(b)).collect();
+        let total: f64 = inverse.iter().sum();
+        inverse.iter().map(|w| w / total).collect()
+    }
+
+    pub fn collected_fees(&self) -> U256 {
+        self.fee_pool_weight - self.total_withdrawn_fees
+    }
+
+    pub fn fees_withdrawable_by(&self, lp: LpId) -> U256 {
+        if self.total_supply.is_zero() {
+            return U256::zero();
+        }
+        let raw = self.fee_pool_weight * self.balance_of(lp) / self.total_supply;
+        raw.saturating_sub(self.withdrawn_fees.get(&lp).copied().unwrap_or_default())
+    }
+
+    /// Pay out an LP's share of accrued fees
+    pub fn withdraw_fees(&mut self, lp: LpId) -> U256 {
+        if self.total_supply.is_zero() {
+            return U256::zero();
+        }
+        let raw = self.fee_pool_weight * self.balance_of(lp) / self.total_supply;
+        let withdrawn = self.withdrawn_fees.entry(lp).or_default();
+        let withdrawable = raw.saturating_sub(*withdrawn);
+        if !withdrawable.is_zero() {
+            *withdrawn = raw;
+            self.total_withdrawn_fees += withdrawable;
+        }
+        withdrawable
+    }
+
+    /// Fee bookkeeping run before every LP share mint/burn (`_beforeTokenTransfer`)
+    fn before_share_transfer(&mut self, from: Option<LpId>, to: Option<LpId>, amount: U256) -> U256 {
+        let fees_paid = from.map(|lp| self.withdraw_fees(lp)).unwrap_or_default();
+
+        let transfer = if self.total_supply.is_zero() {
+            amount
+        } else {
+            self.fee_pool_weight * amount / self.total_supply
+        };
+        match from {
+            Some(lp) => {
+                let withdrawn = self.withdrawn_fees.entry(lp).or_default();
+                *withdrawn = withdrawn.saturating_sub(transfer);
+                self.total_withdrawn_fees = self.total_withdrawn_fees.saturating_sub(transfer);
+            }
+            None => self.fee_pool_weight += transfer,
+        }
+        match to {
+            Some(lp) => {
+                *self.withdrawn_fees.entry(lp).or_default() += transfer;
+                self.total_withdrawn_fees += transfer;
+            }
+            None => self.fee_pool_weight -= transfer,
+        }
+        fees_paid
+    }
+
+    /// Add collateral liquidity; `distribution_hint` sets initial odds and is only valid for the first deposit
+    pub fn add_funding(&mut self, lp: LpId, added_funds: U256, distribution_hint: &[U256]) -> Result<FundingResult, String> {
+        if added_funds.is_zero() {
+            return Err("funding must be non-zero".to_string());
+        }
+        let n = self.pool_balances.len();
+
+        let (send_back_amounts, mint_amount) = if !self.total_supply.is_zero() {
+            if !distribution_hint.is_empty() {
+                return Err("cannot use distribution hint after initial funding".to_string());
+            }
+            let pool_weight = self.pool_balances.iter().copied().max().unwrap_or_default();
+            if pool_weight.is_zero() {
+                return Err("pool has shares but no balances".to_string());
+            }
+            let send_back = self.pool_balances.iter()
+                .map(|&b| added_funds - b * added_funds / pool_weight)
+                .collect();
+            (send_back, added_funds * self.total_supply / pool_weight)
+        } else if !distribution_hint.is_empty() {
+            if distribution_hint.len() != n {
+                return Err("hint length off".to_string());
+            }
+            let max_hint = distribution_hint.iter().copied().max().unwrap_or_default();
+            if max_hint.is_zero() {
+                return Err("must hint a valid distribution".to_string());
+            }
+            let send_back = distribution_hint.iter()
+                .map(|&h| {
+                    let remaining = added_funds * h / max_hint;
+                    if remaining.is_zero() {
+                        return Err("must hint a valid distribution".to_string());
+                    }
+                    Ok(added_funds - remaining)
+                })
+                .collect::<Result<_, _>>()?;
+            (send_back, added_funds)
+        } else {
+            (vec![U256::zero(); n], added_funds)
+        };
+
+        // splitPosition: collateral becomes a full set of outcome tokens held by the pool
+        for (balance, send_back) in self.pool_balances.iter_mut().zip(&send_back_amounts) {
+            *balance = *balance + added_funds - *send_back;
+        }
+
+        self.before_share_transfer(None, Some(lp), mint_amount);
+        *self.lp_balances.entry(lp).or_default() += mint_amount;
+        self.total_supply += mint_amount;
+
+        Ok(FundingResult { shares_minted: mint_amount, send_back_amounts })
+    }
+
+    /// Burn LP shares for a proportional slice of every outcome balance plus accrued fees
+    pub fn remove_funding(&mut self, lp: LpId, shares_to_burn: U256) -> Result<WithdrawalResult, String> {
+        if shares_to_burn.is_zero() {
+            return Err("must burn a non-zero amount of shares".to_string());
+        }
+        if self.total_supply.is_zero() {
+            return Err("pool has no liquidity".to_string());
+        }
+        if shares_to_burn > self.balance_of(lp) {
+            return Err("insufficient LP shares".to_string());
+        }
+        let outcome_amounts: Vec<U256> = self.pool_balances.iter()
+            .map(|&b| b * shares_to_burn / self.total_supply)
+            .collect();
+
+        let fees_withdrawn = self.before_share_transfer(Some(lp), None, shares_to_burn);
+        *self.lp_balances.entry(lp).or_default() -= shares_to_burn;
+        self.total_supply -= shares_to_burn;
+
+        for (balance, amount) in self.pool_balances.iter_mut().zip(&outcome_amounts) {
+            *balance -= *amount;
+        }
+
+        Ok(WithdrawalResult { outcome_amounts, fees_withdrawn })
+    }
+
+    /// Outcome tokens received for investing `investment_amount` collateral in `outcome_index`
+    pub fn calc_buy_amount(&self, investment_amount: U256, outcome_index: usize) -> Result<U256, String> {
+        let buy_balance = *self.pool_balances.get(outcome_index).ok_or("invalid outcome index")?;
+        let investment_minus_fees = investment_amount - investment_amount * self.fee / one();
+
+        let mut ending_outcome_balance = buy_balance * one();
+        for (i, &pool_balance) in self.pool_balances.iter().enumerate() {
+            if i != outcome_index {
+                ending_outcome_balance = ceildiv(ending_outcome_balance * pool_balance, pool_balance + investment_minus_fees);
+            }
+        }
+        if ending_outcome_balance.is_zero() {
+            return Err("must have non-zero balances".to_string());
+        }
+        Ok(buy_balance + investment_minus_fees - ceildiv(ending_outcome_balance, one()))
+    }
+
+    /// Outcome tokens that must be sold to receive `return_amount` collateral from `outcome_index`
+    pub fn calc_sell_amount(&self, return_amount: U256, outcome_index: usize) -> Result<U256, String> {
+        let sell_balance = *self.pool_balances.get(outcome_index).ok_or("invalid outcome index")?;
+        let return_plus_fees = return_amount * one() / (one() - self.fee);
+
+        let mut ending_outcome_balance = sell_balance * one();
+        for (i, &pool_balance) in self.pool_balances.iter().enumerate() {
+            if i != outcome_index {
+                if pool_balance <= return_plus_fees {
+                    return Err("insufficient pool liquidity".to_string());
+                }
+                ending_outcome_balance = ceildiv(ending_outcome_balance * pool_balance, pool_balance - return_plus_fees);
+            }
+        }
+        if ending_outcome_balance.is_zero() {
+            return Err("must have non-zero balances".to_string());
+        }
+        Ok(return_plus_fees + ceildiv(ending_outcome_balance, one()) - sell_balance)
+    }
+
+    /// Buy outcome tokens; fees accrue to the LP fee pool
+    pub fn buy(&mut self, investment_amount: U256, outcome_index: usize, min_outcome_tokens: U256) -> Result<U256, String> {
+        let tokens = self.calc_buy_amount(investment_amount, outcome_index)?;
+        if tokens < min_outcome_tokens {
+            return Err("minimum buy amount not reached".to_string());
+        }
+
+        let fee_amount = investment_amount * self.fee / one();
+        self.fee_pool_weight += fee_amount;
+        let invested = investment_amount - fee_amount;
+
+        for balance in self.pool_balances.iter_mut() {
+            *balance += invested;
+        }
+        self.pool_balances[outcome_index] -= tokens;
+        Ok(tokens)
+    }
+
+    /// Sell outcome tokens for exactly `return_amount` collateral; fees accrue to the LP fee pool
+    pub fn sell(&mut self, return_amount: U256, outcome_index: usize, max_outcome_tokens: U256) -> Result<U256, String> {
+        let tokens = self.calc_sell_amount(return_amount, outcome_index)?;
+        if tokens > max_outcome_tokens {
+            return Err("maximum sell amount exceeded".to_string());
+        }
+
+        let return_plus_fees = return_amount * one() / (one() - self.fee);
+        self.fee_pool_weight += return_plus_fees - return_amount;
+
+        self.pool_balances[outcome_index] += tokens;
+        for balance in self.pool_balances.iter_mut() {
+            *balance -= return_plus_fees;
+        }
+        Ok(tokens)
+    }
+
+    /// Price impact of a prospective buy: (price before, price after, outcome tokens received)
+    pub fn buy_price_impact(&self, investment_amount: U256, outcome_index: usize) -> Result<(f64, f64, U256), String> {
+        let before = self.prices()[outcome_index];
+        let mut after = self.clone();
+        let tokens = after.buy(investment_amount, outcome_index, U256::zero())?;
+        Ok((before, after.prices()[outcome_index], tokens))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Vectors replayed through a uint256 port of FixedProductMarketMaker.sol:
+    // 3 outcomes, 2% fee, hinted 10e18 seed, buy/sell, second LP, buy, partial exit
+    fn wei(value: &str) -> U256 {
+        U256::from_dec_str(value).unwrap()
+    }
+
+    fn wei_vec(values: &[&str]) -> Vec<U256> {
+        values.iter().map(|v| wei(v)).collect()
+    }
+
+    fn seeded() -> FixedProductMarketMaker {
+        let mut fpmm = FixedProductMarketMaker::new(3, U256::from(ONE / 50)).unwrap();
+        let funded = fpmm.add_funding(1, wei("10000000000000000000"), &wei_vec(&["1", "2", "4"])).unwrap();
+        assert_eq!(funded.shares_minted, wei("10000000000000000000"));
+        assert_eq!(funded.send_back_amounts, wei_vec(&["7500000000000000000", "5000000000000000000", "0"]));
+        assert_eq!(fpmm.pool_balances(), wei_vec(&["2500000000000000000", "5000000000000000000", "10000000000000000000"]));
+        fpmm
+    }
+
+    #[test]
+    fn fee_must_be_below_one() {
+        assert!(FixedProductMarketMaker::new(2, U256::from(ONE)).is_err());
+        assert!(FixedProductMarketMaker::from_pool_state(vec![U256::one(); 2], U256::from(ONE) + 1, U256::one()).is_err());
+        assert!(FixedProductMarketMaker::new(2, U256::from(ONE - 1)).is_ok());
+    }
+
+    #[test]
+    fn unfunded_or_empty_pools_return_errors() {
+        let mut fpmm = FixedProductMarketMaker::new(2, U256::zero()).unwrap();
+        assert_eq!(fpmm.remove_funding(1, U256::zero()), Err("must burn a non-zero amount of shares".to_string()));
+        assert_eq!(fpmm.remove_funding(1, U256::one()), Err("pool has no liquidity".to_string()));
+
+        let mut drained = FixedProductMarketMaker::from_pool_state(vec![U256::zero(); 2], U256::zero(), wei("1000")).unwrap();
+        assert_eq!(drained.add_funding(1, wei("1000"), &[]), Err("pool has shares but no balances".to_string()));
+        assert_eq!(drained.total_supply(), wei("1000"));
+
+        let mut funded = seeded();
+        assert!(funded.remove_funding(1, U256::zero()).is_err());
+        assert_eq!(funded.balance_of(1), wei("10000000000000000000"));
+    }
+
+    #[test]
+    fn hint_must_leave_every_outcome_funded() {
+        let mut fpmm = FixedProductMarketMaker::new(2, U256::zero()).unwrap();
+        assert!(fpmm.add_funding(1, wei("1000"), &wei_vec(&["0", "1"])).is_err());
+        // 1000 * 1 / 10_000 rounds to zero
+        assert!(fpmm.add_funding(1, wei("1000"), &wei_vec(&["1", "10000"])).is_err());
+        assert!(fpmm.add_funding(1, wei("1000"), &wei_vec(&["1", "2"])).is_ok());
+        assert!(fpmm.add_funding(2, wei("1000"), &wei_vec(&["1", "2"])).is_err());
+    }
+
+    #[test]
+    fn buy_and_sell_match_contract() {
+        let mut fpmm = seeded();
+        assert_eq!(fpmm.calc_buy_amount(wei("2000000000000000000"), 0).unwrap(), wei("2958346980355975858"));
+        assert_eq!(fpmm.buy(wei("2000000000000000000"), 0, U256::zero()).unwrap(), wei("2958346980355975858"));
+        assert_eq!(fpmm.pool_balances(), wei_vec(&["1501653019644024142", "6960000000000000000", "11960000000000000000"]));
+
+        assert_eq!(fpmm.calc_sell_amount(wei("1000000000000000000"), 0).unwrap(), wei("1442521355959525756"));
+        assert!(fpmm.sell(wei("1000000000000000000"), 0, wei("1442521355959525755")).is_err());
+        assert_eq!(fpmm.sell(wei("1000000000000000000"), 0, U256::MAX).unwrap(), wei("1442521355959525756"));
+        assert_eq!(fpmm.pool_balances(), wei_vec(&["1923766212338243776", "5939591836734693878", "10939591836734693878"]));
+    }
+
+    #[test]
+    fn funding_and_fee_withdrawal_match_contract() {
+        let mut fpmm = seeded();
+        fpmm.buy(wei("2000000000000000000"), 0, U256::zero()).unwrap();
+        fpmm.sell(wei("1000000000000000000"), 0, U256::MAX).unwrap();
+
+        let funded = fpmm.add_funding(2, wei("5000000000000000000"), &[]).unwrap();
+        assert_eq!(funded.shares_minted, wei("4570554436236101783"));
+        assert_eq!(funded.send_back_amounts, wei_vec(&["4120732180391631735", "2285277218118050892", "0"]));
+        assert_eq!(fpmm.total_supply(), wei("14570554436236101783"));
+
+        assert_eq!(fpmm.buy(wei("3000000000000000000"), 2, U256::zero()).unwrap(), wei("13072592512375746348"));
+        assert_eq!(fpmm.fees_withdrawable_by(1), wei("101587104164619857"));
+        assert_eq!(fpmm.fees_withdrawable_by(2), wei("18821059100686264"));
+
+        let withdrawal = fpmm.remove_funding(1, wei("4000000000000000000")).unwrap();
+        assert_eq!(withdrawal.outcome_amounts, wei_vec(&["1576613726561846726", "3182943976320426767", "1594173879867545956"]));
+        assert_eq!(withdrawal.fees_withdrawn, wei("101587104164619857"));
+        assert_eq!(fpmm.pool_balances(), wei_vec(&["4166420305384765315", "8411370642296216219", "4212825444491401574"]));
+        assert_eq!(fpmm.total_supply(), wei("10570554436236101783"));
+        assert_eq!(fpmm.collected_fees(), wei("10677937637695271924") - wei("10659116578594585659"));
+    }
+}
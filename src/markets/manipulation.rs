@@ -0,0 +1,751 @@
+//! Manipulation Detection — Scored Alerts over Conditional Market Trade Streams
+//! Ultramasterful vigilance: the oracle watches the markets it relies on
+//!
+//! Detectors run over normalised binary trade streams (outcome 0 = Yes, 1 = No) from internal,
+//! Omen and imported Augur markets, plus CLOB book snapshots:
+//! - wash trading between linked accounts
+//! - last-minute price pushes before the decision
+//! - spoofing (large resting orders pulled before they can fill)
+//! - Pass/Reject divergence unexplained by volume
+//! - concentration of positions in a few account groups
+
+use std::collections::{BTreeMap, HashMap};
+use std::time::UNIX_EPOCH;
+
+use crate::oracles::augur_history::{AugurMarket, AugurTrade, TradeSide};
+#[cfg(feature = "omen")]
+use crate::oracles::omen_oracle::{self, OmenTrade};
+#[cfg(feature = "polymarket")]
+use crate::oracles::polymarket_oracle::OrderBook;
+use crate::markets::prediction_market::{InternalTrade, NO, YES};
+
+/// Maximum evidence lines attached to one alert
+const MAX_EVIDENCE: usize = 5;
+
+/// One fill in a binary market, normalised across venues
+#[derive(Clone, Debug)]
+pub struct TradeEvent {
+    pub market: String,
+    pub trader: String,
+    /// Unix seconds
+    pub timestamp: u64,
+    /// YES or NO
+    pub outcome: usize,
+    pub side: TradeSide,
+    /// Average fill price of `outcome`
+    pub price: f64,
+    /// Outcome tokens traded
+    pub amount: f64,
+}
+
+impl TradeEvent {
+    /// Yes probability implied by this fill
+    pub fn yes_price(&self) -> f64 {
+        if self.outcome == YES { self.price } else { 1.0 - self.price }
+    }
+
+    /// Signed Yes exposure this fill adds (buying No is short Yes)
+    fn yes_exposure(&self) -> f64 {
+        let sign = if (self.side == TradeSide::Buy) == (self.outcome == YES) { 1.0 } else { -1.0 };
+        sign * self.amount
+    }
+
+    pub fn from_internal(event: &str, trade: &InternalTrade) -> Self {
+        Self {
+            market: event.to_string(),
+            trader: trade.trader.clone(),
+            timestamp: trade.timestamp.duration_since(UNIX_EPOCH).unwrap_or_default().as_secs(),
+            outcome: trade.outcome,
+            side: TradeSide::Buy,
+            price: trade.collateral / trade.tokens.max(f64::EPSILON),
+            amount: trade.tokens,
+        }
+    }
+
+    /// `yes_index` is the FPMM slot equivalent to Yes; trades on other slots count as No
+    #[cfg(feature = "omen")]
+    pub fn from_omen(market_id: &str, trade: &OmenTrade, yes_index: usize) -> Self {
+        let tokens = trade.outcome_tokens_traded as f64;
+        Self {
+            market: market_id.to_string(),
+            trader: trade.trader.clone(),
+            timestamp: trade.timestamp,
+            outcome: if trade.outcome_index == yes_index { YES } else { NO },
+            side: match trade.side {
+                omen_oracle::TradeSide::Buy => TradeSide::Buy,
+                omen_oracle::TradeSide::Sell => TradeSide::Sell,
+            },
+            price: trade.collateral_amount as f64 / tokens.max(1.0),
+            amount: tokens / crate::markets::fpmm::ONE as f64,
+        }
+    }
+
+    /// None for trades on Augur's Invalid outcome or non-Yes/No markets
+    pub fn from_augur(market: &AugurMarket, trade: &AugurTrade) -> Option<Self> {
+        if !market.is_yes_no() || (market.version >= 2 && trade.outcome == 0) {
+            return None;
+        }
+        Some(Self {
+            market: trade.market_id.clone(),
+            trader: trade.trader.clone(),
+            timestamp: trade.timestamp,
+            outcome: if trade.outcome == market.yes_outcome() { YES } else { NO },
+            side: trade.side,
+            price: trade.price,
+            amount: trade.amount,
+        })
+    }
+}
+
+/// One CLOB order book observation
+#[derive(Clone, Debug)]
+pub struct BookSnapshot {
+    /// Unix seconds
+    pub timestamp: u64,
+    pub bids: Vec<(f64, f64)>,
+    pub asks: Vec<(f64, f64)>,
+}
+
+impl BookSnapshot {
+    #[cfg(feature = "polymarket")]
+    pub fn from_order_book(book: &OrderBook, observed_at: std::time::SystemTime) -> Self {
+        Self {
+            timestamp: observed_at.duration_since(UNIX_EPOCH).unwrap_or_default().as_secs(),
+            bids: book.bid_levels(),
+            asks: book.ask_levels(),
+        }
+    }
+
+    fn best_bid(&self) -> Option<f64> {
+        self.bids.iter().map(|&(p, _)| p).reduce(f64::max)
+    }
+
+    fn best_ask(&self) -> Option<f64> {
+        self.asks.iter().map(|&(p, _)| p).reduce(f64::min)
+    }
+}
+
+/// Accounts known to be controlled by the same party (union-find)
+#[derive(Clone, Debug, Default)]
+pub struct AccountLinks {
+    parent: HashMap<String, String>,
+}
+
+impl AccountLinks {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn link(&mut self, a: &str, b: &str) {
+        let (ra, rb) = (self.group_of(a), self.group_of(b));
+        if ra != rb {
+            // Lexically smallest account represents the group, so group names are stable
+            let (child, root) = if ra < rb { (rb, ra) } else { (ra, rb) };
+            self.parent.insert(child, root);
+        }
+    }
+
+    /// Representative account of `account`'s group (itself when unlinked)
+    pub fn group_of(&self, account: &str) -> String {
+        let mut current = account;
+        while let Some(next) = self.parent.get(current) {
+            current = next;
+        }
+        current.to_string()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AlertKind {
+    WashTrading,
+    LastMinutePush,
+    Spoofing,
+    UnexplainedDivergence,
+    PositionConcentration,
+}
+
+/// Scored manipulation alert
+#[derive(Clone, Debug)]
+pub struct ManipulationAlert {
+    pub kind: AlertKind,
+    pub market: String,
+    /// Suspicion in [0, 1]
+    pub score: f64,
+    /// Accounts (or account groups) implicated
+    pub accounts: Vec<String>,
+    /// Unix-second window the evidence covers
+    pub window: (u64, u64),
+    pub evidence: Vec<String>,
+}
+
+/// Detector thresholds
+#[derive(Clone, Debug)]
+pub struct DetectionConfig {
+    /// Max seconds between the two legs of a wash round-trip
+    pub wash_window_secs: u64,
+    /// Max relative size difference between wash legs
+    pub wash_size_tolerance: f64,
+    /// Min share of a group's volume that must be round-tripped to alert
+    pub wash_min_share: f64,
+    /// Length of the pre-decision window watched for pushes
+    pub push_window_secs: u64,
+    /// Z-score of the final-window move (vs earlier windows) that triggers an alert
+    pub push_z_threshold: f64,
+    /// Floor on baseline volatility so quiet markets don't alert on tiny moves
+    pub push_min_volatility: f64,
+    /// Resting size, as a multiple of the side's median level, that counts as large
+    pub spoof_size_multiple: f64,
+    /// Max distance from the touch for a large order to matter
+    pub spoof_max_distance: f64,
+    /// Large orders cancelled within this many seconds without filling are suspicious
+    pub spoof_max_lifetime_secs: u64,
+    /// Bucket length for Pass/Reject divergence
+    pub divergence_window_secs: u64,
+    /// Divergence move one token of volume can explain
+    pub divergence_impact_per_token: f64,
+    /// Min unexplained divergence move to alert
+    pub divergence_min_move: f64,
+    /// Share of net positions held by one group that triggers an alert
+    pub concentration_threshold: f64,
+    /// Ignore markets whose open interest is below this
+    pub concentration_min_interest: f64,
+}
+
+impl Default for DetectionConfig {
+    fn default() -> Self {
+        Self {
+            wash_window_secs: 10 * 60,
+            wash_size_tolerance: 0.05,
+            wash_min_share: 0.2,
+            push_window_secs: 60 * 60,
+            push_z_threshold: 3.0,
+            push_min_volatility: 0.01,
+            spoof_size_multiple: 5.0,
+            spoof_max_distance: 0.05,
+            spoof_max_lifetime_secs: 60,
+            divergence_window_secs: 60 * 60,
+            divergence_impact_per_token: 0.0005,
+            divergence_min_move: 0.05,
+            concentration_threshold: 0.5,
+            concentration_min_interest: 100.0,
+        }
+    }
+}
+
+pub struct ManipulationDetector {
+    pub config: DetectionConfig,
+    links: AccountLinks,
+}
+
+impl ManipulationDetector {
+    pub fn new(config: DetectionConfig) -> Self {
+        Self { config, links: AccountLinks::new() }
+    }
+
+    pub fn with_links(mut self, links: AccountLinks) -> Self {
+        self.links = links;
+        self
+    }
+
+    pub fn links_mut(&mut self) -> &mut AccountLinks {
+        &mut self.links
+    }
+
+    /// Opposing fills of similar size by one account group within the wash window
+    pub fn detect_wash_trading(&self, market: &str, trades: &[TradeEvent]) -> Vec<ManipulationAlert> {
+        let cfg = &self.config;
+        let mut by_group: HashMap<String, Vec<&TradeEvent>> = HashMap::new();
+        for trade in trades {
+            by_group.entry(self.links.group_of(&trade.trader)).or_default().push(trade);
+        }
+
+        let mut alerts = Vec::new();
+        for (group, mut legs) in by_group {
+            legs.sort_by_key(|t| t.timestamp);
+            let volume: f64 = legs.iter().map(|t| t.amount).sum();
+            let mut matched = vec![false; legs.len()];
+            let mut washed = 0.0;
+            let mut evidence = Vec::new();
+            let mut accounts: Vec<String> = Vec::new();
+
+            for i in 0..legs.len() {
+                if matched[i] {
+                    continue;
+                }
+                let a = legs[i];
+                let partner = (i + 1..legs.len())
+                    .take_while(|&j| legs[j].timestamp - a.timestamp <= cfg.wash_window_secs)
+                    .find(|&j| {
+                        let b = legs[j];
+                        !matched[j]
+                            && a.yes_exposure().signum() != b.yes_exposure().signum()
+                            && (a.amount - b.amount).abs() <= cfg.wash_size_tolerance * a.amount.max(b.amount)
+                    });
+                if let Some(j) = partner {
+                    let b = legs[j];
+                    matched[i] = true;
+                    matched[j] = true;
+                    washed += a.amount + b.amount;
+                    for trader in [&a.trader, &b.trader] {
+                        if !accounts.contains(trader) {
+                            accounts.push(trader.clone());
+                        }
+                    }
+                    if evidence.len() < MAX_EVIDENCE {
+                        evidence.push(format!(
+                            "{} {:.2} @ {:.3} (t={}) offset by {} {:.2} @ {:.3} (t={})",
+                            a.trader, a.yes_exposure(), a.yes_price(), a.timestamp,
+                            b.trader, b.yes_exposure(), b.yes_price(), b.timestamp,
+                        ));
+                    }
+                }
+            }
+
+            let share = washed / volume.max(f64::EPSILON);
+            if washed > 0.0 && share >= cfg.wash_min_share {
+                let first = legs.first().map_or(0, |t| t.timestamp);
+                let last = legs.last().map_or(0, |t| t.timestamp);
+                evidence.insert(0, format!("Group {} round-tripped {:.2} of {:.2} tokens ({:.0}%)", group, washed, volume, share * 100.0));
+                alerts.push(ManipulationAlert {
+                    kind: AlertKind::WashTrading,
+                    market: market.to_string(),
+                    score: share.min(1.0),
+                    accounts,
+                    window: (first, last),
+                    evidence,
+                });
+            }
+        }
+        alerts
+    }
+
+    /// Yes-price move over the final window before `decision_time`, scored against earlier windows
+    pub fn detect_last_minute_push(&self, market: &str, trades: &[TradeEvent], decision_time: u64) -> Option<ManipulationAlert> {
+        let cfg = &self.config;
+        let mut sorted: Vec<&TradeEvent> = trades.iter().filter(|t| t.timestamp <= decision_time).collect();
+        sorted.sort_by_key(|t| t.timestamp);
+        let first = sorted.first()?.timestamp;
+        let window = cfg.push_window_secs.max(1);
+        let push_start = decision_time.saturating_sub(window);
+
+        // Price at the end of each window, walking back from the decision
+        let price_at = |t: u64| sorted.iter().take_while(|tr| tr.timestamp <= t).last().map(|tr| tr.yes_price());
+        let mut moves = Vec::new();
+        let mut end = push_start;
+        while end >= first + window {
+            if let (Some(open), Some(close)) = (price_at(end - window), price_at(end)) {
+                moves.push(close - open);
+            }
+            end -= window;
+        }
+        let open = price_at(push_start).or_else(|| sorted.first().map(|t| t.yes_price()))?;
+        let close = price_at(decision_time)?;
+        let push = close - open;
+
+        let baseline = if moves.is_empty() {
+            0.0
+        } else {
+            (moves.iter().map(|m| m * m).sum::<f64>() / moves.len() as f64).sqrt()
+        };
+        let z = push.abs() / baseline.max(cfg.push_min_volatility);
+        if z < cfg.push_z_threshold {
+            return None;
+        }
+
+        // Traders whose final-window flow pushed in the direction of the move
+        let mut flow: HashMap<String, f64> = HashMap::new();
+        for trade in sorted.iter().filter(|t| t.timestamp > push_start) {
+            *flow.entry(self.links.group_of(&trade.trader)).or_default() += trade.yes_exposure() * push.signum();
+        }
+        let mut pushers: Vec<(String, f64)> = flow.into_iter().filter(|(_, f)| *f > 0.0).collect();
+        pushers.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+        let mut evidence = vec![format!(
+            "Yes moved {:.3} -> {:.3} in the final {}s (z = {:.1} vs baseline {:.3} over {} windows)",
+            open, close, window, z, baseline, moves.len(),
+        )];
+        evidence.extend(pushers.iter().take(MAX_EVIDENCE - 1).map(|(g, f)| format!("{} pushed {:.2} tokens", g, f)));
+
+        Some(ManipulationAlert {
+            kind: AlertKind::LastMinutePush,
+            market: market.to_string(),
+            score: (z / (2.0 * cfg.push_z_threshold)).min(1.0),
+            accounts: pushers.into_iter().map(|(g, _)| g).collect(),
+            window: (push_start, decision_time),
+            evidence,
+        })
+    }
+
+    /// Large near-touch orders that vanish within the lifetime limit without the book trading through them
+    pub fn detect_spoofing(&self, market: &str, snapshots: &[BookSnapshot]) -> Vec<ManipulationAlert> {
+        let cfg = &self.config;
+        // (is_bid, price in ticks) -> (first seen, peak size, size ratio)
+        let mut live: HashMap<(bool, i64), (u64, f64, f64)> = HashMap::new();
+        let mut alerts = Vec::new();
+        let tick = |p: f64| (p * 10_000.0).round() as i64;
+        let mut snapshots: Vec<&BookSnapshot> = snapshots.iter().collect();
+        snapshots.sort_by_key(|s| s.timestamp);
+
+        for (idx, &snap) in snapshots.iter().enumerate() {
+            let mut seen = Vec::new();
+            for (is_bid, side, touch) in [(true, &snap.bids, snap.best_bid()), (false, &snap.asks, snap.best_ask())] {
+                let (Some(touch), Some(median)) = (touch, median(side.iter().map(|&(_, s)| s).collect())) else {
+                    continue;
+                };
+                for &(price, size) in side {
+                    let ratio = size / median.max(f64::EPSILON);
+                    if ratio >= cfg.spoof_size_multiple && (price - touch).abs() <= cfg.spoof_max_distance {
+                        let key = (is_bid, tick(price));
+                        let entry = live.entry(key).or_insert((snap.timestamp, size, ratio));
+                        entry.1 = entry.1.max(size);
+                        entry.2 = entry.2.max(ratio);
+                        seen.push(key);
+                    }
+                }
+            }
+
+            // Orders that were live but are gone in this snapshot
+            let gone: Vec<(bool, i64)> = live.keys().filter(|k| !seen.contains(k)).copied().collect();
+            for key in gone {
+                let (since, size, ratio) = live.remove(&key).unwrap();
+                let lifetime = snap.timestamp.saturating_sub(since);
+                let price = key.1 as f64 / 10_000.0;
+                // Filled (not pulled) if the opposite touch reached the order before it vanished
+                let filled = snapshots[..=idx].iter()
+                    .filter(|s| s.timestamp >= since)
+                    .any(|s| if key.0 { s.best_ask().is_some_and(|a| a <= price) } else { s.best_bid().is_some_and(|b| b >= price) });
+                if filled || lifetime > cfg.spoof_max_lifetime_secs {
+                    continue;
+                }
+                alerts.push(ManipulationAlert {
+                    kind: AlertKind::Spoofing,
+                    market: market.to_string(),
+                    score: (ratio / (2.0 * cfg.spoof_size_multiple)).min(1.0),
+                    accounts: Vec::new(),
+                    window: (since, snap.timestamp),
+                    evidence: vec![format!(
+                        "{} of {:.2} @ {:.4} ({:.1}x median level) pulled after {}s without filling",
+                        if key.0 { "Bid" } else { "Ask" }, size, price, ratio, lifetime,
+                    )],
+                });
+            }
+        }
+        alerts
+    }
+
+    /// Windows where the Pass − Reject Yes spread moved more than their combined volume explains
+    pub fn detect_divergence(&self, proposal: &str, pass: &[TradeEvent], reject: &[TradeEvent]) -> Vec<ManipulationAlert> {
+        let cfg = &self.config;
+        let window = cfg.divergence_window_secs.max(1);
+        let bucket = |trades: &[TradeEvent]| {
+            let mut buckets: BTreeMap<u64, (f64, f64)> = BTreeMap::new();
+            let mut sorted: Vec<&TradeEvent> = trades.iter().collect();
+            sorted.sort_by_key(|t| t.timestamp);
+            for trade in sorted {
+                let entry = buckets.entry(trade.timestamp / window).or_insert((0.0, 0.0));
+                entry.0 = trade.yes_price();
+                entry.1 += trade.amount;
+            }
+            buckets
+        };
+        let (pass, reject) = (bucket(pass), bucket(reject));
+
+        let mut alerts = Vec::new();
+        let (mut last_pass, mut last_reject) = (None, None);
+        let mut prev_spread: Option<f64> = None;
+        let keys: std::collections::BTreeSet<u64> = pass.keys().chain(reject.keys()).copied().collect();
+        for key in keys {
+            let (pass_close, pass_volume) = pass.get(&key).map_or((last_pass, 0.0), |&(p, v)| (Some(p), v));
+            let (reject_close, reject_volume) = reject.get(&key).map_or((last_reject, 0.0), |&(p, v)| (Some(p), v));
+            last_pass = pass_close;
+            last_reject = reject_close;
+            let (Some(p), Some(r)) = (pass_close, reject_close) else {
+                continue;
+            };
+            let spread = p - r;
+            if let Some(prev) = prev_spread {
+                let moved = (spread - prev).abs();
+                let volume = pass_volume + reject_volume;
+                let unexplained = moved - cfg.divergence_impact_per_token * volume;
+                if unexplained >= cfg.divergence_min_move {
+                    alerts.push(ManipulationAlert {
+                        kind: AlertKind::UnexplainedDivergence,
+                        market: proposal.to_string(),
+                        score: (unexplained / moved).min(1.0),
+                        accounts: Vec::new(),
+                        window: (key * window, (key + 1) * window),
+                        evidence: vec![format!(
+                            "Pass−Reject spread {:.3} -> {:.3} on {:.2} tokens of volume ({:.3} unexplained)",
+                            prev, spread, volume, unexplained,
+                        )],
+                    });
+                }
+            }
+            prev_spread = Some(spread);
+        }
+        alerts
+    }
+
+    /// Groups holding more than the threshold share of net Yes or No exposure
+    pub fn detect_concentration(&self, market: &str, trades: &[TradeEvent]) -> Vec<ManipulationAlert> {
+        let cfg = &self.config;
+        let mut exposure: HashMap<String, f64> = HashMap::new();
+        for trade in trades {
+            *exposure.entry(self.links.group_of(&trade.trader)).or_default() += trade.yes_exposure();
+        }
+        let window = (
+            trades.iter().map(|t| t.timestamp).min().unwrap_or(0),
+            trades.iter().map(|t| t.timestamp).max().unwrap_or(0),
+        );
+
+        let mut alerts = Vec::new();
+        for (side, sign) in [("Yes", 1.0), ("No", -1.0)] {
+            let mut holders: Vec<(String, f64)> = exposure.iter()
+                .map(|(g, &e)| (g.clone(), e * sign))
+                .filter(|(_, e)| *e > 0.0)
+                .collect();
+            let interest: f64 = holders.iter().map(|(_, e)| e).sum();
+            if holders.is_empty() || interest < cfg.concentration_min_interest {
+                continue;
+            }
+            holders.sort_by(|a, b| b.1.total_cmp(&a.1));
+            let top_share = holders[0].1 / interest;
+            if top_share < cfg.concentration_threshold {
+                continue;
+            }
+            let hhi: f64 = holders.iter().map(|(_, e)| (e / interest).powi(2)).sum();
+            let mut evidence = vec![format!("Net {} interest {:.2}, HHI {:.3}", side, interest, hhi)];
+            evidence.extend(holders.iter().take(MAX_EVIDENCE - 1)
+                .map(|(g, e)| format!("{} holds {:.2} ({:.0}%)", g, e, e / interest * 100.0)));
+            alerts.push(ManipulationAlert {
+                kind: AlertKind::PositionConcentration,
+                market: market.to_string(),
+                score: top_share,
+                accounts: holders.iter().take_while(|(_, e)| e / interest >= cfg.concentration_threshold).map(|(g, _)| g.clone()).collect(),
+                window,
+                evidence,
+            });
+        }
+        alerts
+    }
+
+    /// Trade-stream detectors for one market, highest score first
+    pub fn scan_market(&self, market: &str, trades: &[TradeEvent], decision_time: Option<u64>) -> Vec<ManipulationAlert> {
+        let mut alerts = self.detect_wash_trading(market, trades);
+        if let Some(t) = decision_time {
+            alerts.extend(self.detect_last_minute_push(market, trades, t));
+        }
+        alerts.extend(self.detect_concentration(market, trades));
+        alerts.sort_by(|a, b| b.score.total_cmp(&a.score));
+        alerts
+    }
+
+    /// Both conditional markets of a proposal plus their divergence, highest score first
+    pub fn scan_conditional(&self, proposal: &str, pass: &[TradeEvent], reject: &[TradeEvent], decision_time: Option<u64>) -> Vec<ManipulationAlert> {
+        let mut alerts = self.scan_market(&format!("{}:pass", proposal), pass, decision_time);
+        alerts.extend(self.scan_market(&format!("{}:reject", proposal), reject, decision_time));
+        alerts.extend(self.detect_divergence(proposal, pass, reject));
+        alerts.sort_by(|a, b| b.score.total_cmp(&a.score));
+        alerts
+    }
+}
+
+fn median(mut values: Vec<f64>) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+    values.sort_by(f64::total_cmp);
+    Some(values[values.len() / 2])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trade(trader: &str, timestamp: u64, outcome: usize, side: TradeSide, price: f64, amount: f64) -> TradeEvent {
+        TradeEvent { market: "m".to_string(), trader: trader.to_string(), timestamp, outcome, side, price, amount }
+    }
+
+    fn buy_yes(trader: &str, timestamp: u64, price: f64, amount: f64) -> TradeEvent {
+        trade(trader, timestamp, YES, TradeSide::Buy, price, amount)
+    }
+
+    fn detector() -> ManipulationDetector {
+        let mut links = AccountLinks::new();
+        links.link("alice2", "alice");
+        links.link("whale", "whale2");
+        ManipulationDetector::new(DetectionConfig::default()).with_links(links)
+    }
+
+    /// alice buys 10 Yes, linked alice2 sells `offset` Yes `gap` seconds later; `other` unrelated volume
+    fn wash_fixture(gap: u64, offset: f64, other: f64) -> Vec<TradeEvent> {
+        vec![
+            buy_yes("alice", 100, 0.5, 10.0),
+            buy_yes("bob", 150, 0.55, 30.0),
+            trade("alice2", 100 + gap, YES, TradeSide::Sell, 0.5, offset),
+            buy_yes("alice", 5_000, 0.6, other),
+        ]
+    }
+
+    #[test]
+    fn wash_round_trip_between_linked_accounts() {
+        let alerts = detector().detect_wash_trading("m", &wash_fixture(100, 10.0, 20.0));
+        assert_eq!(alerts.len(), 1);
+        let alert = &alerts[0];
+        assert_eq!(alert.kind, AlertKind::WashTrading);
+        assert!((alert.score - 0.5).abs() < 1e-12);
+        assert_eq!(alert.accounts, vec!["alice", "alice2"]);
+        assert_eq!(alert.window, (100, 5_000));
+        assert_eq!(alert.evidence, vec![
+            "Group alice round-tripped 20.00 of 40.00 tokens (50%)",
+            "alice 10.00 @ 0.500 (t=100) offset by alice2 -10.00 @ 0.500 (t=200)",
+        ]);
+
+        // Size difference right at the 5% tolerance still matches
+        assert_eq!(detector().detect_wash_trading("m", &wash_fixture(100, 10.5, 20.0)).len(), 1);
+    }
+
+    #[test]
+    fn wash_near_misses_stay_quiet() {
+        let detector = detector();
+        // Second leg one second past the 600s window
+        assert!(detector.detect_wash_trading("m", &wash_fixture(601, 10.0, 20.0)).is_empty());
+        assert_eq!(detector.detect_wash_trading("m", &wash_fixture(600, 10.0, 20.0)).len(), 1);
+        // Legs 10 vs 10.6 differ by more than 5% of the larger
+        assert!(detector.detect_wash_trading("m", &wash_fixture(100, 10.6, 20.0)).is_empty());
+        // 20 washed of 101 traded is just under the 20% share
+        assert!(detector.detect_wash_trading("m", &wash_fixture(100, 10.0, 81.0)).is_empty());
+        assert_eq!(detector.detect_wash_trading("m", &wash_fixture(100, 10.0, 80.0)).len(), 1);
+        // Unlinked accounts are not one party
+        let unlinked = ManipulationDetector::new(DetectionConfig::default());
+        assert!(unlinked.detect_wash_trading("m", &wash_fixture(100, 10.0, 20.0)).is_empty());
+    }
+
+    /// Hourly drift of ±0.01 for three hours, then mallory drives Yes to `close` before t = 14 400
+    fn push_fixture(close: f64) -> Vec<TradeEvent> {
+        vec![
+            buy_yes("dan", 0, 0.50, 5.0),
+            buy_yes("dan", 3_600, 0.51, 5.0),
+            trade("erin", 7_200, YES, TradeSide::Sell, 0.50, 5.0),
+            buy_yes("dan", 10_800, 0.51, 5.0),
+            buy_yes("mallory", 12_000, 0.54, 20.0),
+            trade("carol", 13_000, YES, TradeSide::Sell, 0.53, 5.0),
+            buy_yes("mallory", 14_000, close, 20.0),
+            // After the decision: ignored
+            buy_yes("late", 15_000, 0.90, 50.0),
+        ]
+    }
+
+    #[test]
+    fn last_minute_push_is_scored_against_earlier_windows() {
+        let alert = detector().detect_last_minute_push("m", &push_fixture(0.56), 14_400).unwrap();
+        assert_eq!(alert.kind, AlertKind::LastMinutePush);
+        // 0.05 move over a 0.01 baseline: z = 5, scored against 2 × threshold
+        assert!((alert.score - 5.0 / 6.0).abs() < 1e-9, "{}", alert.score);
+        assert_eq!(alert.window, (10_800, 14_400));
+        assert_eq!(alert.accounts, vec!["mallory"]);
+        assert_eq!(alert.evidence, vec![
+            "Yes moved 0.510 -> 0.560 in the final 3600s (z = 5.0 vs baseline 0.010 over 3 windows)",
+            "mallory pushed 40.00 tokens",
+        ]);
+    }
+
+    #[test]
+    fn push_just_under_threshold_is_quiet() {
+        // 0.029 / 0.01 = z 2.9
+        assert!(detector().detect_last_minute_push("m", &push_fixture(0.539), 14_400).is_none());
+        assert!(detector().detect_last_minute_push("m", &push_fixture(0.541), 14_400).is_some());
+        assert!(detector().detect_last_minute_push("m", &[], 14_400).is_none());
+    }
+
+    /// Pass climbs 0.60 → 0.70 while Reject holds 0.40, on `volume` tokens per market in the second hour
+    fn divergence_fixture(volume: f64) -> (Vec<TradeEvent>, Vec<TradeEvent>) {
+        let pass = vec![buy_yes("p", 100, 0.60, 10.0), buy_yes("p", 3_700, 0.70, volume)];
+        let reject = vec![buy_yes("r", 200, 0.40, 10.0), buy_yes("r", 3_800, 0.40, volume)];
+        (pass, reject)
+    }
+
+    #[test]
+    fn divergence_unexplained_by_volume_alerts() {
+        let (pass, reject) = divergence_fixture(10.0);
+        let alerts = detector().detect_divergence("prop", &pass, &reject);
+        assert_eq!(alerts.len(), 1);
+        let alert = &alerts[0];
+        assert_eq!(alert.kind, AlertKind::UnexplainedDivergence);
+        assert_eq!(alert.market, "prop");
+        // 0.1 move, 20 tokens explain 0.01
+        assert!((alert.score - 0.9).abs() < 1e-9, "{}", alert.score);
+        assert_eq!(alert.window, (3_600, 7_200));
+        assert_eq!(alert.evidence, vec!["Pass−Reject spread 0.200 -> 0.300 on 20.00 tokens of volume (0.090 unexplained)"]);
+    }
+
+    #[test]
+    fn divergence_explained_by_volume_is_quiet() {
+        // 101 tokens explain 0.0505 of the 0.1 move, leaving 0.0495 < 0.05
+        let (pass, reject) = divergence_fixture(50.5);
+        assert!(detector().detect_divergence("prop", &pass, &reject).is_empty());
+        let (pass, reject) = divergence_fixture(49.0);
+        assert_eq!(detector().detect_divergence("prop", &pass, &reject).len(), 1);
+    }
+
+    #[test]
+    fn concentrated_holder_group_alerts() {
+        let trades = vec![
+            buy_yes("whale", 10, 0.5, 60.0),
+            buy_yes("whale2", 20, 0.5, 10.0),
+            buy_yes("bob", 30, 0.5, 30.0),
+            // Net No interest of 20 is under the 100 minimum
+            trade("carol", 40, NO, TradeSide::Buy, 0.5, 20.0),
+        ];
+        let alerts = detector().detect_concentration("m", &trades);
+        assert_eq!(alerts.len(), 1);
+        let alert = &alerts[0];
+        assert_eq!(alert.kind, AlertKind::PositionConcentration);
+        assert!((alert.score - 0.7).abs() < 1e-12);
+        assert_eq!(alert.accounts, vec!["whale"]);
+        assert_eq!(alert.window, (10, 40));
+        assert_eq!(alert.evidence, vec![
+            "Net Yes interest 100.00, HHI 0.580",
+            "whale holds 70.00 (70%)",
+            "bob holds 30.00 (30%)",
+        ]);
+    }
+
+    #[test]
+    fn concentration_near_misses_stay_quiet() {
+        let detector = detector();
+        // Top group holds 49.9% of 100
+        let spread = vec![buy_yes("whale", 10, 0.5, 49.9), buy_yes("bob", 20, 0.5, 30.0), buy_yes("dan", 30, 0.5, 20.1)];
+        assert!(detector.detect_concentration("m", &spread).is_empty());
+        // 70% held, but open interest 99.9 is under the minimum
+        let thin = vec![buy_yes("whale", 10, 0.5, 70.0), buy_yes("bob", 20, 0.5, 29.9)];
+        assert!(detector.detect_concentration("m", &thin).is_empty());
+    }
+
+    fn snapshot(timestamp: u64, spoof: bool) -> BookSnapshot {
+        let mut bids = vec![(0.49, 10.0), (0.48, 10.0), (0.47, 10.0)];
+        if spoof {
+            bids.push((0.46, 500.0));
+        }
+        BookSnapshot { timestamp, bids, asks: vec![(0.51, 10.0), (0.52, 10.0), (0.53, 10.0)] }
+    }
+
+    #[test]
+    fn out_of_order_snapshots_do_not_panic() {
+        let detector = ManipulationDetector::new(DetectionConfig::default());
+        // Arrives newest-first; in time order the large bid lives 100..110 and is pulled
+        let snapshots = [snapshot(120, false), snapshot(110, true), snapshot(100, true)];
+        let alerts = detector.detect_spoofing("m", &snapshots);
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].window, (100, 120));
+
+        // Unsorted, the order would appear to vanish before it was placed
+        assert!(detector.detect_spoofing("m", &[snapshot(110, true), snapshot(100, false)]).is_empty());
+    }
+
+    #[test]
+    fn concentration_without_holders_is_quiet() {
+        let detector = ManipulationDetector::new(DetectionConfig { concentration_min_interest: 0.0, ..DetectionConfig::default() });
+        assert!(detector.detect_concentration("m", &[]).is_empty());
+    }
+}
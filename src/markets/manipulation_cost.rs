@@ -0,0 +1,451 @@
+//! Manipulation Cost — Capital Required to Flip a Futarchy Decision
+//! Ultramasterful audit: a decision is only as trustworthy as it is expensive to buy
+//!
+//! Each conditional market is modelled by its market maker (LMSR, binary CPMM or CLOB depth).
+//! The calculator searches over Pass/Reject price targets that flip the configured decision rule
+//! and reports the cheapest split, plus the manipulator's expected loss once informed traders
+//! pull prices back towards the pre-manipulation (fair) level.
+
+use crate::markets::fpmm::{self, FixedProductMarketMaker};
+#[cfg(feature = "polymarket")]
+use crate::oracles::polymarket_oracle::OrderBook;
+
+/// Prices are never pushed beyond these bounds
+const MIN_PRICE: f64 = 0.001;
+const MAX_PRICE: f64 = 0.999;
+
+/// Candidate targets evaluated per search
+const SEARCH_STEPS: usize = 400;
+
+/// Binary (Yes/No) market maker state
+#[derive(Clone, Debug)]
+pub enum MarketMaker {
+    /// Hanson LMSR with liquidity parameter `b` and outstanding shares
+    Lmsr { b: f64, q_yes: f64, q_no: f64 },
+    /// Gnosis-style fixed product pool (Yes price = no / (yes + no)); `fee` is a fraction
+    Cpmm { yes_reserve: f64, no_reserve: f64, fee: f64 },
+    /// Yes order book as (price, size) levels
+    Book { bids: Vec<(f64, f64)>, asks: Vec<(f64, f64)> },
+}
+
+/// Capital spent and tokens received by one push
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Fill {
+    pub capital: f64,
+    pub yes_tokens: f64,
+    pub no_tokens: f64,
+}
+
+fn logit(p: f64) -> f64 {
+    (p / (1.0 - p)).ln()
+}
+
+impl MarketMaker {
+    /// Binary FPMM pool; `yes_index` is the slot treated as Yes
+    pub fn from_fpmm(pool: &FixedProductMarketMaker, yes_index: usize) -> Result<Self, String> {
+        let balances = pool.pool_balances();
+        if balances.len() != 2 || yes_index > 1 {
+            return Err(format!("Expected a binary pool, got {} outcomes", balances.len()));
+        }
+        Ok(MarketMaker::Cpmm {
+            yes_reserve: fpmm::to_f64(balances[yes_index]) / fpmm::ONE as f64,
+            no_reserve: fpmm::to_f64(balances[1 - yes_index]) / fpmm::ONE as f64,
+            fee: fpmm::to_f64(pool.fee()) / fpmm::ONE as f64,
+        })
+    }
+
+    #[cfg(feature = "polymarket")]
+    pub fn from_order_book(book: &OrderBook) -> Self {
+        MarketMaker::Book { bids: book.bid_levels(), asks: book.ask_levels() }
+    }
+
+    /// Current Yes price (book mid-point)
+    pub fn price(&self) -> f64 {
+        match self {
+            MarketMaker::Lmsr { b, q_yes, q_no } => 1.0 / (1.0 + ((q_no - q_yes) / b).exp()),
+            MarketMaker::Cpmm { yes_reserve, no_reserve, .. } => no_reserve / (yes_reserve + no_reserve),
+            MarketMaker::Book { bids, asks } => {
+                let bid = bids.iter().map(|&(p, _)| p).reduce(f64::max);
+                let ask = asks.iter().map(|&(p, _)| p).reduce(f64::min);
+                match (bid, ask) {
+                    (Some(b), Some(a)) => (b + a) / 2.0,
+                    (Some(p), None) | (None, Some(p)) => p,
+                    (None, None) => 0.5,
+                }
+            }
+        }
+    }
+
+    /// Trade until the Yes price reaches `target`, buying Yes to raise it or No to lower it.
+    /// Book pushes sweep every level on the wrong side of the target; posting the resting
+    /// quote that sets the new mid is treated as free.
+    pub fn push_to(&mut self, target: f64) -> Fill {
+        let target = target.clamp(MIN_PRICE, MAX_PRICE);
+        let raise = target > self.price();
+        let mut fill = Fill::default();
+
+        match self {
+            MarketMaker::Lmsr { b, q_yes, q_no } => {
+                let cost = |qy: f64, qn: f64| {
+                    let m = qy.max(qn);
+                    m + *b * (((qy - m) / *b).exp() + ((qn - m) / *b).exp()).ln()
+                };
+                let before = cost(*q_yes, *q_no);
+                if raise {
+                    let next = *q_no + *b * logit(target);
+                    fill.yes_tokens = next - *q_yes;
+                    *q_yes = next;
+                } else {
+                    let next = *q_yes - *b * logit(target);
+                    fill.no_tokens = next - *q_no;
+                    *q_no = next;
+                }
+                fill.capital = cost(*q_yes, *q_no) - before;
+            }
+            MarketMaker::Cpmm { yes_reserve, no_reserve, fee } => {
+                let k = *yes_reserve * *no_reserve;
+                let fee_factor = 1.0 - *fee;
+                if raise {
+                    let no_next = (k * target / (1.0 - target)).sqrt();
+                    let invested = (no_next - *no_reserve).max(0.0);
+                    let yes_next = k / no_next;
+                    fill.yes_tokens = invested + *yes_reserve - yes_next;
+                    fill.capital = invested / fee_factor;
+                    *yes_reserve = yes_next;
+                    *no_reserve = no_next;
+                } else {
+                    let yes_next = (k * (1.0 - target) / target).sqrt();
+                    let invested = (yes_next - *yes_reserve).max(0.0);
+                    let no_next = k / yes_next;
+                    fill.no_tokens = invested + *no_reserve - no_next;
+                    fill.capital = invested / fee_factor;
+                    *yes_reserve = yes_next;
+                    *no_reserve = no_next;
+                }
+            }
+            MarketMaker::Book { bids, asks } => {
+                if raise {
+                    // Lift every ask below the target
+                    asks.retain(|&(p, s)| {
+                        if p < target {
+                            fill.capital += p * s;
+                            fill.yes_tokens += s;
+                            false
+                        } else {
+                            true
+                        }
+                    });
+                    bids.retain(|&(p, _)| p < target);
+                    bids.push((target, 0.0));
+                } else {
+                    // Hit every bid above the target with freshly minted Yes, keeping the No side
+                    bids.retain(|&(p, s)| {
+                        if p > target {
+                            fill.capital += (1.0 - p) * s;
+                            fill.no_tokens += s;
+                            false
+                        } else {
+                            true
+                        }
+                    });
+                    asks.retain(|&(p, _)| p > target);
+                    asks.push((target, 0.0));
+                }
+            }
+        }
+        fill
+    }
+
+    /// Informed traders pull the price `response` of the way back to `fair`.
+    /// Books are assumed to be refilled to their original depth whenever anyone responds.
+    fn respond(&mut self, original: &MarketMaker, fair: f64, response: f64) {
+        if response <= 0.0 {
+            return;
+        }
+        match self {
+            MarketMaker::Book { .. } => *self = original.clone(),
+            _ => {
+                let price = self.price();
+                self.push_to(price + response * (fair - price));
+            }
+        }
+    }
+}
+
+/// What the decision compares
+#[derive(Clone, Debug)]
+pub enum DecisionRule {
+    /// Final spot prices
+    Spot,
+    /// Time-weighted average over the window; `elapsed` periods have already been observed
+    /// at the given averages and the manipulator must hold prices for `remaining` periods
+    Twap { pass_average: f64, reject_average: f64, elapsed: u32, remaining: u32 },
+}
+
+impl DecisionRule {
+    fn periods(&self) -> u32 {
+        match self {
+            DecisionRule::Spot => 1,
+            DecisionRule::Twap { remaining, .. } => (*remaining).max(1),
+        }
+    }
+
+    /// Decision metric if the price is held at `held` for the rest of the window
+    fn metric(&self, held: f64, pass: bool) -> f64 {
+        match self {
+            DecisionRule::Spot => held,
+            DecisionRule::Twap { pass_average, reject_average, elapsed, remaining } => {
+                let average = if pass { *pass_average } else { *reject_average };
+                let (e, r) = (*elapsed as f64, (*remaining).max(1) as f64);
+                (average * e + held * r) / (e + r)
+            }
+        }
+    }
+
+    /// Price that must be held for the metric to equal `metric`
+    fn held_for(&self, metric: f64, pass: bool) -> f64 {
+        match self {
+            DecisionRule::Spot => metric,
+            DecisionRule::Twap { pass_average, reject_average, elapsed, remaining } => {
+                let average = if pass { *pass_average } else { *reject_average };
+                let (e, r) = (*elapsed as f64, (*remaining).max(1) as f64);
+                (metric * (e + r) - average * e) / r
+            }
+        }
+    }
+}
+
+/// Cost of holding one market at a target for the rest of the window
+#[derive(Clone, Debug, Default)]
+pub struct MarketPush {
+    pub start_price: f64,
+    pub target_price: f64,
+    /// Capital deployed across every re-push
+    pub capital: f64,
+    pub yes_tokens: f64,
+    pub no_tokens: f64,
+    /// Capital minus the fair value of tokens held
+    pub expected_loss: f64,
+}
+
+#[derive(Clone, Debug)]
+pub struct ManipulationCost {
+    /// Decision the markets currently imply (true = adopt)
+    pub current_adopt: bool,
+    pub pass: MarketPush,
+    pub reject: MarketPush,
+    pub capital: f64,
+    pub expected_loss: f64,
+}
+
+pub struct ManipulationCostCalculator {
+    pub rule: DecisionRule,
+    /// Adopt when pass metric > reject metric × (1 + threshold)
+    pub threshold: f64,
+    /// Fraction of each price deviation informed traders arbitrage away per period
+    pub informed_response: f64,
+}
+
+impl ManipulationCostCalculator {
+    pub fn new(rule: DecisionRule) -> Self {
+        Self { rule, threshold: 0.0, informed_response: 0.5 }
+    }
+
+    pub fn with_threshold(mut self, threshold: f64) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    pub fn with_informed_response(mut self, response: f64) -> Self {
+        self.informed_response = response.clamp(0.0, 1.0);
+        self
+    }
+
+    fn adopts(&self, pass_metric: f64, reject_metric: f64) -> bool {
+        pass_metric > reject_metric * (1.0 + self.threshold)
+    }
+
+    /// Push `market` to `target` and hold it there, re-pushing after each informed response
+    pub fn hold_cost(&self, market: &MarketMaker, target: f64) -> MarketPush {
+        let fair = market.price();
+        let mut state = market.clone();
+        let mut push = MarketPush { start_price: fair, target_price: target, ..MarketPush::default() };
+        let periods = self.rule.periods();
+        for period in 0..periods {
+            let fill = state.push_to(target);
+            push.capital += fill.capital;
+            push.yes_tokens += fill.yes_tokens;
+            push.no_tokens += fill.no_tokens;
+            if period + 1 < periods {
+                state.respond(market, fair, self.informed_response);
+            }
+        }
+        push.expected_loss = push.capital - push.yes_tokens * fair - push.no_tokens * (1.0 - fair);
+        push
+    }
+
+    /// Cheapest Pass/Reject targets that flip the decision (None if no reachable prices flip it)
+    pub fn cost_to_flip(&self, pass: &MarketMaker, reject: &MarketMaker) -> Option<ManipulationCost> {
+        let (p0, r0) = (pass.price(), reject.price());
+        let current_adopt = self.adopts(self.rule.metric(p0, true), self.rule.metric(r0, false));
+        let scale = 1.0 + self.threshold;
+        let margin = 1e-6;
+
+        // Walk the reject target away from its current price in the helping direction;
+        // the pass market takes up whatever is still needed
+        let reject_extreme = if current_adopt { MAX_PRICE } else { MIN_PRICE };
+        let mut best: Option<ManipulationCost> = None;
+        for step in 0..=SEARCH_STEPS {
+            let r = r0 + (reject_extreme - r0) * step as f64 / SEARCH_STEPS as f64;
+            let boundary = self.rule.metric(r, false) * scale;
+            let needed = self.rule.held_for(if current_adopt { boundary } else { boundary + margin }, true);
+            let p = if current_adopt { needed.min(p0) } else { needed.max(p0) };
+            if !(MIN_PRICE..=MAX_PRICE).contains(&p) {
+                continue;
+            }
+            if self.adopts(self.rule.metric(p, true), self.rule.metric(r, false)) == current_adopt {
+                continue;
+            }
+
+            let pass_push = self.hold_cost(pass, p);
+            let reject_push = self.hold_cost(reject, r);
+            let capital = pass_push.capital + reject_push.capital;
+            if best.as_ref().is_none_or(|b| capital < b.capital) {
+                best = Some(ManipulationCost {
+                    current_adopt,
+                    capital,
+                    expected_loss: pass_push.expected_loss + reject_push.expected_loss,
+                    pass: pass_push,
+                    reject: reject_push,
+                });
+            }
+        }
+        best
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lmsr(b: f64, price: f64) -> MarketMaker {
+        MarketMaker::Lmsr { b, q_yes: b * logit(price), q_no: 0.0 }
+    }
+
+    fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+        assert!((actual - expected).abs() <= tolerance, "expected {} ± {}, got {}", expected, tolerance, actual);
+    }
+
+    #[test]
+    fn lmsr_cost_to_move_half_to_sixty() {
+        let mut market = MarketMaker::Lmsr { b: 100.0, q_yes: 0.0, q_no: 0.0 };
+        let fill = market.push_to(0.6);
+        // Yes shares: b ln(0.6 / 0.4) = 40.5465; cost: b ln(0.5 / 0.4) = 22.3144
+        assert_close(fill.yes_tokens, 40.546_510_8, 1e-6);
+        assert_close(fill.capital, 22.314_355_1, 1e-6);
+        assert_eq!(fill.no_tokens, 0.0);
+        assert_close(market.price(), 0.6, 1e-12);
+
+        // Back down by buying No: b ln(0.6 / 0.5) = 18.2322
+        let back = market.push_to(0.5);
+        assert_close(back.no_tokens, 40.546_510_8, 1e-6);
+        assert_close(back.capital, 18.232_155_7, 1e-6);
+    }
+
+    #[test]
+    fn cpmm_reserve_math() {
+        let mut market = MarketMaker::Cpmm { yes_reserve: 100.0, no_reserve: 100.0, fee: 0.02 };
+        assert_close(market.price(), 0.5, 1e-12);
+        let fill = market.push_to(0.6);
+        // k = 10 000: no' = √(k × 0.6 / 0.4) = 122.4745, yes' = k / no' = 81.6497
+        let MarketMaker::Cpmm { yes_reserve, no_reserve, .. } = market else { unreachable!() };
+        assert_close(no_reserve, 122.474_487_1, 1e-6);
+        assert_close(yes_reserve, 81.649_658_1, 1e-6);
+        // 22.4745 invested, grossed up for the 2% fee; Yes out = invested + 100 − 81.6497
+        assert_close(fill.capital, 22.474_487_1 / 0.98, 1e-6);
+        assert_close(fill.yes_tokens, 40.824_829_0, 1e-6);
+        assert_close(market.price(), 0.6, 1e-12);
+
+        let mut market = MarketMaker::Cpmm { yes_reserve: 100.0, no_reserve: 100.0, fee: 0.0 };
+        let fill = market.push_to(0.4);
+        assert_close(fill.capital, 22.474_487_1, 1e-6);
+        assert_close(fill.no_tokens, 40.824_829_0, 1e-6);
+        assert_close(market.price(), 0.4, 1e-12);
+    }
+
+    #[test]
+    fn book_push_walks_several_levels() {
+        let book = MarketMaker::Book {
+            bids: vec![(0.48, 10.0), (0.47, 20.0)],
+            asks: vec![(0.52, 10.0), (0.55, 20.0), (0.60, 30.0), (0.65, 40.0)],
+        };
+        assert_close(book.price(), 0.50, 1e-12);
+
+        let mut up = book.clone();
+        let fill = up.push_to(0.62);
+        // 0.52 × 10 + 0.55 × 20 + 0.60 × 30
+        assert_close(fill.capital, 34.2, 1e-9);
+        assert_close(fill.yes_tokens, 60.0, 1e-12);
+        // New bid at the target; the 0.65 ask is untouched
+        assert_close(up.price(), (0.62 + 0.65) / 2.0, 1e-12);
+
+        let mut down = book.clone();
+        let fill = down.push_to(0.46);
+        // No side of both bids: 0.52 × 10 + 0.53 × 20
+        assert_close(fill.capital, 15.8, 1e-9);
+        assert_close(fill.no_tokens, 30.0, 1e-12);
+        assert_close(down.price(), 0.46, 1e-12);
+    }
+
+    #[test]
+    fn twap_target_must_be_held_for_remaining_windows() {
+        // 6 of 10 windows observed: pass averaged 0.55, reject 0.50
+        let rule = DecisionRule::Twap { pass_average: 0.55, reject_average: 0.50, elapsed: 6, remaining: 4 };
+        assert_close(rule.metric(0.5, true), 0.53, 1e-12);
+        // Pass TWAP drops to 0.5 only if held at (0.5 × 10 − 0.55 × 6) / 4 = 0.425
+        assert_close(rule.held_for(0.5, true), 0.425, 1e-12);
+
+        // Full informed response undoes each push, so all 4 windows are bought again
+        let calculator = ManipulationCostCalculator::new(rule.clone()).with_informed_response(1.0);
+        let market = MarketMaker::Lmsr { b: 100.0, q_yes: 0.0, q_no: 0.0 };
+        let held = calculator.hold_cost(&market, 0.6);
+        assert_close(held.capital, 4.0 * 22.314_355_1, 1e-5);
+        assert_close(held.yes_tokens, 4.0 * 40.546_510_8, 1e-5);
+        // Tokens are worth 0.5 each at the fair price
+        assert_close(held.expected_loss, 4.0 * (22.314_355_1 - 0.5 * 40.546_510_8), 1e-5);
+        // No response: one push, then holding is free
+        let once = ManipulationCostCalculator::new(rule.clone()).with_informed_response(0.0).hold_cost(&market, 0.6);
+        assert_close(once.capital, 22.314_355_1, 1e-6);
+
+        // Flipping to reject through a cheap pass market needs pass held near 0.425, costing b ln(0.5 / 0.425)
+        let deep_reject = lmsr(1_000_000.0, 0.5);
+        let flip = ManipulationCostCalculator::new(rule)
+            .with_informed_response(0.0)
+            .cost_to_flip(&market, &deep_reject)
+            .unwrap();
+        assert!(flip.current_adopt);
+        assert_close(flip.pass.target_price, 0.425, 1e-3);
+        assert_close(flip.capital, 100.0 * (0.5f64 / 0.425).ln(), 0.5);
+    }
+
+    #[test]
+    fn cost_to_flip_picks_the_cheaper_side() {
+        let calculator = ManipulationCostCalculator::new(DecisionRule::Spot).with_informed_response(0.0);
+
+        // Adopting (0.6 vs 0.5): dropping the thin pass market to 0.5 costs 100 ln 1.2 = 18.23,
+        // lifting the deep reject market to 0.6 would cost 1 000 ln 1.25 = 223.1
+        let flip = calculator.cost_to_flip(&lmsr(100.0, 0.6), &lmsr(1_000.0, 0.5)).unwrap();
+        assert!(flip.current_adopt);
+        assert_close(flip.pass.target_price, 0.5, 1e-6);
+        assert_close(flip.reject.capital, 0.0, 1e-9);
+        assert_close(flip.capital, 100.0 * 1.2f64.ln(), 1e-4);
+
+        // Rejecting (0.4 vs 0.5): dropping the thin reject market below 0.4 costs about 100 ln 1.25 = 22.31,
+        // lifting the deep pass market to 0.5 would cost 1 000 ln 1.2 = 182.3
+        let flip = calculator.cost_to_flip(&lmsr(1_000.0, 0.4), &lmsr(100.0, 0.5)).unwrap();
+        assert!(!flip.current_adopt);
+        assert!(flip.reject.target_price < 0.4);
+        assert!(flip.pass.capital < 1.0, "{}", flip.pass.capital);
+        assert_close(flip.capital, 100.0 * 1.25f64.ln(), 0.5);
+    }
+}
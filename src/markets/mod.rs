@@ -0,0 +1,8 @@
+//! Markets — Market Makers and Market Surveillance
+//! Ultramasterful engines behind every conditional market
+
+pub mod arbitrage;
+pub mod fpmm;
+pub mod manipulation;
+pub mod manipulation_cost;
+pub mod prediction_market;
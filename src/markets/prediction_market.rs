@@ -0,0 +1,145 @@
+//! Prediction Market — Internal Belief Aggregation for Futarchy
+//! Ultramasterful core simulation over binary FPMM pools (outcome 0 = Yes, 1 = No)
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::SystemTime;
+
+use crate::markets::fpmm::{self, FixedProductMarketMaker};
+use crate::mercy::{GateStage, MercyGate};
+use crate::truth::{SharedTruth, Verdict};
+
+/// Fee charged by internal pools (2%)
+const INTERNAL_FEE: u128 = fpmm::ONE / 50;
+
+/// Liquidity provider slot used for the house seed liquidity
+const HOUSE_LP: fpmm::LpId = 0;
+
+pub const YES: usize = 0;
+pub const NO: usize = 1;
+
+/// One fill against an internal pool
+#[derive(Clone, Debug)]
+pub struct InternalTrade {
+    pub trader: String,
+    pub timestamp: SystemTime,
+    pub outcome: usize,
+    pub collateral: f64,
+    pub tokens: f64,
+}
+
+/// One internal binary market
+#[derive(Clone, Debug)]
+pub struct InternalMarket {
+    pub pool: FixedProductMarketMaker,
+    pub opened_at: SystemTime,
+    pub last_trade_at: SystemTime,
+    pub trades: Vec<InternalTrade>,
+    /// Winning outcome once resolved
+    pub resolved: Option<usize>,
+}
+
+impl InternalMarket {
+    pub fn yes_price(&self) -> f64 {
+        self.pool.prices()[YES]
+    }
+
+    /// Outcome tokens held per trader: `[YES, NO]`
+    pub fn positions(&self) -> HashMap<String, [f64; 2]> {
+        let mut positions: HashMap<String, [f64; 2]> = HashMap::new();
+        for trade in &self.trades {
+            positions.entry(trade.trader.clone()).or_default()[trade.outcome] += trade.tokens;
+        }
+        positions
+    }
+}
+
+pub struct PredictionMarket {
+    truth: SharedTruth,
+    gate: Option<Arc<MercyGate>>,
+    markets: HashMap<String, InternalMarket>,
+}
+
+impl PredictionMarket {
+    pub fn new(truth: SharedTruth) -> Self {
+        PredictionMarket {
+            truth,
+            gate: None,
+            markets: HashMap::new(),
+        }
+    }
+
+    /// Admit market creation and trades through a mercy gate
+    pub fn with_mercy_gate(mut self, gate: Arc<MercyGate>) -> Self {
+        self.gate = Some(gate);
+        self
+    }
+
+    /// Open a binary market on `event` seeded with `liquidity` collateral at even odds
+    pub fn open_market(&mut self, event: &str, liquidity: f64) -> Result<(), String> {
+        if self.markets.contains_key(event) {
+            return Err(format!("Market already open: {}", event));
+        }
+        if let Some(gate) = &self.gate {
+            gate.admit(GateStage::MarketCreation, "house", event)?;
+        }
+        let mut pool = FixedProductMarketMaker::new(2, INTERNAL_FEE.into())?;
+        pool.add_funding(HOUSE_LP, fpmm::from_f64(liquidity), &[])?;
+        let now = SystemTime::now();
+        self.markets.insert(event.to_string(), InternalMarket { pool, opened_at: now, last_trade_at: now, trades: Vec::new(), resolved: None });
+        Ok(())
+    }
+
+    /// `trader` buys `outcome` (YES/NO) with `amount` collateral; returns outcome tokens received
+    pub fn trade(&mut self, event: &str, trader: &str, outcome: usize, amount: f64) -> Result<f64, String> {
+        let market = self.markets.get_mut(event)
+            .ok_or_else(|| format!("No internal market for: {}", event))?;
+        if market.resolved.is_some() {
+            return Err(format!("Market resolved: {}", event));
+        }
+        if let Some(gate) = &self.gate {
+            let side = if outcome == YES { "YES" } else { "NO" };
+            gate.admit(GateStage::TradeAdmission, trader, &format!("{} buys {} with {:.2} on {}", trader, side, amount, event))?;
+        }
+        let tokens = market.pool.buy(fpmm::from_f64(amount), outcome, 0.into())?;
+        let tokens = fpmm::to_f64(tokens) / fpmm::ONE as f64;
+        let now = SystemTime::now();
+        market.last_trade_at = now;
+        market.trades.push(InternalTrade { trader: trader.to_string(), timestamp: now, outcome, collateral: amount, tokens });
+        Ok(tokens)
+    }
+
+    /// Resolve `event` to `outcome`; returns each winning trader's payout (1 collateral per token)
+    pub fn resolve(&mut self, event: &str, outcome: usize) -> Result<HashMap<String, f64>, String> {
+        if outcome > NO {
+            return Err(format!("Invalid outcome {} for binary market", outcome));
+        }
+        let market = self.markets.get_mut(event)
+            .ok_or_else(|| format!("No internal market for: {}", event))?;
+        if market.resolved.is_some() {
+            return Err(format!("Market already resolved: {}", event));
+        }
+        market.resolved = Some(outcome);
+        Ok(market.positions().into_iter()
+            .filter(|(_, held)| held[outcome] > 0.0)
+            .map(|(trader, held)| (trader, held[outcome]))
+            .collect())
+    }
+
+    pub fn market(&self, event: &str) -> Option<&InternalMarket> {
+        self.markets.get(event)
+    }
+
+    /// Current Yes probability for `event`
+    pub fn probability(&self, event: &str) -> Option<f64> {
+        self.markets.get(event).map(InternalMarket::yes_price)
+    }
+
+    pub fn aggregate_belief(&self, event: &str) -> Verdict {
+        // Simulate market probability + truth check
+        match self.probability(event) {
+            Some(p) => self.truth.evaluate(&format!("{} (internal market: {:.3})", event, p)),
+            None => self.truth.evaluate(event),
+        }
+    }
+}
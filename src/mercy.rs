@@ -0,0 +1,628 @@
+//! Mercy Gate — Declarative Valence Policy with Mercy Quanta Scoring
+//! Ultramasterful admission control: ideas, proposals, markets and trades pass one auditable gate
+//!
+//! A `MercyPolicy` combines the truth engine's SoulScan valence with scores on the 9 Mercy
+//! Quanta. Every check at a `GateStage` produces a `MercyDecision` that is kept in the gate's
+//! log; threshold rejections near the line (and appealable hard rules) can be overturned on
+//! appeal, and the appeal is recorded on the same decision. An overturned appeal admits the
+//! next identical check, so the actor simply re-runs the action.
+
+use std::collections::{HashMap, VecDeque};
+use std::fs;
+use std::path::Path;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::SystemTime;
+
+use serde::{Deserialize, Serialize};
+
+use crate::truth::{self, SharedTruth, Verdict};
+
+/// Decisions kept in a gate's log before the oldest rotate out
+pub const DEFAULT_LOG_CAPACITY: usize = 10_000;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum MercyQuantum {
+    Love,
+    Joy,
+    Peace,
+    Patience,
+    Kindness,
+    Goodness,
+    Faithfulness,
+    Gentleness,
+    SelfControl,
+}
+
+impl MercyQuantum {
+    pub const ALL: [MercyQuantum; 9] = [
+        MercyQuantum::Love,
+        MercyQuantum::Joy,
+        MercyQuantum::Peace,
+        MercyQuantum::Patience,
+        MercyQuantum::Kindness,
+        MercyQuantum::Goodness,
+        MercyQuantum::Faithfulness,
+        MercyQuantum::Gentleness,
+        MercyQuantum::SelfControl,
+    ];
+}
+
+/// Score in [0, 1] per quantum
+pub type QuantaScores = HashMap<MercyQuantum, f64>;
+
+/// Where in the futarchy pipeline a check happens
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum GateStage {
+    /// Arena ideas, replies and edits
+    IdeaSubmission,
+    ProposalSubmission,
+    MarketCreation,
+    TradeAdmission,
+}
+
+/// Scores content on the 9 Mercy Quanta
+pub trait QuantaScorer: Send + Sync {
+    fn score(&self, content: &str) -> QuantaScores;
+}
+
+/// Deterministic keyword scorer: neutral content sits at `baseline`. Terms match whole words
+/// unless marked as stems with `*` (see `truth::contains_term`)
+pub struct LexiconQuanta {
+    baseline: f64,
+    lexicon: HashMap<MercyQuantum, (Vec<&'static str>, Vec<&'static str>)>,
+}
+
+impl LexiconQuanta {
+    pub fn new() -> Self {
+        use MercyQuantum::*;
+        let lexicon = HashMap::from([
+            (Love, (vec!["love*", "care", "caring", "compassion*", "communit*"], vec!["hate*", "hatred", "contempt*"])),
+            (Joy, (vec!["joy*", "celebrat*", "delight*", "thriv*"], vec!["misery", "despair*"])),
+            (Peace, (vec!["peace*", "reconcil*", "harmon*"], vec!["war", "attack*", "violen*", "destroy*"])),
+            (Patience, (vec!["patien*", "gradual*", "long-term", "phased"], vec!["immediately", "rush*", "panic*"])),
+            (Kindness, (vec!["kind", "kindness", "help*", "support*"], vec!["cruel*", "punish*", "humiliat*"])),
+            (Goodness, (vec!["fair", "fairly", "fairness", "honest*", "transparen*", "public good"], vec!["fraud*", "scam*", "corrupt*", "brib*"])),
+            (Faithfulness, (vec!["commit*", "promise*", "accountab*"], vec!["betray*", "renege*", "rug"])),
+            (Gentleness, (vec!["gentle", "gently", "respect*", "calm*"], vec!["aggressi*", "threat*", "harass*"])),
+            (SelfControl, (vec!["limit", "limited", "cap", "capped", "moderat*", "budget*"], vec!["unlimited", "all-in", "leverage*", "pump*"])),
+        ]);
+        Self { baseline: 0.8, lexicon }
+    }
+}
+
+impl Default for LexiconQuanta {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl QuantaScorer for LexiconQuanta {
+    fn score(&self, content: &str) -> QuantaScores {
+        let words = truth::words(content);
+        self.lexicon.iter()
+            .map(|(&quantum, (positive, negative))| {
+                let up = positive.iter().filter(|t| truth::contains_term(&words, t)).count() as f64;
+                let down = negative.iter().filter(|t| truth::contains_term(&words, t)).count() as f64;
+                (quantum, (self.baseline + 0.1 * up - 0.3 * down).clamp(0.0, 1.0))
+            })
+            .collect()
+    }
+}
+
+/// Rule that rejects regardless of the weighted score
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum HardRejectRule {
+    /// Truth engine did not verify the content
+    NotVerified,
+    /// SoulScan valence below the bound (MercyZero: negative valence auto-rejects)
+    ValenceBelow(f64),
+    QuantumBelow { quantum: MercyQuantum, min: f64 },
+    BlockedActor(String),
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct HardReject {
+    pub rule: HardRejectRule,
+    /// Whether an appeal may overturn a rejection by this rule
+    #[serde(default)]
+    pub appealable: bool,
+}
+
+/// Declarative mercy policy (loadable from JSON)
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MercyPolicy {
+    /// Minimum SoulScan valence
+    pub min_valence: f64,
+    /// Relative weight per quantum; missing quanta weigh 0
+    pub weights: HashMap<MercyQuantum, f64>,
+    /// Minimum weighted quanta score
+    pub weighted_threshold: f64,
+    /// Per-stage overrides of `weighted_threshold`
+    #[serde(default)]
+    pub stage_thresholds: HashMap<GateStage, f64>,
+    #[serde(default)]
+    pub hard_rejects: Vec<HardReject>,
+    /// Threshold rejections within this margin of the line may be appealed
+    pub appeal_margin: f64,
+}
+
+impl Default for MercyPolicy {
+    /// FUTARCHY_MECHANICS.md: SoulScan valence ≥ 0.999, equal quanta weights, MercyZero gate
+    fn default() -> Self {
+        Self {
+            min_valence: 0.999,
+            weights: MercyQuantum::ALL.iter().map(|&q| (q, 1.0)).collect(),
+            weighted_threshold: 0.6,
+            stage_thresholds: HashMap::new(),
+            hard_rejects: vec![
+                HardReject { rule: HardRejectRule::NotVerified, appealable: false },
+                HardReject { rule: HardRejectRule::ValenceBelow(0.5), appealable: false },
+            ],
+            appeal_margin: 0.1,
+        }
+    }
+}
+
+/// Partial policy layered over a base policy (e.g. per arena mode)
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct PolicyOverride {
+    #[serde(default)]
+    pub min_valence: Option<f64>,
+    /// Replaces the weight of each listed quantum
+    #[serde(default)]
+    pub weights: HashMap<MercyQuantum, f64>,
+    #[serde(default)]
+    pub weighted_threshold: Option<f64>,
+    /// Added to the base hard rules
+    #[serde(default)]
+    pub extra_hard_rejects: Vec<HardReject>,
+}
+
+impl MercyPolicy {
+    /// Base policy with `overrides` applied
+    pub fn overridden(&self, overrides: &PolicyOverride) -> Self {
+        let mut policy = self.clone();
+        if let Some(min_valence) = overrides.min_valence {
+            policy.min_valence = min_valence;
+        }
+        policy.weights.extend(overrides.weights.iter().map(|(&q, &w)| (q, w)));
+        if let Some(threshold) = overrides.weighted_threshold {
+            policy.weighted_threshold = threshold;
+            policy.stage_thresholds.clear();
+        }
+        policy.hard_rejects.extend(overrides.extra_hard_rejects.iter().cloned());
+        policy
+    }
+
+    pub fn from_json(raw: &str) -> Result<Self, String> {
+        serde_json::from_str(raw).map_err(|e| format!("Mercy policy error: {:?}", e))
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let raw = fs::read_to_string(path).map_err(|e| format!("Read error {}: {:?}", path.display(), e))?;
+        Self::from_json(&raw)
+    }
+
+    pub fn threshold(&self, stage: GateStage) -> f64 {
+        self.stage_thresholds.get(&stage).copied().unwrap_or(self.weighted_threshold)
+    }
+
+    /// Weight-averaged quanta score
+    pub fn weighted_score(&self, quanta: &QuantaScores) -> f64 {
+        let total: f64 = self.weights.values().sum();
+        if total <= 0.0 {
+            return 0.0;
+        }
+        self.weights.iter()
+            .map(|(q, w)| w * quanta.get(q).copied().unwrap_or(0.0))
+            .sum::<f64>() / total
+    }
+}
+
+/// Council review of a rejected decision
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AppealRecord {
+    pub reviewer: String,
+    pub justification: String,
+    pub overturned: bool,
+    pub decided_at: SystemTime,
+}
+
+/// Auditable outcome of one gate check
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MercyDecision {
+    pub id: u64,
+    pub stage: GateStage,
+    pub actor: String,
+    pub content: String,
+    pub verified: bool,
+    pub valence: f64,
+    pub truth_reasons: Vec<String>,
+    pub quanta: QuantaScores,
+    pub weighted_score: f64,
+    pub threshold: f64,
+    /// Hard rules that fired
+    pub hard_rejections: Vec<String>,
+    pub admitted: bool,
+    pub appealable: bool,
+    pub decided_at: SystemTime,
+    pub appeal: Option<AppealRecord>,
+    /// Earlier decision whose overturned appeal admitted this one
+    #[serde(default)]
+    pub admitted_on_appeal: Option<u64>,
+}
+
+impl MercyDecision {
+    /// Admitted originally or on appeal
+    pub fn is_admitted(&self) -> bool {
+        self.admitted || self.appeal.as_ref().is_some_and(|a| a.overturned)
+    }
+
+    /// One-line reason for rejection errors
+    pub fn summary(&self) -> String {
+        if self.is_admitted() {
+            return match self.admitted_on_appeal {
+                Some(appealed) => format!("Mercy decision #{}: admitted on appeal of #{}", self.id, appealed),
+                None => format!("Mercy decision #{}: admitted ({:.3} ≥ {:.3})", self.id, self.weighted_score, self.threshold),
+            };
+        }
+        let mut reasons = self.hard_rejections.clone();
+        if self.weighted_score < self.threshold {
+            reasons.push(format!("quanta score {:.3} < {:.3}", self.weighted_score, self.threshold));
+        }
+        format!("Mercy decision #{}: rejected ({})", self.id, reasons.join("; "))
+    }
+}
+
+/// What an overturned appeal re-admits: the same stage, actor and content
+type AppealKey = (GateStage, String, String);
+
+/// Bounded decision log; ids keep counting as the oldest decisions rotate out
+struct DecisionLog {
+    decisions: VecDeque<MercyDecision>,
+    next_id: u64,
+    capacity: usize,
+    /// Unused overturned appeals, each admitting one re-run
+    overturned: HashMap<AppealKey, u64>,
+}
+
+impl DecisionLog {
+    fn new(capacity: usize) -> Self {
+        Self { decisions: VecDeque::new(), next_id: 0, capacity: capacity.max(1), overturned: HashMap::new() }
+    }
+
+    fn index(&self, id: u64) -> Option<usize> {
+        let first = self.decisions.front()?.id;
+        usize::try_from(id.checked_sub(first)?).ok().filter(|&i| i < self.decisions.len())
+    }
+
+    fn push(&mut self, decision: MercyDecision) {
+        self.next_id = decision.id + 1;
+        self.decisions.push_back(decision);
+        while self.decisions.len() > self.capacity {
+            self.decisions.pop_front();
+        }
+        // Grants for decisions that rotated out can no longer be traced, so they lapse
+        if let Some(first) = self.decisions.front().map(|d| d.id) {
+            self.overturned.retain(|_, id| *id >= first);
+        }
+    }
+}
+
+/// Uniform mercy gate shared by proposal submission, market creation and trade admission
+pub struct MercyGate {
+    policy: MercyPolicy,
+    truth: SharedTruth,
+    scorer: Arc<dyn QuantaScorer>,
+    log: Arc<Mutex<DecisionLog>>,
+}
+
+impl MercyGate {
+    pub fn new(policy: MercyPolicy, truth: SharedTruth) -> Self {
+        Self {
+            policy,
+            truth,
+            scorer: Arc::new(LexiconQuanta::new()),
+            log: Arc::new(Mutex::new(DecisionLog::new(DEFAULT_LOG_CAPACITY))),
+        }
+    }
+
+    /// Gate applying this policy with `overrides`; it shares this gate's truth engine, scorer
+    /// and decision log, so ids, appeals and exports stay in one place
+    pub fn overridden(&self, overrides: &PolicyOverride) -> Self {
+        Self {
+            policy: self.policy.overridden(overrides),
+            truth: self.truth.clone(),
+            scorer: self.scorer.clone(),
+            log: self.log.clone(),
+        }
+    }
+
+    /// Log entries are only ever pushed or updated in place, so a panic mid-update leaves it readable
+    fn log(&self) -> MutexGuard<'_, DecisionLog> {
+        self.log.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Keep at most `capacity` decisions; older ones rotate out of lookups and exports (the
+    /// capacity applies to every gate sharing the log)
+    pub fn with_log_capacity(self, capacity: usize) -> Self {
+        self.log().capacity = capacity.max(1);
+        self
+    }
+
+    pub fn with_scorer(mut self, scorer: Box<dyn QuantaScorer>) -> Self {
+        self.scorer = Arc::from(scorer);
+        self
+    }
+
+    pub fn policy(&self) -> &MercyPolicy {
+        &self.policy
+    }
+
+    pub fn truth(&self) -> &SharedTruth {
+        &self.truth
+    }
+
+    fn fired(&self, rule: &HardRejectRule, actor: &str, verdict: &Verdict, quanta: &QuantaScores) -> Option<String> {
+        match rule {
+            HardRejectRule::NotVerified if !verdict.verified => Some(format!("not verified: {}", verdict.reasons.join(", "))),
+            HardRejectRule::ValenceBelow(min) if verdict.valence < *min => Some(format!("valence {:.3} < {:.3}", verdict.valence, min)),
+            HardRejectRule::QuantumBelow { quantum, min } => {
+                let score = quanta.get(quantum).copied().unwrap_or(0.0);
+                (score < *min).then(|| format!("{:?} {:.3} < {:.3}", quantum, score, min))
+            }
+            HardRejectRule::BlockedActor(blocked) if blocked == actor => Some(format!("actor {} is blocked", actor)),
+            _ => None,
+        }
+    }
+
+    /// Evaluate `content` submitted by `actor` at `stage`, log and return the decision. A
+    /// rejection is admitted anyway if an unused overturned appeal covers the same check
+    pub fn check(&self, stage: GateStage, actor: &str, content: &str) -> MercyDecision {
+        let verdict = self.truth.evaluate(content);
+        let quanta = self.scorer.score(content);
+        let weighted_score = self.policy.weighted_score(&quanta);
+        let threshold = self.policy.threshold(stage);
+
+        let mut hard_rejections = Vec::new();
+        let mut hard_appealable = true;
+        for hard in &self.policy.hard_rejects {
+            if let Some(reason) = self.fired(&hard.rule, actor, &verdict, &quanta) {
+                hard_rejections.push(reason);
+                hard_appealable &= hard.appealable;
+            }
+        }
+        if verdict.valence < self.policy.min_valence {
+            hard_rejections.push(format!("SoulScan valence {:.3} < {:.3}", verdict.valence, self.policy.min_valence));
+            hard_appealable = false;
+        }
+
+        let passes_threshold = weighted_score >= threshold;
+        let mut admitted = hard_rejections.is_empty() && passes_threshold;
+
+        let mut log = self.log();
+        let admitted_on_appeal = if admitted {
+            None
+        } else {
+            log.overturned.remove(&(stage, actor.to_string(), content.to_string()))
+        };
+        admitted |= admitted_on_appeal.is_some();
+        let appealable = !admitted
+            && hard_appealable
+            && (passes_threshold || threshold - weighted_score <= self.policy.appeal_margin);
+
+        let decision = MercyDecision {
+            id: log.next_id,
+            stage,
+            actor: actor.to_string(),
+            content: content.to_string(),
+            verified: verdict.verified,
+            valence: verdict.valence,
+            truth_reasons: verdict.reasons,
+            quanta,
+            weighted_score,
+            threshold,
+            hard_rejections,
+            admitted,
+            appealable,
+            decided_at: SystemTime::now(),
+            appeal: None,
+            admitted_on_appeal,
+        };
+        if !admitted {
+            log::info!("{}", decision.summary());
+        }
+        log.push(decision.clone());
+        decision
+    }
+
+    /// `check`, as a `Result` for call sites that stop on rejection
+    pub fn admit(&self, stage: GateStage, actor: &str, content: &str) -> Result<MercyDecision, String> {
+        let decision = self.check(stage, actor, content);
+        if decision.admitted {
+            Ok(decision)
+        } else {
+            Err(format!("Mercy Shield: {}", decision.summary()))
+        }
+    }
+
+    /// Record a council review of a rejected, appealable decision; if overturned, the actor's
+    /// next identical check at the same stage is admitted
+    pub fn appeal(&self, decision_id: u64, reviewer: &str, justification: &str, overturn: bool) -> Result<MercyDecision, String> {
+        let mut log = self.log();
+        let index = log.index(decision_id)
+            .ok_or_else(|| format!("No mercy decision #{}", decision_id))?;
+        let decision = &mut log.decisions[index];
+        if decision.admitted {
+            return Err(format!("Mercy decision #{} was admitted; nothing to appeal", decision_id));
+        }
+        if !decision.appealable {
+            return Err(format!("Mercy decision #{} is not appealable", decision_id));
+        }
+        if decision.appeal.is_some() {
+            return Err(format!("Mercy decision #{} has already been appealed", decision_id));
+        }
+        decision.appeal = Some(AppealRecord {
+            reviewer: reviewer.to_string(),
+            justification: justification.to_string(),
+            overturned: overturn,
+            decided_at: SystemTime::now(),
+        });
+        let decision = decision.clone();
+        if overturn {
+            log.overturned.insert((decision.stage, decision.actor.clone(), decision.content.clone()), decision.id);
+        }
+        Ok(decision)
+    }
+
+    /// Look up a decision still held in the log
+    pub fn decision(&self, id: u64) -> Option<MercyDecision> {
+        let log = self.log();
+        log.index(id).map(|i| log.decisions[i].clone())
+    }
+
+    /// Whether `id` was admitted, originally or on appeal
+    pub fn is_admitted(&self, id: u64) -> bool {
+        self.decision(id).is_some_and(|d| d.is_admitted())
+    }
+
+    /// Decisions still held in the log, oldest first
+    pub fn decisions(&self) -> Vec<MercyDecision> {
+        self.log().decisions.iter().cloned().collect()
+    }
+
+    /// Write the retained decision log as JSON
+    pub fn export_log(&self, path: &Path) -> Result<(), String> {
+        let raw = serde_json::to_string_pretty(&self.log().decisions)
+            .map_err(|e| format!("Serialize error: {:?}", e))?;
+        fs::write(path, raw).map_err(|e| format!("Write error {}: {:?}", path.display(), e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::markets::prediction_market::PredictionMarket;
+    use crate::truth::LocalTruth;
+
+    /// Neutral content scores 0.8: rejected, but within the appeal margin
+    fn strict_gate() -> MercyGate {
+        let policy = MercyPolicy { weighted_threshold: 0.85, ..MercyPolicy::default() };
+        MercyGate::new(policy, LocalTruth::shared())
+    }
+
+    #[test]
+    fn lexicon_matches_whole_words() {
+        let scorer = LexiconQuanta::new();
+        let lookalikes = scorer.score("An award for drug research, capital from mankind");
+        for quantum in MercyQuantum::ALL {
+            assert_eq!(lookalikes[&quantum], 0.8, "{:?}", quantum);
+        }
+        let hits = scorer.score("War, a rug, a cap and kindness");
+        assert!(hits[&MercyQuantum::Peace] < 0.8);
+        assert!(hits[&MercyQuantum::Faithfulness] < 0.8);
+        assert!(hits[&MercyQuantum::SelfControl] > 0.8);
+        assert!(hits[&MercyQuantum::Kindness] > 0.8);
+    }
+
+    #[test]
+    fn overturned_appeal_admits_the_next_identical_check_once() {
+        let gate = strict_gate();
+        let rejected = gate.check(GateStage::IdeaSubmission, "ada", "Fund the library");
+        assert!(!rejected.admitted && rejected.appealable);
+        assert!(gate.admit(GateStage::IdeaSubmission, "ada", "Fund the library").is_err());
+
+        gate.appeal(rejected.id, "council", "Clearly benign", true).unwrap();
+        assert!(gate.is_admitted(rejected.id));
+        // Another actor, stage or body is not covered by the appeal
+        assert!(!gate.check(GateStage::IdeaSubmission, "bob", "Fund the library").admitted);
+        assert!(!gate.check(GateStage::MarketCreation, "ada", "Fund the library").admitted);
+
+        let rerun = gate.admit(GateStage::IdeaSubmission, "ada", "Fund the library").unwrap();
+        assert_eq!(rerun.admitted_on_appeal, Some(rejected.id));
+        assert!(gate.admit(GateStage::IdeaSubmission, "ada", "Fund the library").is_err());
+    }
+
+    #[test]
+    fn prediction_market_opens_after_an_overturned_appeal() {
+        let gate = Arc::new(strict_gate());
+        let mut market = PredictionMarket::new(LocalTruth::shared()).with_mercy_gate(gate.clone());
+        assert!(market.open_market("Library funded by 2027", 100.0).is_err());
+        let rejected = gate.decisions().pop().unwrap();
+        gate.appeal(rejected.id, "council", "Benign question", true).unwrap();
+        market.open_market("Library funded by 2027", 100.0).unwrap();
+    }
+
+    struct Fixed(f64);
+
+    impl QuantaScorer for Fixed {
+        fn score(&self, _content: &str) -> QuantaScores {
+            MercyQuantum::ALL.iter().map(|&q| (q, self.0)).collect()
+        }
+    }
+
+    #[test]
+    fn overridden_gates_share_scorer_and_log() {
+        let base = MercyGate::new(MercyPolicy::default(), LocalTruth::shared()).with_scorer(Box::new(Fixed(0.62)));
+        let strict = base.overridden(&PolicyOverride { weighted_threshold: Some(0.7), ..PolicyOverride::default() });
+        assert_eq!(strict.policy().weighted_threshold, 0.7);
+        assert_eq!(base.policy().weighted_threshold, 0.6);
+
+        assert!(base.check(GateStage::IdeaSubmission, "ada", "Plain idea").admitted);
+        let rejected = strict.check(GateStage::IdeaSubmission, "ada", "Plain idea");
+        assert_eq!((rejected.id, rejected.weighted_score, rejected.admitted), (1, 0.62, false));
+        assert_eq!(base.decisions().len(), 2);
+
+        // Appeals recorded through either gate apply to both
+        base.appeal(rejected.id, "council", "Benign", true).unwrap();
+        assert!(strict.is_admitted(rejected.id));
+        assert!(strict.check(GateStage::IdeaSubmission, "ada", "Plain idea").admitted);
+    }
+
+    #[test]
+    fn log_rotates_without_reusing_ids() {
+        let gate = strict_gate().with_log_capacity(2);
+        let ids: Vec<u64> = (0..5).map(|i| gate.check(GateStage::IdeaSubmission, "ada", &format!("Idea {}", i)).id).collect();
+        assert_eq!(ids, vec![0, 1, 2, 3, 4]);
+        assert_eq!(gate.decisions().iter().map(|d| d.id).collect::<Vec<_>>(), vec![3, 4]);
+        assert!(gate.decision(2).is_none());
+        assert_eq!(gate.decision(4).unwrap().content, "Idea 4");
+        assert!(gate.appeal(0, "council", "Too late", true).is_err());
+        gate.appeal(3, "council", "Benign", true).unwrap();
+        assert!(gate.check(GateStage::IdeaSubmission, "ada", "Idea 3").admitted);
+    }
+
+    #[test]
+    fn unused_grants_lapse_when_their_decision_rotates_out() {
+        let gate = strict_gate().with_log_capacity(2);
+        let rejected = gate.check(GateStage::IdeaSubmission, "ada", "Idea 0");
+        gate.appeal(rejected.id, "council", "Benign", true).unwrap();
+        gate.check(GateStage::IdeaSubmission, "ada", "Idea 1");
+        assert_eq!(gate.log().overturned.len(), 1);
+
+        gate.check(GateStage::IdeaSubmission, "ada", "Idea 2");
+        assert!(gate.decision(rejected.id).is_none());
+        assert!(gate.log().overturned.is_empty());
+        assert!(!gate.check(GateStage::IdeaSubmission, "ada", "Idea 0").admitted);
+    }
+
+    #[test]
+    fn poisoned_log_stays_usable() {
+        let gate = Arc::new(strict_gate());
+        let rejected = gate.check(GateStage::IdeaSubmission, "ada", "Fund the library");
+        let holder = gate.clone();
+        let panicked = std::thread::spawn(move || {
+            let _log = holder.log.lock().unwrap();
+            panic!("reviewer crashed while holding the log");
+        })
+        .join();
+        assert!(panicked.is_err());
+        assert!(gate.log.is_poisoned());
+
+        gate.appeal(rejected.id, "council", "Benign", true).unwrap();
+        assert!(gate.admit(GateStage::IdeaSubmission, "ada", "Fund the library").is_ok());
+        assert_eq!(gate.decisions().len(), 2);
+    }
+}
@@ -1,22 +0,0 @@
-//! MetaDAO Oracle — Live Futarchy Market Integration Stub
-//! Ultramasterful belief aggregation from Solana futarchy markets
-
-use nexi::lattice::Nexus;
-
-pub struct MetaDAOOracle {
-    nexus: Nexus,
-}
-
-impl MetaDAOOracle {
-    pub fn new() -> Self {
-        MetaDAOOracle {
-            nexus: Nexus::init_with_mercy(),
-        }
-    }
-
-    /// Simulate MetaDAO market belief aggregation
-    pub fn aggregate_meta_dao_belief(&self, proposal: &str) -> String {
-        // Expand with Solana RPC + market data fetch
-        self.nexus.distill_truth(proposal)
-    }
-}
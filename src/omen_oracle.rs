@@ -1,22 +0,0 @@
-//! Omen Oracle — Conditional Market Integration Stub for Futarchy
-//! Ultramasterful belief aggregation from Omen/Gnosis markets
-
-use nexi::lattice::Nexus;
-
-pub struct OmenOracle {
-    nexus: Nexus,
-}
-
-impl OmenOracle {
-    pub fn new() -> Self {
-        OmenOracle {
-            nexus: Nexus::init_with_mercy(),
-        }
-    }
-
-    /// Aggregate belief from Omen conditional markets
-    pub fn aggregate_omen_belief(&self, market_url: &str) -> String {
-        // Expand with API fetch + market data
-        self.nexus.distill_truth(&format!("Omen futarchy belief: {}", market_url))
-    }
-}
@@ -0,0 +1,461 @@
+//! Augur History — Archived v1/v2 Market Importer and Replay Harness
+//! Ultramasterful cautionary data: how often thin markets were mis-resolved or manipulated
+//!
+//! Dumps are CSV or JSON arrays, one file per record kind (`markets`, `trades`, `reports`,
+//! `disputes`). Outcome indices are Augur's own: v1 yes/no markets use No=0/Yes=1,
+//! v2 uses Invalid=0/No=1/Yes=2.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::sim::futarchy_sim::MarketBackend;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AugurMarket {
+    pub id: String,
+    pub version: u8,
+    pub description: String,
+    pub num_outcomes: u8,
+    pub end_time: u64,
+    pub final_outcome: Option<u8>,
+    #[serde(default)]
+    pub invalid: bool,
+}
+
+impl AugurMarket {
+    pub fn yes_outcome(&self) -> u8 {
+        if self.version >= 2 { 2 } else { 1 }
+    }
+
+    pub fn no_outcome(&self) -> u8 {
+        if self.version >= 2 { 1 } else { 0 }
+    }
+
+    /// Yes probability implied by a recorded trade price (None for the v2 Invalid outcome)
+    pub fn implied_yes_price(&self, trade: &AugurTrade) -> Option<f64> {
+        if trade.outcome == self.yes_outcome() {
+            Some(trade.price)
+        } else if trade.outcome == self.no_outcome() {
+            Some(1.0 - trade.price)
+        } else {
+            None
+        }
+    }
+
+    pub fn is_yes_no(&self) -> bool {
+        self.num_outcomes as usize == if self.version >= 2 { 3 } else { 2 }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TradeSide {
+    Buy,
+    Sell,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AugurTrade {
+    pub market_id: String,
+    pub timestamp: u64,
+    pub trader: String,
+    pub outcome: u8,
+    pub side: TradeSide,
+    pub price: f64,
+    pub amount: f64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AugurReport {
+    pub market_id: String,
+    pub timestamp: u64,
+    pub reporter: String,
+    pub outcome: u8,
+    pub stake: f64,
+    #[serde(default)]
+    pub designated: bool,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AugurDispute {
+    pub market_id: String,
+    pub timestamp: u64,
+    pub round: u32,
+    pub disputer: String,
+    pub outcome: u8,
+    pub bond: f64,
+}
+
+/// Local store of imported Augur history, persisted as a single JSON file
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct AugurStore {
+    pub markets: Vec<AugurMarket>,
+    pub trades: Vec<AugurTrade>,
+    pub reports: Vec<AugurReport>,
+    pub disputes: Vec<AugurDispute>,
+}
+
+/// Read a CSV or JSON dump (by file extension)
+pub fn read_records<T: DeserializeOwned>(path: &Path) -> Result<Vec<T>, String> {
+    match path.extension().and_then(|e| e.to_str()) {
+        Some("csv") => {
+            let mut reader = csv::Reader::from_path(path)
+                .map_err(|e| format!("CSV error in {}: {:?}", path.display(), e))?;
+            reader.deserialize()
+                .collect::<Result<Vec<T>, _>>()
+                .map_err(|e| format!("CSV error in {}: {:?}", path.display(), e))
+        }
+        Some("json") => {
+            let raw = fs::read_to_string(path)
+                .map_err(|e| format!("Read error {}: {:?}", path.display(), e))?;
+            serde_json::from_str(&raw)
+                .map_err(|e| format!("JSON error in {}: {:?}", path.display(), e))
+        }
+        _ => Err(format!("Unsupported dump format: {}", path.display())),
+    }
+}
+
+impl AugurStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Import every `{markets,trades,reports,disputes}.{csv,json}` found in `dir`
+    pub fn import_dir(&mut self, dir: &Path) -> Result<(), String> {
+        fn find(dir: &Path, stem: &str) -> Option<std::path::PathBuf> {
+            ["csv", "json"].iter()
+                .map(|ext| dir.join(format!("{}.{}", stem, ext)))
+                .find(|p| p.exists())
+        }
+
+        if let Some(path) = find(dir, "markets") {
+            self.markets.extend(read_records::<AugurMarket>(&path)?);
+        }
+        if let Some(path) = find(dir, "trades") {
+            self.trades.extend(read_records::<AugurTrade>(&path)?);
+        }
+        if let Some(path) = find(dir, "reports") {
+            self.reports.extend(read_records::<AugurReport>(&path)?);
+        }
+        if let Some(path) = find(dir, "disputes") {
+            self.disputes.extend(read_records::<AugurDispute>(&path)?);
+        }
+
+        self.trades.sort_by_key(|t| t.timestamp);
+        self.reports.sort_by_key(|r| r.timestamp);
+        self.disputes.sort_by_key(|d| (d.round, d.timestamp));
+        Ok(())
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let raw = serde_json::to_string(self).map_err(|e| format!("Serialize error: {:?}", e))?;
+        fs::write(path, raw).map_err(|e| format!("Write error {}: {:?}", path.display(), e))
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let raw = fs::read_to_string(path).map_err(|e| format!("Read error {}: {:?}", path.display(), e))?;
+        serde_json::from_str(&raw).map_err(|e| format!("JSON error in {}: {:?}", path.display(), e))
+    }
+
+    pub fn market(&self, id: &str) -> Option<&AugurMarket> {
+        self.markets.iter().find(|m| m.id == id)
+    }
+
+    pub fn trades_for<'a>(&'a self, market_id: &'a str) -> impl Iterator<Item = &'a AugurTrade> + 'a {
+        self.trades.iter().filter(move |t| t.market_id == market_id)
+    }
+
+    pub fn reports_for<'a>(&'a self, market_id: &'a str) -> impl Iterator<Item = &'a AugurReport> + 'a {
+        self.reports.iter().filter(move |r| r.market_id == market_id)
+    }
+
+    pub fn disputes_for<'a>(&'a self, market_id: &'a str) -> impl Iterator<Item = &'a AugurDispute> + 'a {
+        self.disputes.iter().filter(move |d| d.market_id == market_id)
+    }
+}
+
+/// Replay thresholds
+#[derive(Clone, Debug)]
+pub struct ReplayConfig {
+    /// Liquidity seeded into each replayed market
+    pub liquidity: f64,
+    /// Markets with less traded volume than this are "thin"
+    pub thin_volume: f64,
+    /// Window before end time inspected for last-minute pushes (seconds)
+    pub late_window_secs: u64,
+    /// Replayed price move within the late window that counts as a push
+    pub late_push_threshold: f64,
+    /// Share of volume from a single trader that counts as concentrated
+    pub concentration_threshold: f64,
+    /// Recorded Yes price beyond which the market "knew" the answer
+    pub consensus_threshold: f64,
+}
+
+impl Default for ReplayConfig {
+    fn default() -> Self {
+        Self {
+            liquidity: 100.0,
+            thin_volume: 1_000.0,
+            late_window_secs: 24 * 60 * 60,
+            late_push_threshold: 0.25,
+            concentration_threshold: 0.5,
+            consensus_threshold: 0.9,
+        }
+    }
+}
+
+/// Outcome of replaying one historical yes/no market
+#[derive(Clone, Debug)]
+pub struct MarketReplay {
+    pub market_id: String,
+    pub volume: f64,
+    pub thin: bool,
+    pub engine_price: f64,
+    /// Last recorded Yes price (No trades imply 1 - price)
+    pub last_trade_price: Option<f64>,
+    /// Disputes overturned the designated report
+    pub designated_overturned: bool,
+    /// Final resolution contradicts the recorded market consensus
+    pub against_consensus: bool,
+    pub late_push: bool,
+    pub concentrated: bool,
+}
+
+impl MarketReplay {
+    pub fn misresolved(&self) -> bool {
+        self.designated_overturned || self.against_consensus
+    }
+
+    pub fn manipulation_suspected(&self) -> bool {
+        self.late_push || self.concentrated
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct ReplayReport {
+    pub markets: Vec<MarketReplay>,
+}
+
+impl ReplayReport {
+    fn rate(&self, thin: bool, predicate: impl Fn(&MarketReplay) -> bool) -> (usize, usize) {
+        let group: Vec<&MarketReplay> = self.markets.iter().filter(|m| m.thin == thin).collect();
+        (group.iter().filter(|m| predicate(m)).count(), group.len())
+    }
+
+    /// (mis-resolved, total) for thin or liquid markets
+    pub fn misresolution(&self, thin: bool) -> (usize, usize) {
+        self.rate(thin, MarketReplay::misresolved)
+    }
+
+    /// (manipulation flagged, total) for thin or liquid markets
+    pub fn manipulation(&self, thin: bool) -> (usize, usize) {
+        self.rate(thin, MarketReplay::manipulation_suspected)
+    }
+
+}
+
+impl fmt::Display for ReplayReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let pct = |(hit, total): (usize, usize)| if total == 0 { 0.0 } else { hit as f64 / total as f64 * 100.0 };
+        writeln!(f, "=== Augur Replay Summary ===")?;
+        for (label, thin) in [("Thin", true), ("Liquid", false)] {
+            let mis = self.misresolution(thin);
+            let man = self.manipulation(thin);
+            writeln!(f, "{} markets: {}", label, mis.1)?;
+            writeln!(f, "  Mis-resolved: {:.1}% ({}/{})", pct(mis), mis.0, mis.1)?;
+            writeln!(f, "  Manipulation flagged: {:.1}% ({}/{})", pct(man), man.0, man.1)?;
+        }
+        Ok(())
+    }
+}
+
+/// Group records by market in one pass, preserving their order
+fn by_market<T>(records: &[T], market_id: impl Fn(&T) -> &str) -> HashMap<&str, Vec<&T>> {
+    let mut index: HashMap<&str, Vec<&T>> = HashMap::new();
+    for record in records {
+        index.entry(market_id(record)).or_default().push(record);
+    }
+    index
+}
+
+/// Feed every historical yes/no market's trades through backend `M` and score resolutions
+pub fn replay<M: MarketBackend>(store: &AugurStore, config: &ReplayConfig) -> Result<ReplayReport, String> {
+    let mut report = ReplayReport::default();
+    let trades = by_market(&store.trades, |t| &t.market_id);
+    let reports = by_market(&store.reports, |r| &r.market_id);
+    let disputes = by_market(&store.disputes, |d| &d.market_id);
+
+    for market in store.markets.iter().filter(|m| m.is_yes_no() && !m.invalid) {
+        let Some(final_outcome) = market.final_outcome else { continue };
+        let yes = market.yes_outcome();
+
+        let mut engine = M::with_liquidity(config.liquidity)?;
+        let mut volume = 0.0;
+        let mut by_trader: HashMap<&str, f64> = HashMap::new();
+        let mut last_trade_price = None;
+        let mut price_at_window_start = None;
+        let window_start = market.end_time.saturating_sub(config.late_window_secs);
+
+        for &trade in trades.get(market.id.as_str()).into_iter().flatten() {
+            if trade.timestamp >= window_start && price_at_window_start.is_none() {
+                price_at_window_start = Some(engine.price());
+            }
+            let notional = trade.amount * trade.price;
+            // Buying Yes or selling any other outcome is a bet on Yes
+            if (trade.outcome == yes) == (trade.side == TradeSide::Buy) {
+                engine.buy(notional)?;
+            } else {
+                engine.sell(notional)?;
+            }
+            volume += notional;
+            *by_trader.entry(&trade.trader).or_insert(0.0) += notional;
+            if let Some(price) = market.implied_yes_price(trade) {
+                last_trade_price = Some(price);
+            }
+        }
+
+        let engine_price = engine.price();
+        let top_share = by_trader.values().cloned().fold(0.0, f64::max) / volume.max(f64::EPSILON);
+        let designated = reports.get(market.id.as_str())
+            .and_then(|rs| rs.iter().find(|r| r.designated))
+            .map(|r| r.outcome);
+        let resolved_yes = final_outcome == yes;
+
+        report.markets.push(MarketReplay {
+            market_id: market.id.clone(),
+            volume,
+            thin: volume < config.thin_volume,
+            engine_price,
+            last_trade_price,
+            designated_overturned: designated.is_some_and(|d| d != final_outcome)
+                && disputes.contains_key(market.id.as_str()),
+            against_consensus: last_trade_price.is_some_and(|price| {
+                (resolved_yes && price <= 1.0 - config.consensus_threshold)
+                    || (!resolved_yes && price >= config.consensus_threshold)
+            }),
+            late_push: price_at_window_start
+                .is_some_and(|start| (engine_price - start).abs() >= config.late_push_threshold),
+            concentrated: volume > 0.0 && top_share >= config.concentration_threshold,
+        });
+    }
+
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::markets::fpmm::FixedProductMarketMaker;
+
+    fn market(id: &str, final_outcome: u8) -> AugurMarket {
+        AugurMarket {
+            id: id.to_string(),
+            version: 1,
+            description: format!("Market {}", id),
+            num_outcomes: 2,
+            end_time: 1_000_000,
+            final_outcome: Some(final_outcome),
+            invalid: false,
+        }
+    }
+
+    fn trade(market_id: &str, timestamp: u64, outcome: u8, price: f64) -> AugurTrade {
+        AugurTrade {
+            market_id: market_id.to_string(),
+            timestamp,
+            trader: format!("0x{}", timestamp),
+            outcome,
+            side: TradeSide::Buy,
+            price,
+            amount: 1.0,
+        }
+    }
+
+    #[test]
+    fn consensus_is_judged_on_recorded_prices() {
+        let store = AugurStore {
+            // Recorded Yes at 0.95, resolved No → against consensus despite a tiny replayed move
+            markets: vec![market("a", 0), market("b", 1), market("c", 1)],
+            trades: vec![
+                trade("a", 10, 1, 0.95),
+                trade("b", 11, 1, 0.95),
+                // No trade at 0.97 implies Yes at 0.03
+                trade("c", 12, 0, 0.97),
+            ],
+            ..AugurStore::default()
+        };
+        let report = replay::<FixedProductMarketMaker>(&store, &ReplayConfig::default()).unwrap();
+        let flags: Vec<(&str, bool)> = report.markets.iter().map(|m| (m.market_id.as_str(), m.against_consensus)).collect();
+        assert_eq!(flags, vec![("a", true), ("b", false), ("c", true)]);
+        assert!(report.markets.iter().all(|m| (m.engine_price - 0.5).abs() < 0.05));
+        assert!((report.markets[2].last_trade_price.unwrap() - 0.03).abs() < 1e-12);
+    }
+
+    fn temp_dir(name: &str) -> std::path::PathBuf {
+        let nanos = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap().as_nanos();
+        let dir = std::env::temp_dir().join(format!("augur-history-{}-{}-{}", name, std::process::id(), nanos));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn fixture_dir_imports_and_round_trips() {
+        let dir = temp_dir("fixtures");
+        fs::write(dir.join("markets.csv"), "\
+id,version,description,num_outcomes,end_time,final_outcome,invalid
+m1,1,Will it rain?,2,1000,1,false
+m2,2,Will it snow?,3,2000,,false
+").unwrap();
+        // Out of order on purpose: the import sorts by timestamp
+        fs::write(dir.join("trades.json"), r#"[
+            {"market_id": "m1", "timestamp": 20, "trader": "0xb", "outcome": 0, "side": "sell", "price": 0.4, "amount": 2.0},
+            {"market_id": "m1", "timestamp": 10, "trader": "0xa", "outcome": 1, "side": "buy", "price": 0.55, "amount": 5.0}
+        ]"#).unwrap();
+        fs::write(dir.join("reports.csv"), "\
+market_id,timestamp,reporter,outcome,stake,designated
+m1,1100,0xdesignated,0,1.5,true
+").unwrap();
+        fs::write(dir.join("disputes.json"), r#"[
+            {"market_id": "m1", "timestamp": 1300, "round": 2, "disputer": "0xd", "outcome": 0, "bond": 8.0},
+            {"market_id": "m1", "timestamp": 1200, "round": 1, "disputer": "0xc", "outcome": 1, "bond": 4.0}
+        ]"#).unwrap();
+
+        let mut store = AugurStore::new();
+        store.import_dir(&dir).unwrap();
+        assert_eq!(store.markets.len(), 2);
+        assert_eq!(store.market("m2").unwrap().final_outcome, None);
+        assert_eq!(store.market("m1").unwrap().final_outcome, Some(1));
+        let trades: Vec<(u64, TradeSide)> = store.trades_for("m1").map(|t| (t.timestamp, t.side)).collect();
+        assert_eq!(trades, vec![(10, TradeSide::Buy), (20, TradeSide::Sell)]);
+        assert!(store.reports_for("m1").all(|r| r.designated && r.stake == 1.5));
+        let rounds: Vec<u32> = store.disputes_for("m1").map(|d| d.round).collect();
+        assert_eq!(rounds, vec![1, 2]);
+
+        let saved = dir.join("store.json");
+        store.save(&saved).unwrap();
+        let loaded = AugurStore::load(&saved).unwrap();
+        assert_eq!(serde_json::to_value(&loaded).unwrap(), serde_json::to_value(&store).unwrap());
+
+        // Records can also be read one file at a time; unknown extensions are refused
+        let markets: Vec<AugurMarket> = read_records(&dir.join("markets.csv")).unwrap();
+        assert_eq!(markets[1].description, "Will it snow?");
+        fs::write(dir.join("markets.txt"), "").unwrap();
+        let err = read_records::<AugurMarket>(&dir.join("markets.txt")).unwrap_err();
+        assert!(err.starts_with("Unsupported dump format"), "{}", err);
+        assert!(AugurStore::load(&dir.join("missing.json")).is_err());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn summary_renders_rates() {
+        let store = AugurStore { markets: vec![market("a", 0)], trades: vec![trade("a", 10, 1, 0.95)], ..AugurStore::default() };
+        let summary = replay::<FixedProductMarketMaker>(&store, &ReplayConfig::default()).unwrap().to_string();
+        assert!(summary.contains("Thin markets: 1"), "{}", summary);
+        assert!(summary.contains("Mis-resolved: 100.0% (1/1)"), "{}", summary);
+    }
+}
@@ -0,0 +1,129 @@
+//! Augur Oracle — Historical Prediction Market Reference
+//! Ultramasterful cautionary integration for futarchy evolution
+
+use std::path::Path;
+
+use crate::oracles::augur_history::{replay, AugurMarket, AugurStore, ReplayConfig, ReplayReport};
+use crate::sim::futarchy_sim::MarketBackend;
+use crate::truth::{SharedTruth, Verdict};
+
+/// Archived belief for one historical Augur market
+#[derive(Clone, Debug)]
+pub struct HistoricalBelief {
+    pub market: AugurMarket,
+    pub last_yes_price: Option<f64>,
+    pub mercy_verdict: Verdict,
+}
+
+pub struct AugurOracle {
+    truth: SharedTruth,
+    store: AugurStore,
+}
+
+impl AugurOracle {
+    pub fn new(truth: SharedTruth) -> Self {
+        Self::with_store(AugurStore::new(), truth)
+    }
+
+    /// Oracle over a previously saved store
+    pub fn with_store(store: AugurStore, truth: SharedTruth) -> Self {
+        AugurOracle { truth, store }
+    }
+
+    pub fn store(&self) -> &AugurStore {
+        &self.store
+    }
+
+    /// Import archived CSV/JSON dumps from a directory
+    pub fn import_dir(&mut self, dir: &Path) -> Result<(), String> {
+        self.store.import_dir(dir)
+    }
+
+    /// Historical Augur belief for markets matching `event` (cautionary — low liquidity)
+    pub fn historical_augur_belief(&self, event: &str) -> Vec<HistoricalBelief> {
+        let needle = event.to_lowercase();
+        self.store.markets.iter()
+            .filter(|m| m.description.to_lowercase().contains(&needle))
+            .map(|market| HistoricalBelief {
+                // No-side fills imply a Yes price too, so the latest fill on either side counts
+                last_yes_price: self.store.trades_for(&market.id)
+                    .filter_map(|t| market.implied_yes_price(t))
+                    .last(),
+                mercy_verdict: self.truth.evaluate(&format!("Augur historical caution: {}", market.description)),
+                market: market.clone(),
+            })
+            .collect()
+    }
+
+    /// Replay every imported market through backend `M`
+    pub fn replay<M: MarketBackend>(&self, config: &ReplayConfig) -> Result<ReplayReport, String> {
+        replay::<M>(&self.store, config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::oracles::augur_history::{AugurTrade, TradeSide};
+    use crate::truth::LocalTruth;
+
+    fn trade(market_id: &str, timestamp: u64, outcome: u8, price: f64) -> AugurTrade {
+        AugurTrade {
+            market_id: market_id.to_string(),
+            timestamp,
+            trader: format!("0x{}", timestamp),
+            outcome,
+            side: TradeSide::Buy,
+            price,
+            amount: 1.0,
+        }
+    }
+
+    fn market(id: &str, version: u8, description: &str) -> AugurMarket {
+        AugurMarket {
+            id: id.to_string(),
+            version,
+            description: description.to_string(),
+            num_outcomes: if version >= 2 { 3 } else { 2 },
+            end_time: 1_000,
+            final_outcome: None,
+            invalid: false,
+        }
+    }
+
+    #[test]
+    fn trailing_no_trade_sets_the_yes_price() {
+        let store = AugurStore {
+            markets: vec![
+                market("v1", 1, "Will the levee hold in 2019?"),
+                market("v2", 2, "Will the levee be rebuilt?"),
+                market("other", 1, "Unrelated harvest market"),
+            ],
+            trades: vec![
+                // v1: Yes at 0.60, then No at 0.30 → Yes implied at 0.70
+                trade("v1", 10, 1, 0.60),
+                trade("v1", 20, 0, 0.30),
+                // v2: No (1) at 0.80 → 0.20; a trailing Invalid (0) fill implies nothing
+                trade("v2", 10, 2, 0.50),
+                trade("v2", 20, 1, 0.80),
+                trade("v2", 30, 0, 0.05),
+            ],
+            ..AugurStore::default()
+        };
+        let oracle = AugurOracle::with_store(store, LocalTruth::shared());
+
+        let beliefs = oracle.historical_augur_belief("LEVEE");
+        let prices: Vec<(&str, Option<f64>)> = beliefs.iter().map(|b| (b.market.id.as_str(), b.last_yes_price)).collect();
+        assert_eq!(prices.len(), 2);
+        assert_eq!(prices[0].0, "v1");
+        assert!((prices[0].1.unwrap() - 0.70).abs() < 1e-12);
+        assert_eq!(prices[1].0, "v2");
+        assert!((prices[1].1.unwrap() - 0.20).abs() < 1e-12);
+
+        let untraded = AugurOracle::with_store(
+            AugurStore { markets: vec![market("quiet", 1, "Quiet levee market")], ..AugurStore::default() },
+            LocalTruth::shared(),
+        );
+        assert_eq!(untraded.historical_augur_belief("levee")[0].last_yes_price, None);
+    }
+}
@@ -0,0 +1,213 @@
+//! Conditional Token ID Derivation — Exact Rust Mirror of Gnosis CTHelpers
+//! Ultramasterful reconciliation of simulator positions with on-chain CTF positions
+
+use ethers::{
+    types::{Address, U256, U512},
+    utils::keccak256,
+};
+
+/// alt_bn128 base field modulus (curve y² = x³ + 3)
+const P: U256 = U256([
+    0x3c208c16d87cfd47,
+    0x97816a916871ca8d,
+    0xb85045b68181585d,
+    0x30644e72e131a029,
+]);
+
+const B: u64 = 3;
+
+/// `keccak256(abi.encodePacked(oracle, questionId, outcomeSlotCount))`
+pub fn get_condition_id(oracle: Address, question_id: [u8; 32], outcome_slot_count: u32) -> [u8; 32] {
+    let mut packed = Vec::with_capacity(20 + 32 + 32);
+    packed.extend_from_slice(oracle.as_bytes());
+    packed.extend_from_slice(&question_id);
+    packed.extend_from_slice(&u256_bytes(U256::from(outcome_slot_count)));
+    keccak256(packed)
+}
+
+/// CTF v1.0+ collection ID: hash-to-curve of (conditionId, indexSet), added onto the parent collection point
+pub fn get_collection_id(parent_collection_id: [u8; 32], condition_id: [u8; 32], index_set: U256) -> Result<[u8; 32], String> {
+    let mut packed = Vec::with_capacity(64);
+    packed.extend_from_slice(&condition_id);
+    packed.extend_from_slice(&u256_bytes(index_set));
+
+    let mut x1 = U256::from_big_endian(&keccak256(packed));
+    let odd = x1.bit(255);
+    let mut y1;
+    let mut yy;
+    loop {
+        x1 = addmod(x1, U256::one());
+        yy = curve_rhs(x1);
+        y1 = sqrt(yy);
+        if mulmod(y1, y1) == yy {
+            break;
+        }
+    }
+    if odd != y1.bit(0) {
+        y1 = P - y1;
+    }
+
+    let mut x2 = U256::from_big_endian(&parent_collection_id);
+    if !x2.is_zero() {
+        let odd = x2.bit(254);
+        x2 = (x2 << 2) >> 2;
+        let yy = curve_rhs(x2);
+        let mut y2 = sqrt(yy);
+        if odd != y2.bit(0) {
+            y2 = P - y2;
+        }
+        if mulmod(y2, y2) != yy {
+            return Err("invalid parent collection ID".to_string());
+        }
+        (x1, y1) = ec_add((x1, y1), (x2, y2));
+    }
+
+    if y1.bit(0) {
+        x1 ^= U256::one() << 254;
+    }
+
+    Ok(u256_bytes(x1))
+}
+
+/// `uint(keccak256(abi.encodePacked(collateralToken, collectionId)))`
+pub fn get_position_id(collateral_token: Address, collection_id: [u8; 32]) -> U256 {
+    let mut packed = Vec::with_capacity(20 + 32);
+    packed.extend_from_slice(collateral_token.as_bytes());
+    packed.extend_from_slice(&collection_id);
+    U256::from_big_endian(&keccak256(packed))
+}
+
+fn u256_bytes(value: U256) -> [u8; 32] {
+    let mut out = [0u8; 32];
+    value.to_big_endian(&mut out);
+    out
+}
+
+fn addmod(a: U256, b: U256) -> U256 {
+    let sum = U512::from(a) + U512::from(b);
+    U256::try_from(sum % U512::from(P)).expect("reduced below P")
+}
+
+fn submod(a: U256, b: U256) -> U256 {
+    addmod(a, P - (b % P))
+}
+
+fn mulmod(a: U256, b: U256) -> U256 {
+    let product = U512::from(a) * U512::from(b);
+    U256::try_from(product % U512::from(P)).expect("reduced below P")
+}
+
+fn powmod(mut base: U256, mut exp: U256) -> U256 {
+    let mut result = U256::one();
+    base %= P;
+    while !exp.is_zero() {
+        if exp.bit(0) {
+            result = mulmod(result, base);
+        }
+        base = mulmod(base, base);
+        exp >>= 1;
+    }
+    result
+}
+
+fn curve_rhs(x: U256) -> U256 {
+    addmod(mulmod(x, mulmod(x, x)), U256::from(B))
+}
+
+/// Candidate square root (P ≡ 3 mod 4); callers check it squares back
+fn sqrt(a: U256) -> U256 {
+    powmod(a, (P + U256::one()) >> 2)
+}
+
+fn inverse(a: U256) -> U256 {
+    powmod(a, P - U256::from(2))
+}
+
+/// Affine point addition matching the ecAdd precompile (0x06), infinity encoded as (0, 0)
+fn ec_add(p1: (U256, U256), p2: (U256, U256)) -> (U256, U256) {
+    let ((x1, y1), (x2, y2)) = (p1, p2);
+    let lambda = if x1 == x2 {
+        if addmod(y1, y2).is_zero() {
+            return (U256::zero(), U256::zero());
+        }
+        mulmod(mulmod(U256::from(3), mulmod(x1, x1)), inverse(addmod(y1, y1)))
+    } else {
+        mulmod(submod(y2, y1), inverse(submod(x2, x1)))
+    };
+    let x3 = submod(submod(mulmod(lambda, lambda), x1), x2);
+    let y3 = submod(mulmod(lambda, submod(x1, x3)), y1);
+    (x3, y3)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Vectors from a line-by-line port of CTHelpers.sol (conditional-tokens-contracts v1.0.3)
+    const ORACLE: &str = "0x5b38da6a701c568545dcfcb03fcb875f56beddc4";
+    const USDC: &str = "0x2791bca1f2de4661ed88a30c99a7a9449aa84174";
+    const CONDITION: &str = "27ace8e768ac743efad720fafb135d6d9c7dae0545d281b91d8078d65d81bd77";
+    const SECOND_CONDITION: &str = "377bbd7cbbee67e9abb5c9e32993c09cf4e3c7a958badccb35d719b42f3dd0de";
+
+    fn bytes32(hex: &str) -> [u8; 32] {
+        let mut out = [0u8; 32];
+        for (i, byte) in out.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).unwrap();
+        }
+        out
+    }
+
+    fn address(hex: &str) -> Address {
+        hex.parse().unwrap()
+    }
+
+    #[test]
+    fn condition_ids_match_reference() {
+        assert_eq!(get_condition_id(address(ORACLE), [0xab; 32], 2), bytes32(CONDITION));
+        assert_eq!(get_condition_id(address(ORACLE), keccak256(b"second question"), 3), bytes32(SECOND_CONDITION));
+    }
+
+    #[test]
+    fn top_level_collections_and_positions_match_reference() {
+        let yes = get_collection_id([0u8; 32], bytes32(CONDITION), U256::from(1)).unwrap();
+        let no = get_collection_id([0u8; 32], bytes32(CONDITION), U256::from(2)).unwrap();
+        assert_eq!(yes, bytes32("17ae80364845a6b057c95b0356b268a5ffafb72cf89f6fc47553b4bfaab1f569"));
+        assert_eq!(no, bytes32("138a9b4acfbf5d574b00dd46d27f50cbbdf74dbeb3c85af7f09ca630744a85ea"));
+        assert_eq!(
+            get_position_id(address(USDC), yes),
+            U256::from_big_endian(&bytes32("d29868574e4c704c0e3f401f62251af3d95e0bdf897b9f0bc75b6f2ab6d71ca1"))
+        );
+        assert_eq!(
+            get_position_id(address(USDC), no),
+            U256::from_big_endian(&bytes32("2dbe7a92d4b77c6c27f92dca8d56a1074aa9786c5723f974f13d82342ccd750d"))
+        );
+    }
+
+    #[test]
+    fn nested_collection_with_parent_matches_reference() {
+        let parent = get_collection_id([0u8; 32], bytes32(CONDITION), U256::from(1)).unwrap();
+        let nested = get_collection_id(parent, bytes32(SECOND_CONDITION), U256::from(0b101)).unwrap();
+        assert_eq!(nested, bytes32("5340322ba9807f0151eb663167f2b4d93b7297d4420f68ecb890ee05e8cacc32"));
+        assert_eq!(
+            get_position_id(address(USDC), nested),
+            U256::from_big_endian(&bytes32("4a4f3818e68ab82c7f5ef0628358a839797995ef04a2aefcce08c9f85d6967ea"))
+        );
+
+        // Collection IDs are curve points, so the order conditions are nested in does not matter
+        let other_parent = get_collection_id([0u8; 32], bytes32(SECOND_CONDITION), U256::from(0b101)).unwrap();
+        assert_eq!(get_collection_id(other_parent, bytes32(CONDITION), U256::from(1)).unwrap(), nested);
+    }
+
+    #[test]
+    fn off_curve_parent_is_rejected() {
+        // Smallest x with no point on the curve
+        let parent = (1u64..)
+            .map(U256::from)
+            .find(|x| {
+                let yy = curve_rhs(*x);
+                mulmod(sqrt(yy), sqrt(yy)) != yy
+            })
+            .unwrap();
+        assert!(get_collection_id(u256_bytes(parent), bytes32(CONDITION), U256::from(1)).is_err());
+    }
+}
@@ -0,0 +1,270 @@
+//! Gnosis Futarchy Bridge — Conditional Token Oracle for Multi-Chain Belief Aggregation
+//! Ultramasterful integration with Gnosis Conditional Tokens
+
+use std::sync::Arc;
+
+use ethers::{
+    contract::{abigen, parse_log, ContractCall},
+    middleware::{NonceManagerMiddleware, SignerMiddleware},
+    providers::{Http, Middleware, Provider},
+    signers::{LocalWallet, Signer},
+    types::{Address, TransactionReceipt, U256, U512},
+};
+use ethers::core::abi::Detokenize;
+
+use crate::oracles::ctf_ids::{get_collection_id, get_condition_id, get_position_id};
+use crate::futarchy::FutarchyOutcome;
+use crate::mercy::{GateStage, MercyGate};
+
+abigen!(
+    ConditionalTokens,
+    r#"[
+        function prepareCondition(address oracle, bytes32 questionId, uint outcomeSlotCount)
+        function splitPosition(address collateralToken, bytes32 parentCollectionId, bytes32 conditionId, uint[] partition, uint amount)
+        function reportPayouts(bytes32 questionId, uint[] payouts)
+        function payoutNumerators(bytes32 conditionId, uint index) external view returns (uint)
+        function payoutDenominator(bytes32 conditionId) external view returns (uint)
+        function getOutcomeSlotCount(bytes32 conditionId) external view returns (uint)
+        event ConditionPreparation(bytes32 indexed conditionId, address indexed oracle, bytes32 indexed questionId, uint outcomeSlotCount)
+    ]"#,
+);
+
+/// Signing client: nonce manager over the signer, so a failed send resyncs the nonce it handed out
+pub type BridgeClient = NonceManagerMiddleware<SignerMiddleware<Provider<Http>, LocalWallet>>;
+
+/// Headroom applied on top of `eth_estimateGas` (percent)
+const GAS_HEADROOM_PERCENT: u64 = 20;
+
+/// Fixed-point scale used when normalising payout numerators
+const PAYOUT_SCALE: u128 = 1_000_000_000_000_000_000;
+
+/// On-chain resolution state of a CTF condition
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConditionResolution {
+    Unresolved { outcome_slots: usize },
+    Resolved { numerators: Vec<U256>, denominator: U256 },
+}
+
+impl ConditionResolution {
+    /// Payout fraction per outcome slot (sums to 1.0), `None` while unresolved
+    pub fn normalised_payouts(&self) -> Option<Vec<f64>> {
+        match self {
+            ConditionResolution::Unresolved { .. } => None,
+            ConditionResolution::Resolved { numerators, denominator } => Some(
+                numerators.iter()
+                    .map(|n| {
+                        // Widen to U512 so full-range numerators cannot overflow before the divide
+                        let scaled = n.full_mul(U256::from(PAYOUT_SCALE)) / U512::from(*denominator);
+                        u128::try_from(scaled).unwrap_or(u128::MAX) as f64 / PAYOUT_SCALE as f64
+                    })
+                    .collect(),
+            ),
+        }
+    }
+}
+
+impl From<ConditionResolution> for FutarchyOutcome {
+    fn from(resolution: ConditionResolution) -> Self {
+        match resolution.normalised_payouts() {
+            None => FutarchyOutcome::Pending,
+            Some(payouts) => FutarchyOutcome::from_payouts(payouts),
+        }
+    }
+}
+
+pub struct GnosisBridge {
+    gate: Arc<MercyGate>,
+    client: Arc<BridgeClient>,
+    contract: ConditionalTokens<BridgeClient>,
+}
+
+impl GnosisBridge {
+    pub fn new(rpc_url: &str, contract_address: Address, signer_key: &str, chain_id: u64, gate: Arc<MercyGate>) -> Result<Self, String> {
+        let provider = Provider::<Http>::try_from(rpc_url)
+            .map_err(|e| format!("Provider error: {:?}", e))?;
+        let wallet = signer_key.parse::<LocalWallet>()
+            .map_err(|e| format!("Signer error: {:?}", e))?
+            .with_chain_id(chain_id);
+        let address = wallet.address();
+        let signer = SignerMiddleware::new(provider, wallet);
+        let client = Arc::new(NonceManagerMiddleware::new(signer, address));
+        let contract = ConditionalTokens::new(contract_address, client.clone());
+
+        Ok(GnosisBridge {
+            gate,
+            client,
+            contract,
+        })
+    }
+
+    /// Address transactions are signed from (also the oracle for conditions it reports on)
+    pub fn signer_address(&self) -> Address {
+        self.client.inner().address()
+    }
+
+    /// Condition ID for a question this bridge reports on as oracle
+    pub fn own_condition_id(&self, question_id: [u8; 32], outcomes: u32) -> [u8; 32] {
+        get_condition_id(self.signer_address(), question_id, outcomes)
+    }
+
+    /// ERC-1155 position ID for a top-level conditional position on `condition_id`
+    pub fn position_id(&self, collateral_token: Address, condition_id: [u8; 32], index_set: U256) -> Result<U256, String> {
+        let collection_id = get_collection_id([0u8; 32], condition_id, index_set)?;
+        Ok(get_position_id(collateral_token, collection_id))
+    }
+
+    /// Estimate gas, sign, submit and wait for a successful receipt
+    async fn submit<D: Detokenize>(&self, call: ContractCall<BridgeClient, D>, label: &str) -> Result<TransactionReceipt, String> {
+        let estimate = call.estimate_gas()
+            .await
+            .map_err(|e| format!("{} gas estimation failed: {:?}", label, e))?;
+        let call = call.gas(estimate * (100 + GAS_HEADROOM_PERCENT) / 100);
+
+        let pending = call.send()
+            .await
+            .map_err(|e| format!("{} submission failed: {:?}", label, e))?;
+        let receipt = pending
+            .await
+            .map_err(|e| format!("{} receipt error: {:?}", label, e))?
+            .ok_or_else(|| format!("{} dropped from mempool", label))?;
+
+        if receipt.status != Some(1.into()) {
+            return Err(format!("{} reverted in tx {:?}", label, receipt.transaction_hash));
+        }
+        Ok(receipt)
+    }
+
+    /// Prepare futarchy condition on Gnosis — returns the condition ID from `ConditionPreparation`
+    pub async fn prepare_futarchy_condition(
+        &self,
+        oracle: Address,
+        question_id: [u8; 32],
+        outcomes: u32,
+        description: &str,
+    ) -> Result<[u8; 32], String> {
+        // Mercy-gated: the question must pass market creation before anything is signed
+        self.gate.admit(GateStage::MarketCreation, &format!("{:?}", self.signer_address()), description)?;
+
+        let call = self.contract.prepare_condition(oracle, question_id, U256::from(outcomes));
+        let receipt = self.submit(call, "prepareCondition").await?;
+
+        receipt.logs.into_iter()
+            .filter(|log| log.address == self.contract.address())
+            .find_map(|log| parse_log::<ConditionPreparationFilter>(log).ok())
+            .map(|event| event.condition_id)
+            .ok_or_else(|| "prepareCondition receipt has no ConditionPreparation event".to_string())
+    }
+
+    /// Split collateral (or a parent position) into conditional positions along `partition`
+    pub async fn split_position(
+        &self,
+        collateral_token: Address,
+        parent_collection_id: [u8; 32],
+        condition_id: [u8; 32],
+        partition: Vec<U256>,
+        amount: U256,
+    ) -> Result<TransactionReceipt, String> {
+        let call = self.contract.split_position(collateral_token, parent_collection_id, condition_id, partition, amount);
+        self.submit(call, "splitPosition").await
+    }
+
+    /// Report payouts as the condition oracle (signer must be the oracle address)
+    pub async fn report_payouts(&self, question_id: [u8; 32], payouts: Vec<U256>) -> Result<TransactionReceipt, String> {
+        let call = self.contract.report_payouts(question_id, payouts);
+        self.submit(call, "reportPayouts").await
+    }
+
+    /// Read a condition's payout vector — unresolved while the denominator is still zero
+    pub async fn read_resolution(&self, condition_id: [u8; 32]) -> Result<ConditionResolution, String> {
+        let slots = self.contract.get_outcome_slot_count(condition_id)
+            .call()
+            .await
+            .map_err(|e| format!("getOutcomeSlotCount failed: {:?}", e))?
+            .as_usize();
+        if slots == 0 {
+            return Err(format!("Condition 0x{} not prepared", hex_id(&condition_id)));
+        }
+
+        let denominator = self.contract.payout_denominator(condition_id)
+            .call()
+            .await
+            .map_err(|e| format!("payoutDenominator failed: {:?}", e))?;
+        if denominator.is_zero() {
+            return Ok(ConditionResolution::Unresolved { outcome_slots: slots });
+        }
+
+        let mut numerators = Vec::with_capacity(slots);
+        for index in 0..slots {
+            let numerator = self.contract.payout_numerators(condition_id, U256::from(index))
+                .call()
+                .await
+                .map_err(|e| format!("payoutNumerators({}) failed: {:?}", index, e))?;
+            numerators.push(numerator);
+        }
+
+        Ok(ConditionResolution::Resolved { numerators, denominator })
+    }
+
+    /// Resolve futarchy outcome via Gnosis payouts
+    pub async fn resolve_futarchy_outcome(&self, condition_id: [u8; 32]) -> Result<FutarchyOutcome, String> {
+        self.read_resolution(condition_id).await.map(FutarchyOutcome::from)
+    }
+}
+
+fn hex_id(id: &[u8; 32]) -> String {
+    id.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mercy::MercyPolicy;
+    use crate::truth::LocalTruth;
+
+    #[test]
+    fn normalised_payouts_survive_full_range_numerators() {
+        let resolution = ConditionResolution::Resolved {
+            numerators: vec![U256::MAX, U256::zero()],
+            denominator: U256::MAX,
+        };
+        assert_eq!(resolution.normalised_payouts(), Some(vec![1.0, 0.0]));
+
+        let half = U256::MAX / 2;
+        let split = ConditionResolution::Resolved { numerators: vec![half, half], denominator: half * 2 };
+        assert_eq!(split.normalised_payouts(), Some(vec![0.5, 0.5]));
+        assert_eq!(ConditionResolution::Unresolved { outcome_slots: 2 }.normalised_payouts(), None);
+    }
+
+    /// Round-trip against a local Anvil/Hardhat node with ConditionalTokens deployed.
+    /// Needs `CTF_RPC_URL`, `CTF_ADDRESS` and `CTF_SIGNER_KEY` (`CTF_CHAIN_ID` defaults to 31337).
+    #[tokio::test]
+    #[ignore = "needs a local node with ConditionalTokens deployed"]
+    async fn prepare_report_and_resolve_on_local_node() {
+        let env = |key: &str| std::env::var(key).unwrap_or_else(|_| panic!("{} not set", key));
+        let chain_id = std::env::var("CTF_CHAIN_ID").ok().and_then(|c| c.parse().ok()).unwrap_or(31337);
+        let contract: Address = env("CTF_ADDRESS").parse().expect("CTF_ADDRESS");
+        let gate = Arc::new(MercyGate::new(MercyPolicy::default(), LocalTruth::shared()));
+        let bridge = GnosisBridge::new(&env("CTF_RPC_URL"), contract, &env("CTF_SIGNER_KEY"), chain_id, gate)
+            .expect("bridge");
+
+        let mut question_id = [0u8; 32];
+        let nanos = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap().as_nanos();
+        question_id[16..].copy_from_slice(&nanos.to_be_bytes());
+
+        let oracle = bridge.signer_address();
+        let condition_id = bridge
+            .prepare_futarchy_condition(oracle, question_id, 2, "Fund open-source public goods for the commons")
+            .await
+            .expect("prepareCondition");
+        assert_eq!(condition_id, bridge.own_condition_id(question_id, 2));
+        assert_eq!(
+            bridge.read_resolution(condition_id).await.unwrap(),
+            ConditionResolution::Unresolved { outcome_slots: 2 }
+        );
+
+        // Back-to-back sends exercise the nonce manager
+        bridge.report_payouts(question_id, vec![U256::one(), U256::zero()]).await.expect("reportPayouts");
+        assert!(bridge.report_payouts(question_id, vec![U256::one(), U256::zero()]).await.is_err());
+        assert_eq!(bridge.resolve_futarchy_outcome(condition_id).await.unwrap(), FutarchyOutcome::Adopted);
+    }
+}
@@ -0,0 +1,776 @@
+//! MetaDAO Oracle — Live Futarchy Market Integration via Solana JSON-RPC
+//! Ultramasterful belief aggregation from Solana futarchy markets
+//!
+//! Account layouts follow the autocrat / conditional_vault / amm Anchor programs
+//! (8-byte discriminator, then Borsh-encoded fields).
+
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::truth::{SharedTruth, Verdict};
+
+const SOLANA_RPC: &str = "https://api.mainnet-beta.solana.com";
+
+/// AMM prices are quote-per-base scaled by 1e12
+pub const AMM_PRICE_SCALE: u128 = 1_000_000_000_000;
+
+const ANCHOR_DISCRIMINATOR_LEN: usize = 8;
+
+/// Program ids of the MetaDAO v0.3 deployment
+pub const AUTOCRAT_PROGRAM: &str = "autoQP9RmUNkzzKRXsMkWicDVZ3h29vvyMDcAYjCxxg";
+pub const AMM_PROGRAM: &str = "AMM5G2nxuKUwCLRYTW7qqEwuoqCtNSjtbipwEmm2g8bH";
+pub const CONDITIONAL_VAULT_PROGRAM: &str = "VAU1T7S5UuEHmMvXtXMVmpEoQtZ2ya7eRb7gcN47wDp";
+
+/// Anchor account discriminator: `sha256("account:<Name>")[..8]`
+pub fn account_discriminator(name: &str) -> [u8; ANCHOR_DISCRIMINATOR_LEN] {
+    let hash = Sha256::digest(format!("account:{}", name).as_bytes());
+    hash[..ANCHOR_DISCRIMINATOR_LEN].try_into().unwrap()
+}
+
+/// Borsh size of one instruction `AccountMeta` (pubkey, is_signer, is_writable)
+const PROPOSAL_ACCOUNT_LEN: usize = 34;
+
+pub type Pubkey = [u8; 32];
+
+/// Base58 rendering used when addressing accounts over RPC
+pub fn pubkey_to_string(key: &Pubkey) -> String {
+    bs58::encode(key).into_string()
+}
+
+/// Minimal Borsh reader for the fixed account layouts below
+struct BorshReader<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> BorshReader<'a> {
+    /// Reader positioned after the discriminator, which must be `name`'s
+    fn anchor_account(data: &'a [u8], name: &str) -> Result<Self, String> {
+        let discriminator = data.get(..ANCHOR_DISCRIMINATOR_LEN)
+            .ok_or_else(|| "Account data shorter than Anchor discriminator".to_string())?;
+        if discriminator != account_discriminator(name) {
+            return Err(format!("Account is not a {} (discriminator mismatch)", name));
+        }
+        Ok(Self { data, offset: ANCHOR_DISCRIMINATOR_LEN })
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8], String> {
+        let end = self.offset + len;
+        let bytes = self.data.get(self.offset..end)
+            .ok_or_else(|| format!("Account data truncated at byte {}", self.offset))?;
+        self.offset = end;
+        Ok(bytes)
+    }
+
+    fn remaining(&self) -> usize {
+        self.data.len().saturating_sub(self.offset)
+    }
+
+    fn u8(&mut self) -> Result<u8, String> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn bool(&mut self) -> Result<bool, String> {
+        Ok(self.u8()? != 0)
+    }
+
+    fn u16(&mut self) -> Result<u16, String> {
+        Ok(u16::from_le_bytes(self.take(2)?.try_into().unwrap()))
+    }
+
+    fn u32(&mut self) -> Result<u32, String> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn u64(&mut self) -> Result<u64, String> {
+        Ok(u64::from_le_bytes(self.take(8)?.try_into().unwrap()))
+    }
+
+    fn u128(&mut self) -> Result<u128, String> {
+        Ok(u128::from_le_bytes(self.take(16)?.try_into().unwrap()))
+    }
+
+    fn pubkey(&mut self) -> Result<Pubkey, String> {
+        Ok(self.take(32)?.try_into().unwrap())
+    }
+
+    fn bytes(&mut self) -> Result<Vec<u8>, String> {
+        let len = self.u32()? as usize;
+        Ok(self.take(len)?.to_vec())
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        String::from_utf8(self.bytes()?).map_err(|e| format!("Invalid UTF-8 string: {:?}", e))
+    }
+}
+
+/// autocrat `Dao` account — futarchy parameters shared by all its proposals
+#[derive(Clone, Debug)]
+pub struct Dao {
+    pub treasury_pda_bump: u8,
+    pub treasury: Pubkey,
+    pub token_mint: Pubkey,
+    pub usdc_mint: Pubkey,
+    pub proposal_count: u32,
+    pub pass_threshold_bps: u16,
+    pub slots_per_proposal: u64,
+    pub twap_initial_observation: u128,
+    pub twap_max_observation_change_per_update: u128,
+    pub min_quote_futarchic_liquidity: u64,
+    pub min_base_futarchic_liquidity: u64,
+}
+
+impl Dao {
+    pub fn decode(data: &[u8]) -> Result<Self, String> {
+        let mut r = BorshReader::anchor_account(data, "Dao")?;
+        Ok(Dao {
+            treasury_pda_bump: r.u8()?,
+            treasury: r.pubkey()?,
+            token_mint: r.pubkey()?,
+            usdc_mint: r.pubkey()?,
+            proposal_count: r.u32()?,
+            pass_threshold_bps: r.u16()?,
+            slots_per_proposal: r.u64()?,
+            twap_initial_observation: r.u128()?,
+            twap_max_observation_change_per_update: r.u128()?,
+            min_quote_futarchic_liquidity: r.u64()?,
+            min_base_futarchic_liquidity: r.u64()?,
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProposalState {
+    Pending,
+    Passed,
+    Failed,
+    Executed,
+}
+
+#[derive(Clone, Debug)]
+pub struct ProposalAccount {
+    pub pubkey: Pubkey,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+#[derive(Clone, Debug)]
+pub struct ProposalInstruction {
+    pub program_id: Pubkey,
+    pub accounts: Vec<ProposalAccount>,
+    pub data: Vec<u8>,
+}
+
+/// autocrat `Proposal` account
+#[derive(Clone, Debug)]
+pub struct Proposal {
+    pub number: u32,
+    pub proposer: Pubkey,
+    pub description_url: String,
+    pub slot_enqueued: u64,
+    pub state: ProposalState,
+    pub instruction: ProposalInstruction,
+    pub pass_amm: Pubkey,
+    pub fail_amm: Pubkey,
+    pub base_vault: Pubkey,
+    pub quote_vault: Pubkey,
+    pub dao: Pubkey,
+    pub pass_lp_tokens_locked: u64,
+    pub fail_lp_tokens_locked: u64,
+    pub nonce: u64,
+    pub pda_bump: u8,
+}
+
+impl Proposal {
+    pub fn decode(data: &[u8]) -> Result<Self, String> {
+        let mut r = BorshReader::anchor_account(data, "Proposal")?;
+        let number = r.u32()?;
+        let proposer = r.pubkey()?;
+        let description_url = r.string()?;
+        let slot_enqueued = r.u64()?;
+        let state = match r.u8()? {
+            0 => ProposalState::Pending,
+            1 => ProposalState::Passed,
+            2 => ProposalState::Failed,
+            3 => ProposalState::Executed,
+            other => return Err(format!("Unknown proposal state: {}", other)),
+        };
+        let program_id = r.pubkey()?;
+        let account_count = r.u32()? as usize;
+        // Each AccountMeta is a pubkey plus two flags; bound the count by what the data can hold
+        if account_count > r.remaining() / PROPOSAL_ACCOUNT_LEN {
+            return Err(format!("Instruction claims {} accounts, data holds at most {}", account_count, r.remaining() / PROPOSAL_ACCOUNT_LEN));
+        }
+        let mut accounts = Vec::with_capacity(account_count);
+        for _ in 0..account_count {
+            accounts.push(ProposalAccount {
+                pubkey: r.pubkey()?,
+                is_signer: r.bool()?,
+                is_writable: r.bool()?,
+            });
+        }
+        let data = r.bytes()?;
+
+        Ok(Proposal {
+            number,
+            proposer,
+            description_url,
+            slot_enqueued,
+            state,
+            instruction: ProposalInstruction { program_id, accounts, data },
+            pass_amm: r.pubkey()?,
+            fail_amm: r.pubkey()?,
+            base_vault: r.pubkey()?,
+            quote_vault: r.pubkey()?,
+            dao: r.pubkey()?,
+            pass_lp_tokens_locked: r.u64()?,
+            fail_lp_tokens_locked: r.u64()?,
+            nonce: r.u64()?,
+            pda_bump: r.u8()?,
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VaultStatus {
+    Active,
+    Finalized,
+    Reverted,
+}
+
+/// conditional_vault `ConditionalVault` account
+#[derive(Clone, Debug)]
+pub struct ConditionalVault {
+    pub status: VaultStatus,
+    pub settlement_authority: Pubkey,
+    pub underlying_token_mint: Pubkey,
+    pub underlying_token_account: Pubkey,
+    pub conditional_on_finalize_token_mint: Pubkey,
+    pub conditional_on_revert_token_mint: Pubkey,
+    pub pda_bump: u8,
+    pub decimals: u8,
+}
+
+impl ConditionalVault {
+    pub fn decode(data: &[u8]) -> Result<Self, String> {
+        let mut r = BorshReader::anchor_account(data, "ConditionalVault")?;
+        let status = match r.u8()? {
+            0 => VaultStatus::Active,
+            1 => VaultStatus::Finalized,
+            2 => VaultStatus::Reverted,
+            other => return Err(format!("Unknown vault status: {}", other)),
+        };
+        Ok(ConditionalVault {
+            status,
+            settlement_authority: r.pubkey()?,
+            underlying_token_mint: r.pubkey()?,
+            underlying_token_account: r.pubkey()?,
+            conditional_on_finalize_token_mint: r.pubkey()?,
+            conditional_on_revert_token_mint: r.pubkey()?,
+            pda_bump: r.u8()?,
+            decimals: r.u8()?,
+        })
+    }
+}
+
+/// Lagging TWAP oracle embedded in every MetaDAO AMM
+#[derive(Clone, Debug)]
+pub struct TwapOracle {
+    pub last_updated_slot: u64,
+    pub last_price: u128,
+    pub last_observation: u128,
+    pub aggregator: u128,
+    pub max_observation_change_per_update: u128,
+    pub initial_observation: u128,
+    /// Slots after AMM creation before observations count (0 on layouts without it)
+    pub start_delay_slots: u64,
+}
+
+/// amm `Amm` account
+#[derive(Clone, Debug)]
+pub struct Amm {
+    pub bump: u8,
+    pub created_at_slot: u64,
+    pub lp_mint: Pubkey,
+    pub base_mint: Pubkey,
+    pub quote_mint: Pubkey,
+    pub base_mint_decimals: u8,
+    pub quote_mint_decimals: u8,
+    pub base_amount: u64,
+    pub quote_amount: u64,
+    pub oracle: TwapOracle,
+}
+
+impl Amm {
+    pub fn decode(data: &[u8]) -> Result<Self, String> {
+        let mut r = BorshReader::anchor_account(data, "Amm")?;
+        let bump = r.u8()?;
+        let created_at_slot = r.u64()?;
+        let lp_mint = r.pubkey()?;
+        let base_mint = r.pubkey()?;
+        let quote_mint = r.pubkey()?;
+        let base_mint_decimals = r.u8()?;
+        let quote_mint_decimals = r.u8()?;
+        let base_amount = r.u64()?;
+        let quote_amount = r.u64()?;
+        let last_updated_slot = r.u64()?;
+        let last_price = r.u128()?;
+        let last_observation = r.u128()?;
+        let aggregator = r.u128()?;
+        let max_observation_change_per_update = r.u128()?;
+        let initial_observation = r.u128()?;
+        let start_delay_slots = if r.remaining() >= 8 { r.u64()? } else { 0 };
+
+        Ok(Amm {
+            bump,
+            created_at_slot,
+            lp_mint,
+            base_mint,
+            quote_mint,
+            base_mint_decimals,
+            quote_mint_decimals,
+            base_amount,
+            quote_amount,
+            oracle: TwapOracle {
+                last_updated_slot,
+                last_price,
+                last_observation,
+                aggregator,
+                max_observation_change_per_update,
+                initial_observation,
+                start_delay_slots,
+            },
+        })
+    }
+
+    /// Spot price in AMM units (quote per base × 1e12)
+    pub fn spot_price_raw(&self) -> Option<u128> {
+        if self.base_amount == 0 {
+            return None;
+        }
+        Some(self.quote_amount as u128 * AMM_PRICE_SCALE / self.base_amount as u128)
+    }
+
+    /// On-chain `get_twap`: aggregator over slots since creation + start delay
+    pub fn twap_raw(&self) -> Option<u128> {
+        let start_slot = self.created_at_slot + self.oracle.start_delay_slots;
+        if self.oracle.last_updated_slot <= start_slot {
+            return None;
+        }
+        Some(self.oracle.aggregator / (self.oracle.last_updated_slot - start_slot) as u128)
+    }
+
+    /// Convert an AMM-unit price into whole quote tokens per whole base token
+    pub fn to_ui_price(&self, raw: u128) -> f64 {
+        let decimals = self.base_mint_decimals as i32 - self.quote_mint_decimals as i32;
+        raw as f64 / AMM_PRICE_SCALE as f64 * 10f64.powi(decimals)
+    }
+
+    pub fn spot_price(&self) -> Option<f64> {
+        self.spot_price_raw().map(|p| self.to_ui_price(p))
+    }
+
+    pub fn twap(&self) -> Option<f64> {
+        self.twap_raw().map(|p| self.to_ui_price(p))
+    }
+}
+
+#[derive(Serialize)]
+struct RpcRequest<'a> {
+    jsonrpc: &'static str,
+    id: u64,
+    method: &'static str,
+    params: (&'a str, AccountInfoConfig),
+}
+
+#[derive(Serialize)]
+struct AccountInfoConfig {
+    encoding: &'static str,
+    commitment: &'static str,
+}
+
+#[derive(Deserialize)]
+struct RpcResponse {
+    result: Option<RpcResult>,
+    error: Option<RpcError>,
+}
+
+#[derive(Deserialize)]
+struct RpcError {
+    code: i64,
+    message: String,
+}
+
+#[derive(Deserialize)]
+struct RpcResult {
+    context: RpcContext,
+    value: Option<RpcAccount>,
+}
+
+#[derive(Deserialize)]
+struct RpcContext {
+    slot: u64,
+}
+
+#[derive(Deserialize)]
+struct RpcAccount {
+    data: (String, String),
+    owner: String,
+}
+
+/// Raw account bytes with the slot they were read at
+#[derive(Clone, Debug)]
+pub struct AccountSnapshot {
+    pub slot: u64,
+    pub owner: String,
+    pub data: Vec<u8>,
+}
+
+impl AccountSnapshot {
+    /// Account data, provided `program` owns the account
+    pub fn owned_by(&self, program: &str) -> Result<&[u8], String> {
+        if self.owner != program {
+            return Err(format!("Account owned by {}, expected {}", self.owner, program));
+        }
+        Ok(&self.data)
+    }
+}
+
+/// Program ids whose accounts the oracle will decode
+#[derive(Clone, Debug)]
+pub struct MetaDaoPrograms {
+    pub autocrat: String,
+    pub amm: String,
+    pub conditional_vault: String,
+}
+
+impl Default for MetaDaoPrograms {
+    fn default() -> Self {
+        Self {
+            autocrat: AUTOCRAT_PROGRAM.to_string(),
+            amm: AMM_PROGRAM.to_string(),
+            conditional_vault: CONDITIONAL_VAULT_PROGRAM.to_string(),
+        }
+    }
+}
+
+/// Pass/fail market view of one MetaDAO proposal
+#[derive(Clone, Debug)]
+pub struct MetaDAOBelief {
+    pub proposal: Proposal,
+    pub pass_amm: Amm,
+    pub fail_amm: Amm,
+    pub base_vault: ConditionalVault,
+    pub quote_vault: ConditionalVault,
+    pub slot: u64,
+    pub pass_price: Option<f64>,
+    pub fail_price: Option<f64>,
+    pub pass_twap: Option<f64>,
+    pub fail_twap: Option<f64>,
+    pub mercy_verdict: Verdict,
+}
+
+pub struct MetaDAOOracle {
+    client: Client,
+    truth: SharedTruth,
+    rpc_url: String,
+    programs: MetaDaoPrograms,
+}
+
+impl MetaDAOOracle {
+    pub fn new(truth: SharedTruth) -> Self {
+        Self::with_endpoint(SOLANA_RPC, truth)
+    }
+
+    /// Point the oracle at another RPC node (e.g. a local stand-in serving captured accounts)
+    pub fn with_endpoint(rpc_url: &str, truth: SharedTruth) -> Self {
+        MetaDAOOracle {
+            client: Client::new(),
+            truth,
+            rpc_url: rpc_url.to_string(),
+            programs: MetaDaoPrograms::default(),
+        }
+    }
+
+    /// Decode accounts owned by another deployment's programs
+    pub fn with_programs(mut self, programs: MetaDaoPrograms) -> Self {
+        self.programs = programs;
+        self
+    }
+
+    /// `getAccountInfo` with base64 encoding
+    pub async fn fetch_account(&self, address: &str) -> Result<AccountSnapshot, String> {
+        let request = RpcRequest {
+            jsonrpc: "2.0",
+            id: 1,
+            method: "getAccountInfo",
+            params: (address, AccountInfoConfig { encoding: "base64", commitment: "confirmed" }),
+        };
+        let resp = self.client.post(&self.rpc_url)
+            .json(&request)
+            .send()
+            .await
+            .map_err(|e| format!("RPC error: {:?}", e))?
+            .json::<RpcResponse>()
+            .await
+            .map_err(|e| format!("Parse error: {:?}", e))?;
+
+        if let Some(err) = resp.error {
+            return Err(format!("RPC error {}: {}", err.code, err.message));
+        }
+        let result = resp.result.ok_or_else(|| "RPC returned no result".to_string())?;
+        let account = result.value.ok_or_else(|| format!("Account {} not found", address))?;
+        if account.data.1 != "base64" {
+            return Err(format!("Unexpected account encoding: {}", account.data.1));
+        }
+        let data = BASE64.decode(&account.data.0)
+            .map_err(|e| format!("Base64 error: {:?}", e))?;
+
+        Ok(AccountSnapshot { slot: result.context.slot, owner: account.owner, data })
+    }
+
+    pub async fn fetch_proposal(&self, address: &str) -> Result<Proposal, String> {
+        Proposal::decode(self.fetch_account(address).await?.owned_by(&self.programs.autocrat)?)
+    }
+
+    pub async fn fetch_amm(&self, address: &str) -> Result<Amm, String> {
+        Amm::decode(self.fetch_account(address).await?.owned_by(&self.programs.amm)?)
+    }
+
+    pub async fn fetch_dao(&self, address: &str) -> Result<Dao, String> {
+        Dao::decode(self.fetch_account(address).await?.owned_by(&self.programs.autocrat)?)
+    }
+
+    pub async fn fetch_vault(&self, address: &str) -> Result<ConditionalVault, String> {
+        ConditionalVault::decode(self.fetch_account(address).await?.owned_by(&self.programs.conditional_vault)?)
+    }
+
+    /// MetaDAO market belief for a proposal account: pass/fail spot prices and TWAPs
+    pub async fn aggregate_meta_dao_belief(&self, proposal: &str) -> Result<MetaDAOBelief, String> {
+        let snapshot = self.fetch_account(proposal).await?;
+        let decoded = Proposal::decode(snapshot.owned_by(&self.programs.autocrat)?)?;
+
+        let [pass_key, fail_key, base_key, quote_key] =
+            [&decoded.pass_amm, &decoded.fail_amm, &decoded.base_vault, &decoded.quote_vault].map(pubkey_to_string);
+        let (pass_amm, fail_amm, base_vault, quote_vault) = tokio::try_join!(
+            self.fetch_amm(&pass_key),
+            self.fetch_amm(&fail_key),
+            self.fetch_vault(&base_key),
+            self.fetch_vault(&quote_key),
+        )?;
+
+        Ok(MetaDAOBelief {
+            mercy_verdict: self.truth.evaluate(&decoded.description_url),
+            slot: snapshot.slot,
+            pass_price: pass_amm.spot_price(),
+            fail_price: fail_amm.spot_price(),
+            pass_twap: pass_amm.twap(),
+            fail_twap: fail_amm.twap(),
+            proposal: decoded,
+            pass_amm,
+            fail_amm,
+            base_vault,
+            quote_vault,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::*;
+    use crate::oracles::stub_server::StubServer;
+    use crate::truth::LocalTruth;
+
+    const PROPOSAL_KEY: Pubkey = [1; 32];
+    const PASS_AMM_KEY: Pubkey = [2; 32];
+    const FAIL_AMM_KEY: Pubkey = [3; 32];
+    const BASE_VAULT_KEY: Pubkey = [4; 32];
+    const QUOTE_VAULT_KEY: Pubkey = [5; 32];
+
+    /// Anchor account dump: discriminator followed by Borsh fields
+    struct Dump(Vec<u8>);
+
+    impl Dump {
+        fn new(name: &str) -> Self {
+            Dump(account_discriminator(name).to_vec())
+        }
+
+        fn put(mut self, bytes: &[u8]) -> Self {
+            self.0.extend_from_slice(bytes);
+            self
+        }
+    }
+
+    fn proposal_dump(account_count: u32, accounts: usize) -> Vec<u8> {
+        let url = b"https://metadao.fi/proposals/fund-public-goods";
+        let mut dump = Dump::new("Proposal")
+            .put(&7u32.to_le_bytes())
+            .put(&[9; 32])
+            .put(&(url.len() as u32).to_le_bytes())
+            .put(url)
+            .put(&250_000_000u64.to_le_bytes())
+            .put(&[0])
+            .put(&[8; 32])
+            .put(&account_count.to_le_bytes());
+        for _ in 0..accounts {
+            dump = dump.put(&[6; 32]).put(&[1, 0]);
+        }
+        dump.put(&3u32.to_le_bytes())
+            .put(&[1, 2, 3])
+            .put(&PASS_AMM_KEY)
+            .put(&FAIL_AMM_KEY)
+            .put(&BASE_VAULT_KEY)
+            .put(&QUOTE_VAULT_KEY)
+            .put(&[7; 32])
+            .put(&1_000u64.to_le_bytes())
+            .put(&2_000u64.to_le_bytes())
+            .put(&42u64.to_le_bytes())
+            .put(&[254])
+            .0
+    }
+
+    /// AMM with 9-decimal base, 6-decimal quote and reserves pricing base at `quote / base`
+    fn amm_dump(base_amount: u64, quote_amount: u64, aggregator: u128) -> Vec<u8> {
+        Dump::new("Amm")
+            .put(&[255])
+            .put(&1_000u64.to_le_bytes())
+            .put(&[10; 32])
+            .put(&[11; 32])
+            .put(&[12; 32])
+            .put(&[9, 6])
+            .put(&base_amount.to_le_bytes())
+            .put(&quote_amount.to_le_bytes())
+            .put(&1_100u64.to_le_bytes())
+            .put(&0u128.to_le_bytes())
+            .put(&0u128.to_le_bytes())
+            .put(&aggregator.to_le_bytes())
+            .put(&0u128.to_le_bytes())
+            .put(&0u128.to_le_bytes())
+            .put(&0u64.to_le_bytes())
+            .0
+    }
+
+    fn vault_dump(status: u8) -> Vec<u8> {
+        Dump::new("ConditionalVault")
+            .put(&[status])
+            .put(&[13; 32])
+            .put(&[14; 32])
+            .put(&[15; 32])
+            .put(&[16; 32])
+            .put(&[17; 32])
+            .put(&[253, 6])
+            .0
+    }
+
+    /// Local `getAccountInfo` stand-in serving the dumps above
+    fn rpc() -> StubServer {
+        rpc_with_owners(AUTOCRAT_PROGRAM, AMM_PROGRAM)
+    }
+
+    fn rpc_with_owners(proposal_owner: &'static str, amm_owner: &'static str) -> StubServer {
+        let accounts: Vec<(String, &str, Vec<u8>)> = vec![
+            (pubkey_to_string(&PROPOSAL_KEY), proposal_owner, proposal_dump(2, 2)),
+            // 1 base = 0.5 quote in UI units → 5e8 in AMM units (quote per base × 1e12, raw decimals)
+            (pubkey_to_string(&PASS_AMM_KEY), amm_owner, amm_dump(2_000_000_000_000, 1_000_000_000, 50_000_000_000)),
+            (pubkey_to_string(&FAIL_AMM_KEY), amm_owner, amm_dump(4_000_000_000_000, 1_000_000_000, 25_000_000_000)),
+            (pubkey_to_string(&BASE_VAULT_KEY), CONDITIONAL_VAULT_PROGRAM, vault_dump(0)),
+            (pubkey_to_string(&QUOTE_VAULT_KEY), CONDITIONAL_VAULT_PROGRAM, vault_dump(0)),
+        ];
+        StubServer::start(move |_, request: &Value| {
+            let address = request["params"][0].as_str().unwrap_or_default();
+            let value = accounts.iter().find(|(key, _, _)| key == address).map(|(_, owner, data)| json!({
+                "data": [BASE64.encode(data), "base64"],
+                "owner": owner,
+                "lamports": 1_000_000,
+                "executable": false,
+                "rentEpoch": 0,
+            }));
+            json!({ "jsonrpc": "2.0", "id": 1, "result": { "context": { "slot": 250_000_123 }, "value": value } })
+        })
+    }
+
+    #[test]
+    fn proposal_decodes_instruction_accounts() {
+        let proposal = Proposal::decode(&proposal_dump(2, 2)).unwrap();
+        assert_eq!(proposal.number, 7);
+        assert_eq!(proposal.instruction.accounts.len(), 2);
+        assert!(proposal.instruction.accounts[0].is_signer);
+        assert_eq!(proposal.instruction.data, vec![1, 2, 3]);
+        assert_eq!(proposal.pass_amm, PASS_AMM_KEY);
+        assert_eq!(proposal.pda_bump, 254);
+    }
+
+    #[test]
+    fn discriminator_must_match_the_account_type() {
+        // sha256("account:Proposal")[..8]
+        assert_eq!(account_discriminator("Proposal"), [26, 94, 189, 187, 116, 136, 53, 33]);
+        let err = Amm::decode(&proposal_dump(2, 2)).unwrap_err();
+        assert_eq!(err, "Account is not a Amm (discriminator mismatch)");
+        // A vault dump padded to proposal length still fails on its discriminator
+        let mut vault = vault_dump(0);
+        vault.resize(proposal_dump(2, 2).len(), 0);
+        assert!(Proposal::decode(&vault).unwrap_err().contains("discriminator mismatch"));
+        assert!(Proposal::decode(&[0; 4]).is_err());
+    }
+
+    #[tokio::test]
+    async fn accounts_owned_by_other_programs_are_rejected() {
+        let impostor = "11111111111111111111111111111111";
+        let server = rpc_with_owners(impostor, AMM_PROGRAM);
+        let oracle = MetaDAOOracle::with_endpoint(&server.url, LocalTruth::shared());
+        let err = oracle.aggregate_meta_dao_belief(&pubkey_to_string(&PROPOSAL_KEY)).await.unwrap_err();
+        assert_eq!(err, format!("Account owned by {}, expected {}", impostor, AUTOCRAT_PROGRAM));
+
+        let server = rpc_with_owners(AUTOCRAT_PROGRAM, AUTOCRAT_PROGRAM);
+        let oracle = MetaDAOOracle::with_endpoint(&server.url, LocalTruth::shared());
+        let err = oracle.fetch_amm(&pubkey_to_string(&PASS_AMM_KEY)).await.unwrap_err();
+        assert!(err.starts_with(&format!("Account owned by {}", AUTOCRAT_PROGRAM)), "{}", err);
+
+        // Another deployment is accepted once its programs are configured
+        let programs = MetaDaoPrograms { amm: AUTOCRAT_PROGRAM.to_string(), ..MetaDaoPrograms::default() };
+        let oracle = oracle.with_programs(programs);
+        assert!(oracle.fetch_amm(&pubkey_to_string(&PASS_AMM_KEY)).await.is_ok());
+    }
+
+    #[test]
+    fn oversized_account_count_is_rejected() {
+        let err = Proposal::decode(&proposal_dump(u32::MAX, 0)).unwrap_err();
+        assert!(err.contains("4294967295 accounts"), "{}", err);
+        assert!(Proposal::decode(&proposal_dump(3, 2)).is_err());
+    }
+
+    #[tokio::test]
+    async fn belief_from_local_rpc() {
+        let server = rpc();
+        let oracle = MetaDAOOracle::with_endpoint(&server.url, LocalTruth::shared());
+        let belief = oracle.aggregate_meta_dao_belief(&pubkey_to_string(&PROPOSAL_KEY)).await.unwrap();
+
+        assert_eq!(server.requests().len(), 5);
+        assert_eq!(belief.slot, 250_000_123);
+        assert_eq!(belief.proposal.state, ProposalState::Pending);
+        assert_eq!(belief.base_vault.status, VaultStatus::Active);
+        assert_eq!(belief.pass_amm.spot_price_raw(), Some(500_000_000));
+        assert!((belief.pass_price.unwrap() - 0.5).abs() < 1e-12);
+        assert!((belief.fail_price.unwrap() - 0.25).abs() < 1e-12);
+        // aggregator / 100 slots since creation
+        assert!((belief.pass_twap.unwrap() - 0.5).abs() < 1e-12);
+        assert!((belief.fail_twap.unwrap() - 0.25).abs() < 1e-12);
+    }
+
+    #[tokio::test]
+    async fn missing_account_and_rpc_errors_surface() {
+        let server = rpc();
+        let oracle = MetaDAOOracle::with_endpoint(&server.url, LocalTruth::shared());
+        let err = oracle.fetch_amm(&pubkey_to_string(&[99; 32])).await.unwrap_err();
+        assert!(err.contains("not found"), "{}", err);
+
+        let failing = StubServer::start(|_, _| json!({ "jsonrpc": "2.0", "id": 1, "error": { "code": -32005, "message": "Node is behind" } }));
+        let oracle = MetaDAOOracle::with_endpoint(&failing.url, LocalTruth::shared());
+        let err = oracle.fetch_account(&pubkey_to_string(&PROPOSAL_KEY)).await.unwrap_err();
+        assert_eq!(err, "RPC error -32005: Node is behind");
+    }
+}
@@ -0,0 +1,333 @@
+//! MetaDAO Outcome Predictor — Mid-Trading Replay of the On-Chain TWAP Pass Rule
+//! Ultramasterful foresight for Solana futarchy decisions
+//!
+//! Replays `amm::update_twap` (lagging observation capped at `max_observation_change_per_update`
+//! per update, start delay) forward to the proposal's end slot, then applies autocrat's
+//! `finalize_proposal` rule: pass iff pass TWAP > fail TWAP × (10 000 + threshold bps) / 10 000.
+
+use crate::oracles::meta_dao_oracle::{pubkey_to_string, Amm, Dao, MetaDAOBelief, MetaDAOOracle, ProposalState, AMM_PRICE_SCALE};
+
+pub const MAX_BPS: u128 = 10_000;
+
+/// Which conditional market a flip would be executed in
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MarketSide {
+    Pass,
+    Fail,
+}
+
+/// Token a manipulator must put into the AMM
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InputToken {
+    Base,
+    Quote,
+}
+
+/// Cheapest way found to flip the projected decision
+#[derive(Clone, Debug)]
+pub struct FlipCost {
+    pub market: MarketSide,
+    /// Spot price (AMM units) that must be held from now until the end slot
+    pub target_price_raw: u128,
+    pub input_token: InputToken,
+    /// Raw token amount swapped in, fee included
+    pub input_amount: u64,
+    /// Input valued in raw quote units at the current spot price
+    pub quote_value: u64,
+}
+
+#[derive(Clone, Debug)]
+pub struct DecisionProjection {
+    pub current_slot: u64,
+    pub end_slot: u64,
+    pub pass_twap_now: Option<u128>,
+    pub fail_twap_now: Option<u128>,
+    pub projected_pass_twap: u128,
+    pub projected_fail_twap: u128,
+    pub threshold: u128,
+    pub projected_state: ProposalState,
+    /// Projected pass TWAP relative to the threshold, in bps (negative = failing)
+    pub margin_bps: i64,
+    pub capital_to_flip: Option<FlipCost>,
+}
+
+pub struct MetaDAOPredictor {
+    /// Slots between TWAP cranks assumed for the remaining window (1 = cranked every slot)
+    pub update_interval_slots: u64,
+    /// AMM swap fee in bps
+    pub amm_fee_bps: u16,
+}
+
+impl Default for MetaDAOPredictor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MetaDAOPredictor {
+    pub fn new() -> Self {
+        MetaDAOPredictor {
+            update_interval_slots: 1,
+            amm_fee_bps: 100,
+        }
+    }
+
+    /// Fetch live proposal/DAO state and project the decision at the RPC's current slot
+    pub async fn fetch_and_predict(&self, oracle: &MetaDAOOracle, proposal: &str) -> Result<DecisionProjection, String> {
+        let belief = oracle.aggregate_meta_dao_belief(proposal).await?;
+        let dao = oracle.fetch_dao(&pubkey_to_string(&belief.proposal.dao)).await?;
+        self.predict(&belief, &dao, belief.slot)
+    }
+
+    /// Project the decision assuming both markets hold their current spot prices until the end slot
+    pub fn predict(&self, belief: &MetaDAOBelief, dao: &Dao, current_slot: u64) -> Result<DecisionProjection, String> {
+        let end_slot = belief.proposal.slot_enqueued + dao.slots_per_proposal;
+        let pass_spot = belief.pass_amm.spot_price_raw().ok_or("Pass AMM has no base liquidity")?;
+        let fail_spot = belief.fail_amm.spot_price_raw().ok_or("Fail AMM has no base liquidity")?;
+
+        let project = |amm: &Amm, price: u128| self.project_twap(amm, current_slot, end_slot, price);
+        let projected_pass_twap = project(&belief.pass_amm, pass_spot).ok_or("Pass TWAP window has not started")?;
+        let projected_fail_twap = project(&belief.fail_amm, fail_spot).ok_or("Fail TWAP window has not started")?;
+
+        let threshold = pass_threshold(projected_fail_twap, dao.pass_threshold_bps);
+        let passes = projected_pass_twap > threshold;
+        let margin_bps = if threshold == 0 {
+            0
+        } else {
+            ((projected_pass_twap as f64 / threshold as f64 - 1.0) * MAX_BPS as f64) as i64
+        };
+
+        // Flipping means moving one market while the other holds its current spot
+        let flips_via_pass = |price: u128| {
+            project(&belief.pass_amm, price)
+                .map(|twap| (twap > threshold) != passes)
+                .unwrap_or(false)
+        };
+        let flips_via_fail = |price: u128| {
+            project(&belief.fail_amm, price)
+                .map(|twap| (projected_pass_twap > pass_threshold(twap, dao.pass_threshold_bps)) != passes)
+                .unwrap_or(false)
+        };
+
+        // Passing → push pass down or fail up; failing → push pass up or fail down
+        let candidates = [
+            self.flip_cost(MarketSide::Pass, &belief.pass_amm, current_slot, end_slot, !passes, flips_via_pass),
+            self.flip_cost(MarketSide::Fail, &belief.fail_amm, current_slot, end_slot, passes, flips_via_fail),
+        ];
+        let capital_to_flip = candidates.into_iter().flatten().min_by_key(|cost| cost.quote_value);
+
+        Ok(DecisionProjection {
+            current_slot,
+            end_slot,
+            pass_twap_now: belief.pass_amm.twap_raw(),
+            fail_twap_now: belief.fail_amm.twap_raw(),
+            projected_pass_twap,
+            projected_fail_twap,
+            threshold,
+            projected_state: if passes { ProposalState::Passed } else { ProposalState::Failed },
+            margin_bps,
+            capital_to_flip,
+        })
+    }
+
+    /// TWAP at `end_slot` if spot is held at `price` from `current_slot` (None before the start delay elapses)
+    pub fn project_twap(&self, amm: &Amm, current_slot: u64, end_slot: u64, price: u128) -> Option<u128> {
+        let start_slot = amm.created_at_slot + amm.oracle.start_delay_slots;
+        let max_change = amm.oracle.max_observation_change_per_update;
+        let interval = self.update_interval_slots.max(1);
+
+        let mut last_updated = amm.oracle.last_updated_slot.max(start_slot);
+        let mut observation = amm.oracle.last_observation;
+        let mut aggregator = amm.oracle.aggregator;
+
+        let mut slot = current_slot.max(last_updated + 1);
+        while slot <= end_slot {
+            observation = if price > observation {
+                price.min(observation.saturating_add(max_change))
+            } else {
+                price.max(observation.saturating_sub(max_change))
+            };
+            aggregator = aggregator.wrapping_add(observation * (slot - last_updated) as u128);
+            last_updated = slot;
+            slot = if slot == end_slot { slot + 1 } else { (slot + interval).min(end_slot) };
+        }
+
+        if last_updated <= start_slot {
+            return None;
+        }
+        Some(aggregator / (last_updated - start_slot) as u128)
+    }
+
+    /// Smallest price move in `side`'s AMM that flips the decision, costed against its reserves
+    fn flip_cost<F: Fn(u128) -> bool>(
+        &self,
+        side: MarketSide,
+        amm: &Amm,
+        current_slot: u64,
+        end_slot: u64,
+        raise: bool,
+        flips: F,
+    ) -> Option<FlipCost> {
+        let spot = amm.spot_price_raw()?;
+        let target = if raise {
+            // Beyond this the lagging observation saturates, so higher prices cannot help
+            let updates = (end_slot.saturating_sub(current_slot) / self.update_interval_slots.max(1) + 1) as u128;
+            let ceiling = spot
+                .max(amm.oracle.last_observation)
+                .saturating_add(amm.oracle.max_observation_change_per_update.saturating_mul(updates));
+            if !flips(ceiling) {
+                return None;
+            }
+            let (mut lo, mut hi) = (spot, ceiling);
+            while hi - lo > 1 {
+                let mid = lo + (hi - lo) / 2;
+                if flips(mid) { hi = mid } else { lo = mid }
+            }
+            hi
+        } else {
+            if !flips(1) {
+                return None;
+            }
+            let (mut lo, mut hi) = (1, spot);
+            while hi - lo > 1 {
+                let mid = lo + (hi - lo) / 2;
+                if flips(mid) { lo = mid } else { hi = mid }
+            }
+            lo
+        };
+        Some(self.swap_cost(side, amm, spot, target))
+    }
+
+    /// Constant-product input needed to move spot from `spot` to `target`
+    fn swap_cost(&self, side: MarketSide, amm: &Amm, spot: u128, target: u128) -> FlipCost {
+        let base = amm.base_amount as f64;
+        let quote = amm.quote_amount as f64;
+        let k = base * quote;
+        let target_ratio = target as f64 / AMM_PRICE_SCALE as f64;
+        let fee_factor = 1.0 - self.amm_fee_bps as f64 / MAX_BPS as f64;
+
+        let (input_token, input_amount) = if target > spot {
+            (InputToken::Quote, ((k * target_ratio).sqrt() - quote).max(0.0) / fee_factor)
+        } else {
+            (InputToken::Base, ((k / target_ratio).sqrt() - base).max(0.0) / fee_factor)
+        };
+        let quote_value = match input_token {
+            InputToken::Quote => input_amount,
+            InputToken::Base => input_amount * spot as f64 / AMM_PRICE_SCALE as f64,
+        };
+
+        FlipCost {
+            market: side,
+            target_price_raw: target,
+            input_token,
+            input_amount: input_amount.ceil() as u64,
+            quote_value: quote_value.ceil() as u64,
+        }
+    }
+}
+
+/// autocrat threshold: fail TWAP scaled up by the DAO's pass threshold
+pub fn pass_threshold(fail_twap: u128, pass_threshold_bps: u16) -> u128 {
+    fail_twap * (MAX_BPS + pass_threshold_bps as u128) / MAX_BPS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::oracles::meta_dao_oracle::TwapOracle;
+
+    /// AMM created at slot 100 with a 10-slot start delay, cranked through slot 120 at 1 000
+    fn amm(base_amount: u64, quote_amount: u64, last_observation: u128, max_change: u128) -> Amm {
+        Amm {
+            bump: 255,
+            created_at_slot: 100,
+            lp_mint: [0; 32],
+            base_mint: [1; 32],
+            quote_mint: [2; 32],
+            base_mint_decimals: 9,
+            quote_mint_decimals: 6,
+            base_amount,
+            quote_amount,
+            oracle: TwapOracle {
+                last_updated_slot: 120,
+                last_price: last_observation,
+                last_observation,
+                aggregator: 1_000 * 10,
+                max_observation_change_per_update: max_change,
+                initial_observation: 1_000,
+                start_delay_slots: 10,
+            },
+        }
+    }
+
+    #[test]
+    fn projected_twap_ramps_toward_far_prices() {
+        let predictor = MetaDAOPredictor::new();
+        let amm = amm(1, 1, 1_000, 100);
+        // Observation climbs 1 100, 1 200, … 1 500 over slots 121–125: (10 000 + 6 500) / 15
+        assert_eq!(predictor.project_twap(&amm, 121, 125, 10_000), Some(1_100));
+        // Falls 900 … 500: (10 000 + 3 500) / 15
+        assert_eq!(predictor.project_twap(&amm, 121, 125, 0), Some(900));
+        // A price within one step is reached immediately: (10 000 + 5 × 1 050) / 15
+        assert_eq!(predictor.project_twap(&amm, 121, 125, 1_050), Some(1_016));
+        // Already at the end slot: nothing left to replay
+        assert_eq!(predictor.project_twap(&amm, 130, 120, 10_000), Some(1_000));
+
+        // Cranking every other slot halves how far the observation can move
+        let sparse = MetaDAOPredictor { update_interval_slots: 2, ..MetaDAOPredictor::new() };
+        // Slots 121 (+1 100), 123 (+2 × 1 200), 125 (+2 × 1 300): 16 100 / 15
+        assert_eq!(sparse.project_twap(&amm, 121, 125, 10_000), Some(1_073));
+    }
+
+    #[test]
+    fn projected_twap_is_none_before_start_slot() {
+        let predictor = MetaDAOPredictor::new();
+        let mut fresh = amm(1, 1, 1_000, 100);
+        fresh.oracle.last_updated_slot = 0;
+        fresh.oracle.aggregator = 0;
+        // Window opens at slot 110; ending there leaves no slots to average over
+        assert_eq!(predictor.project_twap(&fresh, 105, 110, 1_000), None);
+        assert_eq!(predictor.project_twap(&fresh, 105, 112, 1_000), Some(1_000));
+    }
+
+    #[test]
+    fn pass_threshold_scales_fail_twap() {
+        assert_eq!(pass_threshold(1_000, 0), 1_000);
+        assert_eq!(pass_threshold(1_000, 300), 1_030);
+        // 999 × 1.03 = 1 028.97, floored like autocrat's integer math
+        assert_eq!(pass_threshold(999, 300), 1_028);
+    }
+
+    #[test]
+    fn flip_cost_bisects_to_the_smallest_flipping_price() {
+        let predictor = MetaDAOPredictor::new();
+        // 1 000 base against 500 quote (raw): spot 0.5 × 1e12
+        let pool = amm(1_000_000_000, 500_000_000, 500_000_000_000, 1_000_000_000);
+        let spot = pool.spot_price_raw().unwrap();
+        assert_eq!(spot, 500_000_000_000);
+
+        let up = predictor
+            .flip_cost(MarketSide::Pass, &pool, 120, 125, true, |price| price >= spot + 12_345)
+            .unwrap();
+        assert_eq!(up.market, MarketSide::Pass);
+        assert_eq!(up.target_price_raw, spot + 12_345);
+        assert_eq!(up.input_token, InputToken::Quote);
+        assert_eq!(up.quote_value, up.input_amount);
+
+        let down = predictor
+            .flip_cost(MarketSide::Fail, &pool, 120, 125, false, |price| price <= spot / 4)
+            .unwrap();
+        assert_eq!(down.target_price_raw, spot / 4);
+        assert_eq!(down.input_token, InputToken::Base);
+        // √(k / 0.125) − base = 1 000 000 000 more base, grossed up for the 1% fee
+        let expected = 1_000_000_000.0 / 0.99;
+        assert!((down.input_amount as f64 - expected).abs() <= 1.0, "{}", down.input_amount);
+        assert!((down.quote_value as f64 - expected / 2.0).abs() <= 1.0, "{}", down.quote_value);
+
+        // Six updates of 1e9 cap how far the observation can be pushed
+        let ceiling = spot + 6 * 1_000_000_000;
+        assert!(predictor.flip_cost(MarketSide::Pass, &pool, 120, 125, true, |price| price > ceiling).is_none());
+        assert!(predictor.flip_cost(MarketSide::Pass, &pool, 120, 125, true, |price| price >= ceiling).is_some());
+        assert!(predictor.flip_cost(MarketSide::Fail, &pool, 120, 125, false, |_| false).is_none());
+    }
+}
@@ -0,0 +1,23 @@
+//! Oracles — Live and Historical Belief Sources
+//! Ultramasterful venues feeding futarchy decisions (live venues behind cargo features)
+
+pub mod augur_history;
+pub mod augur_oracle;
+pub mod odds_history;
+pub mod oracle_feed;
+
+#[cfg(feature = "gnosis")]
+pub mod ctf_ids;
+#[cfg(feature = "gnosis")]
+pub mod gnosis_bridge;
+#[cfg(feature = "metadao")]
+pub mod meta_dao_oracle;
+#[cfg(feature = "metadao")]
+pub mod meta_dao_predictor;
+#[cfg(feature = "omen")]
+pub mod omen_oracle;
+#[cfg(feature = "polymarket")]
+pub mod polymarket_oracle;
+
+#[cfg(all(test, any(feature = "metadao", feature = "omen", feature = "polymarket")))]
+mod stub_server;
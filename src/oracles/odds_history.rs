@@ -0,0 +1,464 @@
+//! Odds History — Time-Series Capture, Storage and Resampling for Every Oracle
+//! Ultramasterful memory: decision rules are backtested against what the markets really said
+//!
+//! Readings are appended as JSON lines under `<dir>/<source>/<subject>.jsonl`, one file per
+//! series, and indexed in memory on open. Timestamps are Unix milliseconds.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::oracles::oracle_feed::{BeliefSource, OracleReading, OracleSource};
+
+pub fn to_millis(t: SystemTime) -> u64 {
+    t.duration_since(UNIX_EPOCH).unwrap_or_default().as_millis() as u64
+}
+
+/// One stored reading
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct OddsPoint {
+    pub source: OracleSource,
+    pub subject: String,
+    pub value: f64,
+    /// When the venue's data was last fresh
+    pub observed_at: u64,
+    /// When the poller captured it
+    pub recorded_at: u64,
+}
+
+impl OddsPoint {
+    pub fn from_reading(reading: &OracleReading, recorded_at: SystemTime) -> Self {
+        Self {
+            source: reading.source,
+            subject: reading.subject.clone(),
+            value: reading.value,
+            observed_at: to_millis(reading.observed_at),
+            recorded_at: to_millis(recorded_at),
+        }
+    }
+}
+
+/// Open/high/low/close bar over `[start, start + width)`
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct OhlcBar {
+    pub start: u64,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    pub samples: usize,
+}
+
+pub type SeriesKey = (OracleSource, String);
+
+/// Append-only odds store with an in-memory index sorted by observation time
+pub struct OddsStore {
+    dir: PathBuf,
+    series: HashMap<SeriesKey, Vec<OddsPoint>>,
+}
+
+impl OddsStore {
+    /// Open (or create) the store at `dir`, loading every series file
+    pub fn open(dir: &Path) -> Result<Self, String> {
+        fs::create_dir_all(dir).map_err(|e| format!("Create error {}: {:?}", dir.display(), e))?;
+        let mut store = Self { dir: dir.to_path_buf(), series: HashMap::new() };
+
+        for source_dir in Self::entries(dir)? {
+            if !source_dir.is_dir() {
+                continue;
+            }
+            for path in Self::entries(&source_dir)? {
+                if path.extension().and_then(|e| e.to_str()) == Some("jsonl") {
+                    store.load_file(&path)?;
+                }
+            }
+        }
+        for points in store.series.values_mut() {
+            points.sort_by_key(|p| p.observed_at);
+        }
+        Ok(store)
+    }
+
+    fn entries(dir: &Path) -> Result<Vec<PathBuf>, String> {
+        let read = fs::read_dir(dir).map_err(|e| format!("Read error {}: {:?}", dir.display(), e))?;
+        Ok(read.filter_map(|entry| entry.ok().map(|e| e.path())).collect())
+    }
+
+    fn load_file(&mut self, path: &Path) -> Result<(), String> {
+        let mut raw = fs::read(path).map_err(|e| format!("Read error {}: {:?}", path.display(), e))?;
+
+        // A torn final line from an interrupted write is cut back to the last newline,
+        // so the next append starts on a fresh line instead of extending the fragment
+        if !raw.is_empty() && !raw.ends_with(b"\n") {
+            let keep = raw.iter().rposition(|&b| b == b'\n').map_or(0, |i| i + 1);
+            log::warn!("Truncating torn line in {} ({} bytes)", path.display(), raw.len() - keep);
+            let file = OpenOptions::new().write(true).open(path)
+                .map_err(|e| format!("Write error {}: {:?}", path.display(), e))?;
+            file.set_len(keep as u64).map_err(|e| format!("Write error {}: {:?}", path.display(), e))?;
+            raw.truncate(keep);
+        }
+
+        for (n, line) in String::from_utf8_lossy(&raw).lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            match serde_json::from_str::<OddsPoint>(line) {
+                Ok(point) => self.series.entry((point.source, point.subject.clone())).or_default().push(point),
+                Err(e) => log::warn!("Skipping {}:{}: {:?}", path.display(), n + 1, e),
+            }
+        }
+        Ok(())
+    }
+
+    fn series_path(&self, source: OracleSource, subject: &str) -> PathBuf {
+        let file: String = subject.chars()
+            .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' { c } else { '_' })
+            .collect();
+        self.dir.join(format!("{:?}", source).to_lowercase()).join(format!("{}.jsonl", file))
+    }
+
+    /// Persist and index one point
+    pub fn append(&mut self, point: OddsPoint) -> Result<(), String> {
+        let path = self.series_path(point.source, &point.subject);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| format!("Create error {}: {:?}", parent.display(), e))?;
+        }
+        let line = serde_json::to_string(&point).map_err(|e| format!("Serialize error: {:?}", e))?;
+        let mut file = OpenOptions::new().create(true).append(true).open(&path)
+            .map_err(|e| format!("Write error {}: {:?}", path.display(), e))?;
+        writeln!(file, "{}", line).map_err(|e| format!("Write error {}: {:?}", path.display(), e))?;
+
+        let points = self.series.entry((point.source, point.subject.clone())).or_default();
+        let at = points.partition_point(|p| p.observed_at <= point.observed_at);
+        points.insert(at, point);
+        Ok(())
+    }
+
+    pub fn record(&mut self, reading: &OracleReading) -> Result<(), String> {
+        self.append(OddsPoint::from_reading(reading, SystemTime::now()))
+    }
+
+    pub fn series_keys(&self) -> impl Iterator<Item = &SeriesKey> {
+        self.series.keys()
+    }
+
+    /// Most recently observed point of a series
+    pub fn last(&self, source: OracleSource, subject: &str) -> Option<&OddsPoint> {
+        self.series.get(&(source, subject.to_string()))?.last()
+    }
+
+    /// Points observed in `[from, to)` (Unix ms)
+    pub fn range(&self, source: OracleSource, subject: &str, from: u64, to: u64) -> &[OddsPoint] {
+        let points = match self.series.get(&(source, subject.to_string())) {
+            Some(points) => points.as_slice(),
+            None => return &[],
+        };
+        let lo = points.partition_point(|p| p.observed_at < from);
+        let hi = points.partition_point(|p| p.observed_at < to);
+        &points[lo..hi.max(lo)]
+    }
+
+    /// Last point observed at or before `at`
+    pub fn latest_at(&self, source: OracleSource, subject: &str, at: u64) -> Option<&OddsPoint> {
+        self.range(source, subject, 0, at.saturating_add(1)).last()
+    }
+
+    /// Resample `[from, to)` into OHLC bars of `width`; empty intervals produce no bar
+    pub fn resample(&self, source: OracleSource, subject: &str, from: u64, to: u64, width: Duration) -> Vec<OhlcBar> {
+        let width = (width.as_millis() as u64).max(1);
+        let mut bars: BTreeMap<u64, OhlcBar> = BTreeMap::new();
+        for point in self.range(source, subject, from, to) {
+            let start = from + (point.observed_at - from) / width * width;
+            bars.entry(start)
+                .and_modify(|bar| {
+                    bar.high = bar.high.max(point.value);
+                    bar.low = bar.low.min(point.value);
+                    bar.close = point.value;
+                    bar.samples += 1;
+                })
+                .or_insert(OhlcBar {
+                    start,
+                    open: point.value,
+                    high: point.value,
+                    low: point.value,
+                    close: point.value,
+                    samples: 1,
+                });
+        }
+        bars.into_values().collect()
+    }
+
+    /// Export raw points in `[from, to)` as CSV (or JSON when `path` ends in `.json`)
+    pub fn export(&self, source: OracleSource, subject: &str, from: u64, to: u64, path: &Path) -> Result<(), String> {
+        write_records(path, self.range(source, subject, from, to))
+    }
+
+    /// Export resampled bars as CSV (or JSON when `path` ends in `.json`)
+    pub fn export_bars(&self, source: OracleSource, subject: &str, from: u64, to: u64, width: Duration, path: &Path) -> Result<(), String> {
+        write_records(path, &self.resample(source, subject, from, to, width))
+    }
+}
+
+/// Write records as CSV or JSON (by file extension)
+fn write_records<T: Serialize>(path: &Path, records: &[T]) -> Result<(), String> {
+    if path.extension().and_then(|e| e.to_str()) == Some("json") {
+        let raw = serde_json::to_string_pretty(records).map_err(|e| format!("Serialize error: {:?}", e))?;
+        return fs::write(path, raw).map_err(|e| format!("Write error {}: {:?}", path.display(), e));
+    }
+    let mut writer = csv::Writer::from_path(path)
+        .map_err(|e| format!("CSV error in {}: {:?}", path.display(), e))?;
+    for record in records {
+        writer.serialize(record).map_err(|e| format!("CSV error in {}: {:?}", path.display(), e))?;
+    }
+    writer.flush().map_err(|e| format!("Write error {}: {:?}", path.display(), e))
+}
+
+/// One series the poller captures: `subject` as known to `source` by `venue_id`
+struct Tracked {
+    source: Arc<dyn BeliefSource>,
+    subject: String,
+    venue_id: String,
+}
+
+/// Periodically records readings from every tracked oracle into an `OddsStore`
+pub struct OddsPoller {
+    store: Arc<Mutex<OddsStore>>,
+    tracked: Vec<Tracked>,
+}
+
+impl OddsPoller {
+    pub fn new(store: Arc<Mutex<OddsStore>>) -> Self {
+        Self { store, tracked: Vec::new() }
+    }
+
+    /// Capture `subject` from `source`, which knows it by `venue_id`
+    pub fn track(mut self, source: Arc<dyn BeliefSource>, subject: &str, venue_id: &str) -> Self {
+        self.tracked.push(Tracked { source, subject: subject.to_string(), venue_id: venue_id.to_string() });
+        self
+    }
+
+    pub fn store(&self) -> &Arc<Mutex<OddsStore>> {
+        &self.store
+    }
+
+    /// Read every tracked series once; failed reads are logged and skipped, and readings
+    /// identical to the last stored point (same observation time and value) are not re-recorded
+    pub async fn poll_once(&self) -> usize {
+        let mut recorded = 0;
+        for tracked in &self.tracked {
+            match tracked.source.read_belief(&tracked.venue_id).await {
+                Ok(mut reading) => {
+                    reading.subject = tracked.subject.clone();
+                    let mut store = self.store.lock().unwrap();
+                    let unchanged = store.last(reading.source, &reading.subject)
+                        .is_some_and(|p| p.observed_at == to_millis(reading.observed_at) && p.value == reading.value);
+                    if unchanged {
+                        continue;
+                    }
+                    match store.record(&reading) {
+                        Ok(()) => recorded += 1,
+                        Err(e) => log::warn!("Failed to store {:?} reading for {}: {}", reading.source, tracked.subject, e),
+                    }
+                }
+                Err(e) => log::warn!("{:?} poll for {} failed: {}", tracked.source.source(), tracked.subject, e),
+            }
+        }
+        recorded
+    }
+
+    /// Run `poll_once` every `interval` until the returned handle is aborted
+    pub fn spawn(self: Arc<Self>, interval: Duration) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                self.poll_once().await;
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("odds-history-{}-{}-{}", name, std::process::id(), to_millis(SystemTime::now())));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn point(value: f64, observed_at: u64) -> OddsPoint {
+        OddsPoint { source: OracleSource::Internal, subject: "q".to_string(), value, observed_at, recorded_at: observed_at }
+    }
+
+    /// Returns whatever value/time it currently holds
+    struct Fixed(Mutex<(f64, SystemTime)>);
+
+    #[async_trait]
+    impl BeliefSource for Fixed {
+        fn source(&self) -> OracleSource {
+            OracleSource::Internal
+        }
+
+        async fn read_belief(&self, subject: &str) -> Result<OracleReading, String> {
+            let (value, observed_at) = *self.0.lock().unwrap();
+            Ok(OracleReading { source: OracleSource::Internal, subject: subject.to_string(), value, observed_at })
+        }
+    }
+
+    /// Fixed series, appended out of order: three points in [1s, 2s), one at 2s, none in [3s, 4s)
+    fn fixture_store(name: &str) -> (PathBuf, OddsStore) {
+        let dir = temp_dir(name);
+        let mut store = OddsStore::open(&dir).unwrap();
+        for (value, at) in [(0.60, 2_000), (0.50, 1_000), (0.45, 1_999), (0.55, 1_500), (0.80, 5_000), (0.70, 4_500), (0.65, 4_999)] {
+            store.append(point(value, at)).unwrap();
+        }
+        (dir, store)
+    }
+
+    fn ohlc(bars: &[OhlcBar]) -> Vec<(u64, f64, f64, f64, f64, usize)> {
+        bars.iter().map(|b| (b.start, b.open, b.high, b.low, b.close, b.samples)).collect()
+    }
+
+    #[test]
+    fn range_is_half_open_and_sorted() {
+        let (dir, store) = fixture_store("range");
+        let times = |from, to| -> Vec<u64> {
+            store.range(OracleSource::Internal, "q", from, to).iter().map(|p| p.observed_at).collect()
+        };
+        assert_eq!(times(0, u64::MAX), vec![1_000, 1_500, 1_999, 2_000, 4_500, 4_999, 5_000]);
+        assert_eq!(times(1_000, 2_000), vec![1_000, 1_500, 1_999]);
+        assert_eq!(times(1_999, 2_001), vec![1_999, 2_000]);
+        assert!(times(5_000, 5_000).is_empty());
+        assert!(times(6_000, 1_000).is_empty());
+        assert!(store.range(OracleSource::Augur, "q", 0, u64::MAX).is_empty());
+        assert!(store.range(OracleSource::Internal, "other", 0, u64::MAX).is_empty());
+
+        assert_eq!(store.latest_at(OracleSource::Internal, "q", 2_500).unwrap().value, 0.60);
+        assert_eq!(store.latest_at(OracleSource::Internal, "q", 2_000).unwrap().value, 0.60);
+        assert!(store.latest_at(OracleSource::Internal, "q", 999).is_none());
+        assert_eq!(store.last(OracleSource::Internal, "q").unwrap().value, 0.80);
+
+        // Reopening rebuilds the same sorted index from disk
+        drop(store);
+        let reopened = OddsStore::open(&dir).unwrap();
+        assert_eq!(reopened.range(OracleSource::Internal, "q", 0, u64::MAX).len(), 7);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn resample_buckets_ohlc_from_the_range_start() {
+        let (dir, store) = fixture_store("resample");
+        let second = Duration::from_secs(1);
+
+        // [3s, 4s) is empty and produces no bar; the point at 5s is outside [1s, 5s)
+        let bars = store.resample(OracleSource::Internal, "q", 1_000, 5_000, second);
+        assert_eq!(ohlc(&bars), vec![
+            (1_000, 0.50, 0.55, 0.45, 0.45, 3),
+            (2_000, 0.60, 0.60, 0.60, 0.60, 1),
+            (4_000, 0.70, 0.70, 0.65, 0.65, 2),
+        ]);
+
+        // Buckets are aligned to `from`, so boundary points shift with it
+        let shifted = store.resample(OracleSource::Internal, "q", 500, 6_000, second);
+        assert_eq!(ohlc(&shifted), vec![
+            (500, 0.50, 0.50, 0.50, 0.50, 1),
+            (1_500, 0.55, 0.60, 0.45, 0.60, 3),
+            (4_500, 0.70, 0.80, 0.65, 0.80, 3),
+        ]);
+
+        assert!(store.resample(OracleSource::Internal, "q", 3_000, 4_000, second).is_empty());
+        // A zero width degrades to 1 ms buckets rather than dividing by zero
+        assert_eq!(store.resample(OracleSource::Internal, "q", 0, u64::MAX, Duration::ZERO).len(), 7);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn exports_points_and_bars_as_csv_or_json() {
+        let (dir, store) = fixture_store("export");
+
+        let points_csv = dir.join("points.csv");
+        store.export(OracleSource::Internal, "q", 1_000, 2_000, &points_csv).unwrap();
+        assert_eq!(fs::read_to_string(&points_csv).unwrap(), "\
+source,subject,value,observed_at,recorded_at
+Internal,q,0.5,1000,1000
+Internal,q,0.55,1500,1500
+Internal,q,0.45,1999,1999
+");
+
+        let bars_csv = dir.join("bars.csv");
+        store.export_bars(OracleSource::Internal, "q", 1_000, 5_000, Duration::from_secs(1), &bars_csv).unwrap();
+        assert_eq!(fs::read_to_string(&bars_csv).unwrap(), "\
+start,open,high,low,close,samples
+1000,0.5,0.55,0.45,0.45,3
+2000,0.6,0.6,0.6,0.6,1
+4000,0.7,0.7,0.65,0.65,2
+");
+
+        let bars_json = dir.join("bars.json");
+        store.export_bars(OracleSource::Internal, "q", 1_000, 5_000, Duration::from_secs(1), &bars_json).unwrap();
+        let bars: Vec<OhlcBar> = serde_json::from_str(&fs::read_to_string(&bars_json).unwrap()).unwrap();
+        assert_eq!(ohlc(&bars), ohlc(&store.resample(OracleSource::Internal, "q", 1_000, 5_000, Duration::from_secs(1))));
+
+        let points_json = dir.join("points.json");
+        store.export(OracleSource::Internal, "q", 4_999, u64::MAX, &points_json).unwrap();
+        let points: Vec<OddsPoint> = serde_json::from_str(&fs::read_to_string(&points_json).unwrap()).unwrap();
+        assert_eq!(points.iter().map(|p| (p.value, p.observed_at)).collect::<Vec<_>>(), vec![(0.65, 4_999), (0.80, 5_000)]);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn torn_last_line_is_truncated_on_open() {
+        let dir = temp_dir("torn");
+        let mut store = OddsStore::open(&dir).unwrap();
+        store.append(point(0.4, 1_000)).unwrap();
+        let path = store.series_path(OracleSource::Internal, "q");
+        drop(store);
+
+        let mut file = OpenOptions::new().append(true).open(&path).unwrap();
+        write!(file, "{{\"source\":\"Internal\",\"subj").unwrap();
+        drop(file);
+
+        let mut store = OddsStore::open(&dir).unwrap();
+        assert!(fs::read(&path).unwrap().ends_with(b"\n"));
+        store.append(point(0.6, 2_000)).unwrap();
+        drop(store);
+
+        let store = OddsStore::open(&dir).unwrap();
+        let values: Vec<f64> = store.range(OracleSource::Internal, "q", 0, u64::MAX).iter().map(|p| p.value).collect();
+        assert_eq!(values, vec![0.4, 0.6]);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn poller_skips_unchanged_readings() {
+        let dir = temp_dir("poller");
+        let store = Arc::new(Mutex::new(OddsStore::open(&dir).unwrap()));
+        let at = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let source = Arc::new(Fixed(Mutex::new((0.55, at))));
+        let poller = OddsPoller::new(store.clone()).track(source.clone(), "q", "venue-q");
+
+        assert_eq!(poller.poll_once().await, 1);
+        assert_eq!(poller.poll_once().await, 0);
+        // Same observation time but a new value is a change
+        source.0.lock().unwrap().0 = 0.6;
+        assert_eq!(poller.poll_once().await, 1);
+        source.0.lock().unwrap().1 = at + Duration::from_secs(1);
+        assert_eq!(poller.poll_once().await, 1);
+
+        // Stored under the tracked subject, not the venue id
+        let store = store.lock().unwrap();
+        let points = store.range(OracleSource::Internal, "q", 0, u64::MAX);
+        assert_eq!(points.iter().map(|p| p.value).collect::<Vec<_>>(), vec![0.55, 0.6, 0.6]);
+        assert!(store.range(OracleSource::Internal, "venue-q", 0, u64::MAX).is_empty());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
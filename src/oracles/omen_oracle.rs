@@ -0,0 +1,426 @@
+//! Omen Oracle — Conditional Market Integration for Futarchy via the Omen/FPMM Subgraph
+//! Ultramasterful belief aggregation from Omen/Gnosis markets
+
+use reqwest::Client;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::markets::fpmm::FixedProductMarketMaker;
+use crate::futarchy::FutarchyOutcome;
+use crate::truth::{SharedTruth, Verdict};
+
+const OMEN_SUBGRAPH: &str = "https://api.thegraph.com/subgraphs/name/protofire/omen-xdai";
+
+const MARKET_QUERY: &str = r#"
+query Market($id: ID!) {
+  fixedProductMarketMaker(id: $id) {
+    id
+    title
+    outcomes
+    collateralToken
+    fee
+    outcomeTokenAmounts
+    outcomeTokenMarginalPrices
+    liquidityParameter
+    scaledLiquidityParameter
+    collateralVolume
+    resolutionTimestamp
+    payouts
+  }
+}
+"#;
+
+const TRADES_QUERY: &str = r#"
+query Trades($id: String!, $first: Int!, $after: String!) {
+  fpmmTrades(where: { fpmm: $id, id_gt: $after }, first: $first, orderBy: id, orderDirection: asc) {
+    id
+    type
+    creator { id }
+    outcomeIndex
+    collateralAmount
+    outcomeTokensTraded
+    creationTimestamp
+  }
+}
+"#;
+
+/// Trades fetched per page (subgraph page limit)
+const TRADE_PAGE: u32 = 1000;
+
+#[derive(Serialize)]
+struct GraphQLRequest<'a, V> {
+    query: &'a str,
+    variables: V,
+}
+
+#[derive(Deserialize)]
+struct GraphQLResponse<T> {
+    data: Option<T>,
+    errors: Option<Vec<GraphQLError>>,
+}
+
+#[derive(Deserialize)]
+struct GraphQLError {
+    message: String,
+}
+
+#[derive(Serialize)]
+struct MarketVars<'a> {
+    id: &'a str,
+}
+
+#[derive(Serialize)]
+struct TradeVars<'a> {
+    id: &'a str,
+    first: u32,
+    /// Cursor: last trade ID of the previous page
+    after: &'a str,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MarketData {
+    fixed_product_market_maker: Option<GqlMarket>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GqlMarket {
+    id: String,
+    title: Option<String>,
+    outcomes: Option<Vec<String>>,
+    collateral_token: String,
+    fee: String,
+    outcome_token_amounts: Vec<String>,
+    outcome_token_marginal_prices: Option<Vec<String>>,
+    liquidity_parameter: String,
+    scaled_liquidity_parameter: String,
+    collateral_volume: String,
+    resolution_timestamp: Option<String>,
+    payouts: Option<Vec<String>>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TradeData {
+    fpmm_trades: Vec<GqlTrade>,
+}
+
+#[derive(Deserialize)]
+struct GqlCreator {
+    id: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GqlTrade {
+    id: String,
+    #[serde(rename = "type")]
+    kind: String,
+    creator: GqlCreator,
+    outcome_index: String,
+    collateral_amount: String,
+    outcome_tokens_traded: String,
+    creation_timestamp: String,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TradeSide {
+    Buy,
+    Sell,
+}
+
+/// Single FPMM trade (raw amounts in collateral base units)
+#[derive(Clone, Debug)]
+pub struct OmenTrade {
+    pub id: String,
+    pub side: TradeSide,
+    pub trader: String,
+    pub outcome_index: usize,
+    pub collateral_amount: u128,
+    pub outcome_tokens_traded: u128,
+    pub timestamp: u64,
+}
+
+/// Snapshot of an Omen fixed-product market maker pool
+#[derive(Clone, Debug)]
+pub struct OmenMarket {
+    pub id: String,
+    pub title: String,
+    pub outcomes: Vec<String>,
+    pub collateral_token: String,
+    /// Fee in collateral base units per 1e18 traded
+    pub fee: u128,
+    pub outcome_token_amounts: Vec<u128>,
+    /// Derived marginal prices per outcome (sum to 1.0)
+    pub prices: Vec<f64>,
+    pub liquidity_parameter: u128,
+    pub scaled_liquidity: f64,
+    pub collateral_volume: u128,
+    pub trades: Vec<OmenTrade>,
+    pub resolution: FutarchyOutcome,
+    pub mercy_verdict: Verdict,
+}
+
+impl OmenMarket {
+    /// Local FPMM mirror of this pool for predicting the price impact of trades
+    /// (LP supply is approximated by the subgraph's liquidity parameter)
+    pub fn to_fpmm(&self) -> Result<FixedProductMarketMaker, String> {
+        FixedProductMarketMaker::from_pool_state(
+            self.outcome_token_amounts.iter().map(|&a| a.into()).collect(),
+            self.fee.into(),
+            self.liquidity_parameter.into(),
+        )
+    }
+}
+
+pub struct OmenOracle {
+    client: Client,
+    truth: SharedTruth,
+    subgraph_url: String,
+}
+
+impl OmenOracle {
+    pub fn new(truth: SharedTruth) -> Self {
+        Self::with_endpoint(OMEN_SUBGRAPH, truth)
+    }
+
+    /// Point the oracle at another subgraph endpoint (e.g. a local GraphQL stand-in)
+    pub fn with_endpoint(subgraph_url: &str, truth: SharedTruth) -> Self {
+        OmenOracle {
+            client: Client::new(),
+            truth,
+            subgraph_url: subgraph_url.to_string(),
+        }
+    }
+
+    async fn query<V: Serialize, T: DeserializeOwned>(&self, query: &str, variables: V) -> Result<T, String> {
+        let resp = self.client.post(&self.subgraph_url)
+            .json(&GraphQLRequest { query, variables })
+            .send()
+            .await
+            .map_err(|e| format!("Subgraph error: {:?}", e))?
+            .json::<GraphQLResponse<T>>()
+            .await
+            .map_err(|e| format!("Parse error: {:?}", e))?;
+
+        if let Some(errors) = resp.errors.filter(|errs| !errs.is_empty()) {
+            let messages: Vec<String> = errors.into_iter().map(|e| e.message).collect();
+            return Err(format!("Subgraph query failed: {}", messages.join("; ")));
+        }
+        resp.data.ok_or_else(|| "Subgraph returned no data".to_string())
+    }
+
+    /// Fetch every trade for a market in chronological order, paging on trade ID until a short page
+    pub async fn fetch_trades(&self, market_id: &str) -> Result<Vec<OmenTrade>, String> {
+        let mut trades = Vec::new();
+        let mut after = String::new();
+        loop {
+            let vars = TradeVars { id: market_id, first: TRADE_PAGE, after: &after };
+            let page: TradeData = self.query(TRADES_QUERY, vars).await?;
+            let count = page.fpmm_trades.len();
+            for raw in page.fpmm_trades {
+                trades.push(OmenTrade::try_from(raw)?);
+            }
+            match trades.last() {
+                Some(last) if count == TRADE_PAGE as usize => after = last.id.clone(),
+                _ => break,
+            }
+        }
+        trades.sort_by(|a, b| a.timestamp.cmp(&b.timestamp).then_with(|| a.id.cmp(&b.id)));
+        Ok(trades)
+    }
+
+    /// Fetch pool state, derived prices, trades and resolution for one FPMM
+    pub async fn fetch_market(&self, market_id: &str) -> Result<OmenMarket, String> {
+        let market_id = market_id.to_lowercase();
+        let data: MarketData = self.query(MARKET_QUERY, MarketVars { id: &market_id }).await?;
+        let raw = data.fixed_product_market_maker
+            .ok_or_else(|| format!("Omen market {} not found", market_id))?;
+        let trades = self.fetch_trades(&market_id).await?;
+
+        let outcome_token_amounts = raw.outcome_token_amounts.iter()
+            .map(|a| parse_int(a))
+            .collect::<Result<Vec<_>, _>>()?;
+        let prices = match &raw.outcome_token_marginal_prices {
+            Some(prices) => prices.iter().map(|p| parse_decimal(p)).collect::<Result<Vec<_>, _>>()?,
+            None => marginal_prices(&outcome_token_amounts),
+        };
+        let resolution = match (&raw.resolution_timestamp, &raw.payouts) {
+            (Some(_), Some(payouts)) => FutarchyOutcome::from_payouts(
+                payouts.iter().map(|p| parse_decimal(p)).collect::<Result<Vec<_>, _>>()?,
+            ),
+            _ => FutarchyOutcome::Pending,
+        };
+        let title = raw.title.unwrap_or_default();
+
+        Ok(OmenMarket {
+            mercy_verdict: self.truth.evaluate(&format!("Omen futarchy belief: {}", title)),
+            id: raw.id,
+            title,
+            outcomes: raw.outcomes.unwrap_or_default(),
+            collateral_token: raw.collateral_token,
+            fee: parse_int(&raw.fee)?,
+            outcome_token_amounts,
+            prices,
+            liquidity_parameter: parse_int(&raw.liquidity_parameter)?,
+            scaled_liquidity: parse_decimal(&raw.scaled_liquidity_parameter)?,
+            collateral_volume: parse_int(&raw.collateral_volume)?,
+            trades,
+            resolution,
+        })
+    }
+
+    /// Aggregate belief from an Omen conditional market (accepts a market address or an Omen UI URL)
+    pub async fn aggregate_omen_belief(&self, market_url: &str) -> Result<OmenMarket, String> {
+        let market_id = market_url
+            .rsplit(['/', '#'])
+            .find(|segment| segment.starts_with("0x"))
+            .ok_or_else(|| format!("No market address in {}", market_url))?;
+        self.fetch_market(market_id).await
+    }
+}
+
+impl TryFrom<GqlTrade> for OmenTrade {
+    type Error = String;
+
+    fn try_from(raw: GqlTrade) -> Result<Self, String> {
+        let side = match raw.kind.as_str() {
+            "Buy" => TradeSide::Buy,
+            "Sell" => TradeSide::Sell,
+            other => return Err(format!("Unknown trade type: {}", other)),
+        };
+        Ok(OmenTrade {
+            id: raw.id,
+            side,
+            trader: raw.creator.id,
+            outcome_index: parse_int(&raw.outcome_index)? as usize,
+            collateral_amount: parse_int(&raw.collateral_amount)?,
+            outcome_tokens_traded: parse_int(&raw.outcome_tokens_traded)?,
+            timestamp: parse_int(&raw.creation_timestamp)? as u64,
+        })
+    }
+}
+
+/// FPMM marginal price of outcome i: (1/amount_i) / Σ(1/amount_j)
+pub fn marginal_prices(amounts: &[u128]) -> Vec<f64> {
+    if amounts.contains(&0) {
+        return vec![0.0; amounts.len()];
+    }
+    let inverse: Vec<f64> = amounts.iter().map(|&a| 1.0 / a as f64).collect();
+    let total: f64 = inverse.iter().sum();
+    inverse.iter().map(|w| w / total).collect()
+}
+
+fn parse_int(value: &str) -> Result<u128, String> {
+    value.parse().map_err(|_| format!("Invalid BigInt: {}", value))
+}
+
+fn parse_decimal(value: &str) -> Result<f64, String> {
+    value.parse().map_err(|_| format!("Invalid BigDecimal: {}", value))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::*;
+    use crate::oracles::stub_server::StubServer;
+    use crate::truth::LocalTruth;
+
+    const MARKET_ID: &str = "0x5a6d36f49de2e5e6dd9bb1bcc1b8e5b8ff8e6ff7";
+
+    /// Recorded `fixedProductMarketMaker` response (resolved binary market)
+    const MARKET_RESPONSE: &str = r#"{"data":{"fixedProductMarketMaker":{
+        "id":"0x5a6d36f49de2e5e6dd9bb1bcc1b8e5b8ff8e6ff7",
+        "title":"Will the treasury fund the public goods round?",
+        "outcomes":["Yes","No"],
+        "collateralToken":"0xe91d153e0b41518a2ce8dd3d7944fa863463a97d",
+        "fee":"20000000000000000",
+        "outcomeTokenAmounts":["3000000000000000000","1000000000000000000"],
+        "outcomeTokenMarginalPrices":["0.25","0.75"],
+        "liquidityParameter":"1732050807568877293",
+        "scaledLiquidityParameter":"1.732050807568877293",
+        "collateralVolume":"5400000000000000000",
+        "resolutionTimestamp":"1700000000",
+        "payouts":["1","0"]
+    }}}"#;
+
+    fn trade(n: usize) -> Value {
+        json!({
+            "id": format!("0xtrade{:05}", n),
+            "type": if n.is_multiple_of(3) { "Sell" } else { "Buy" },
+            "creator": { "id": format!("0xtrader{}", n % 7) },
+            "outcomeIndex": (n % 2).to_string(),
+            "collateralAmount": "1000000000000000000",
+            "outcomeTokensTraded": "1900000000000000000",
+            // Reverse ID order so the chronological sort is exercised
+            "creationTimestamp": (1_700_000_000 - n as u64).to_string(),
+        })
+    }
+
+    /// Replays `total` trades, paging like the subgraph does on `id_gt`
+    fn subgraph(total: usize) -> StubServer {
+        StubServer::start(move |_, request| {
+            let query = request["query"].as_str().unwrap_or_default();
+            if query.contains("fixedProductMarketMaker") {
+                return serde_json::from_str(MARKET_RESPONSE).unwrap();
+            }
+            let after = request["variables"]["after"].as_str().unwrap_or_default().to_string();
+            let first = request["variables"]["first"].as_u64().unwrap() as usize;
+            let page: Vec<Value> = (0..total)
+                .map(trade)
+                .filter(|t| t["id"].as_str().unwrap() > after.as_str())
+                .take(first)
+                .collect();
+            json!({ "data": { "fpmmTrades": page } })
+        })
+    }
+
+    #[tokio::test]
+    async fn fetch_trades_pages_until_a_short_page() {
+        let server = subgraph(TRADE_PAGE as usize * 2 + 5);
+        let oracle = OmenOracle::with_endpoint(&server.url, LocalTruth::shared());
+        let trades = oracle.fetch_trades(MARKET_ID).await.unwrap();
+
+        assert_eq!(trades.len(), TRADE_PAGE as usize * 2 + 5);
+        assert_eq!(server.requests().len(), 3);
+        assert!(trades.windows(2).all(|w| w[0].timestamp <= w[1].timestamp));
+        assert_eq!(trades[0].id, format!("0xtrade{:05}", TRADE_PAGE as usize * 2 + 4));
+    }
+
+    #[tokio::test]
+    async fn exact_page_multiple_stops_on_empty_page() {
+        let server = subgraph(TRADE_PAGE as usize);
+        let oracle = OmenOracle::with_endpoint(&server.url, LocalTruth::shared());
+        assert_eq!(oracle.fetch_trades(MARKET_ID).await.unwrap().len(), TRADE_PAGE as usize);
+        assert_eq!(server.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn fetch_market_decodes_recorded_pool() {
+        let server = subgraph(4);
+        let oracle = OmenOracle::with_endpoint(&server.url, LocalTruth::shared());
+        let market = oracle.aggregate_omen_belief(&format!("https://omen.eth.limo/#/{}", MARKET_ID)).await.unwrap();
+
+        assert_eq!(market.outcomes, vec!["Yes", "No"]);
+        assert_eq!(market.prices, vec![0.25, 0.75]);
+        assert_eq!(market.fee, 20_000_000_000_000_000);
+        assert_eq!(market.trades.len(), 4);
+        assert_eq!(market.trades.iter().filter(|t| t.side == TradeSide::Sell).count(), 2);
+        assert_eq!(market.resolution, FutarchyOutcome::Adopted);
+    }
+
+    #[tokio::test]
+    async fn subgraph_errors_surface() {
+        let server = StubServer::start(|_, _| json!({ "errors": [{ "message": "indexing_error" }] }));
+        let oracle = OmenOracle::with_endpoint(&server.url, LocalTruth::shared());
+        let err = oracle.fetch_market(MARKET_ID).await.unwrap_err();
+        assert!(err.contains("indexing_error"), "{}", err);
+    }
+
+    #[test]
+    fn marginal_prices_are_inverse_reserve_weights() {
+        assert_eq!(marginal_prices(&[3, 1]), vec![0.25, 0.75]);
+        assert_eq!(marginal_prices(&[0, 1]), vec![0.0, 0.0]);
+    }
+}
@@ -0,0 +1,608 @@
+//! Oracle Feed — Timestamped Beliefs, Staleness Limits, Heartbeats and Failover
+//! Ultramasterful guarantee: no silently stale belief ever reaches a futarchy decision
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::oracles::augur_oracle::AugurOracle;
+#[cfg(feature = "metadao")]
+use crate::oracles::meta_dao_oracle::MetaDAOOracle;
+#[cfg(feature = "omen")]
+use crate::oracles::omen_oracle::OmenOracle;
+#[cfg(feature = "polymarket")]
+use crate::oracles::polymarket_oracle::PolymarketOracle;
+use crate::markets::prediction_market::PredictionMarket;
+
+/// Solana slot time used to age MetaDAO TWAP observations
+#[cfg(feature = "metadao")]
+const SLOT_DURATION: Duration = Duration::from_millis(400);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum OracleSource {
+    MetaDAO,
+    Polymarket,
+    Omen,
+    Augur,
+    Internal,
+}
+
+/// One belief reading about a subject (proposal, event or question)
+#[derive(Clone, Debug)]
+pub struct OracleReading {
+    pub source: OracleSource,
+    pub subject: String,
+    /// Belief that adopting / resolving Yes is favoured on the `adoption_share` scale
+    pub value: f64,
+    /// When the underlying market data was last fresh (not when it was fetched)
+    pub observed_at: SystemTime,
+}
+
+impl OracleReading {
+    pub fn age(&self, now: SystemTime) -> Duration {
+        now.duration_since(self.observed_at).unwrap_or_default()
+    }
+}
+
+/// Common belief scale for every venue: adopted / (adopted + rejected), 0.5 = indifferent.
+/// For a single binary market (No = 1 − Yes) this is simply the Yes price.
+pub fn adoption_share(adopted: f64, rejected: f64) -> Result<f64, String> {
+    if !(adopted >= 0.0 && rejected >= 0.0 && adopted + rejected > 0.0) {
+        return Err(format!("Cannot compare conditional prices {} and {}", adopted, rejected));
+    }
+    Ok(adopted / (adopted + rejected))
+}
+
+/// Venue able to produce a belief reading
+#[async_trait]
+pub trait BeliefSource: Send + Sync {
+    fn source(&self) -> OracleSource;
+
+    /// Read the belief for `subject` (a venue-specific identifier)
+    async fn read_belief(&self, subject: &str) -> Result<OracleReading, String>;
+}
+
+/// Maximum reading age per source
+#[derive(Clone, Debug)]
+pub struct FreshnessPolicy {
+    max_age: HashMap<OracleSource, Duration>,
+    default_max_age: Duration,
+}
+
+impl FreshnessPolicy {
+    pub fn new(default_max_age: Duration) -> Self {
+        Self { max_age: HashMap::new(), default_max_age }
+    }
+
+    pub fn with_max_age(mut self, source: OracleSource, max_age: Duration) -> Self {
+        self.max_age.insert(source, max_age);
+        self
+    }
+
+    pub fn max_age(&self, source: OracleSource) -> Duration {
+        self.max_age.get(&source).copied().unwrap_or(self.default_max_age)
+    }
+
+    pub fn is_fresh(&self, reading: &OracleReading, now: SystemTime) -> bool {
+        reading.age(now) <= self.max_age(reading.source)
+    }
+}
+
+impl Default for FreshnessPolicy {
+    fn default() -> Self {
+        Self::new(Duration::from_secs(5 * 60))
+            .with_max_age(OracleSource::MetaDAO, Duration::from_secs(2 * 60))
+            .with_max_age(OracleSource::Polymarket, Duration::from_secs(60))
+            .with_max_age(OracleSource::Internal, Duration::from_secs(15 * 60))
+    }
+}
+
+/// Liveness of one source as seen by the heartbeat
+#[derive(Clone, Debug, Default)]
+pub struct SourceHealth {
+    pub last_success: Option<SystemTime>,
+    pub last_error: Option<(SystemTime, String)>,
+    pub consecutive_failures: u32,
+}
+
+impl SourceHealth {
+    pub fn is_alive(&self, now: SystemTime, heartbeat_timeout: Duration) -> bool {
+        self.last_success
+            .is_some_and(|t| now.duration_since(t).unwrap_or_default() <= heartbeat_timeout)
+    }
+}
+
+/// Why a source in the chain did not supply the belief
+#[derive(Clone, Debug)]
+pub enum SkipReason {
+    Failed(String),
+    Stale { age: Duration, max_age: Duration },
+}
+
+/// Result of asking the failover chain for a belief
+#[derive(Clone, Debug)]
+pub enum BeliefOutcome {
+    Fresh {
+        reading: OracleReading,
+        /// Earlier sources that were tried and skipped
+        skipped: Vec<(OracleSource, SkipReason)>,
+    },
+    NoFreshBelief {
+        attempts: Vec<(OracleSource, SkipReason)>,
+    },
+}
+
+impl BeliefOutcome {
+    pub fn reading(&self) -> Option<&OracleReading> {
+        match self {
+            BeliefOutcome::Fresh { reading, .. } => Some(reading),
+            BeliefOutcome::NoFreshBelief { .. } => None,
+        }
+    }
+}
+
+/// Ordered failover chain, e.g. MetaDAO → Polymarket → internal PredictionMarket
+pub struct FailoverChain {
+    sources: Vec<Arc<dyn BeliefSource>>,
+    policy: FreshnessPolicy,
+    /// Venue-specific identifiers for a shared subject, per source
+    aliases: HashMap<(OracleSource, String), String>,
+    health: Mutex<HashMap<OracleSource, SourceHealth>>,
+    heartbeat_timeout: Duration,
+}
+
+impl FailoverChain {
+    pub fn new(policy: FreshnessPolicy) -> Self {
+        Self {
+            sources: Vec::new(),
+            policy,
+            aliases: HashMap::new(),
+            health: Mutex::new(HashMap::new()),
+            heartbeat_timeout: Duration::from_secs(5 * 60),
+        }
+    }
+
+    /// Append a source; earlier sources take precedence
+    pub fn then(mut self, source: Arc<dyn BeliefSource>) -> Self {
+        self.sources.push(source);
+        self
+    }
+
+    pub fn with_heartbeat_timeout(mut self, timeout: Duration) -> Self {
+        self.heartbeat_timeout = timeout;
+        self
+    }
+
+    /// Map `subject` to the identifier `source` knows it by (market ID, proposal account, ...)
+    pub fn alias(&mut self, source: OracleSource, subject: &str, venue_id: &str) {
+        self.aliases.insert((source, subject.to_string()), venue_id.to_string());
+    }
+
+    pub fn policy(&self) -> &FreshnessPolicy {
+        &self.policy
+    }
+
+    fn venue_id<'a>(&'a self, source: OracleSource, subject: &'a str) -> &'a str {
+        self.aliases.get(&(source, subject.to_string())).map_or(subject, String::as_str)
+    }
+
+    fn record(&self, source: OracleSource, result: &Result<OracleReading, String>) {
+        let now = SystemTime::now();
+        let mut health = self.health.lock().unwrap();
+        let entry = health.entry(source).or_default();
+        match result {
+            Ok(_) => {
+                entry.last_success = Some(now);
+                entry.consecutive_failures = 0;
+            }
+            Err(e) => {
+                entry.last_error = Some((now, e.clone()));
+                entry.consecutive_failures += 1;
+            }
+        }
+    }
+
+    /// First fresh reading in chain order, or an explicit "no fresh belief" with every skip reason
+    pub async fn fresh_belief(&self, subject: &str) -> BeliefOutcome {
+        let mut skipped = Vec::new();
+        for source in &self.sources {
+            let kind = source.source();
+            let result = source.read_belief(self.venue_id(kind, subject)).await;
+            self.record(kind, &result);
+
+            match result {
+                Ok(mut reading) => {
+                    let now = SystemTime::now();
+                    if self.policy.is_fresh(&reading, now) {
+                        reading.subject = subject.to_string();
+                        return BeliefOutcome::Fresh { reading, skipped };
+                    }
+                    log::warn!("{:?} belief for {} is stale", kind, subject);
+                    skipped.push((kind, SkipReason::Stale { age: reading.age(now), max_age: self.policy.max_age(kind) }));
+                }
+                Err(e) => {
+                    log::warn!("{:?} belief for {} failed: {}", kind, subject, e);
+                    skipped.push((kind, SkipReason::Failed(e)));
+                }
+            }
+        }
+        BeliefOutcome::NoFreshBelief { attempts: skipped }
+    }
+
+    /// Probe every source once for `subject`, updating heartbeat health
+    pub async fn heartbeat(&self, subject: &str) {
+        for source in &self.sources {
+            let kind = source.source();
+            let result = source.read_belief(self.venue_id(kind, subject)).await;
+            self.record(kind, &result);
+        }
+    }
+
+    /// Run `heartbeat` every `interval` until the returned handle is aborted
+    pub fn spawn_heartbeat(self: &Arc<Self>, subject: String, interval: Duration) -> tokio::task::JoinHandle<()> {
+        let chain = Arc::clone(self);
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                chain.heartbeat(&subject).await;
+            }
+        })
+    }
+
+    pub fn health(&self, source: OracleSource) -> SourceHealth {
+        self.health.lock().unwrap().get(&source).cloned().unwrap_or_default()
+    }
+
+    /// Sources whose last successful reading is older than the heartbeat timeout
+    pub fn dead_sources(&self) -> Vec<OracleSource> {
+        let now = SystemTime::now();
+        let health = self.health.lock().unwrap();
+        self.sources.iter()
+            .map(|s| s.source())
+            .filter(|kind| !health.get(kind).is_some_and(|h| h.is_alive(now, self.heartbeat_timeout)))
+            .collect()
+    }
+}
+
+#[cfg(feature = "metadao")]
+#[async_trait]
+impl BeliefSource for MetaDAOOracle {
+    fn source(&self) -> OracleSource {
+        OracleSource::MetaDAO
+    }
+
+    /// Pass share of pass+fail TWAP (spot before the TWAP window opens), aged by oracle slot lag
+    async fn read_belief(&self, subject: &str) -> Result<OracleReading, String> {
+        let belief = self.aggregate_meta_dao_belief(subject).await?;
+        let pass = belief.pass_twap.or(belief.pass_price).ok_or("Pass market has no price")?;
+        let fail = belief.fail_twap.or(belief.fail_price).ok_or("Fail market has no price")?;
+
+        let last_update = belief.pass_amm.oracle.last_updated_slot.min(belief.fail_amm.oracle.last_updated_slot);
+        let lag_slots = u32::try_from(belief.slot.saturating_sub(last_update)).unwrap_or(u32::MAX);
+        Ok(OracleReading {
+            source: OracleSource::MetaDAO,
+            subject: subject.to_string(),
+            value: adoption_share(pass, fail)?,
+            observed_at: SystemTime::now().checked_sub(SLOT_DURATION * lag_slots).unwrap_or(UNIX_EPOCH),
+        })
+    }
+}
+
+#[cfg(feature = "polymarket")]
+#[async_trait]
+impl BeliefSource for PolymarketOracle {
+    fn source(&self) -> OracleSource {
+        OracleSource::Polymarket
+    }
+
+    /// Adopted share of the conditional pair, as old as the staler of the two order books
+    async fn read_belief(&self, subject: &str) -> Result<OracleReading, String> {
+        let belief = self.aggregate_futarchy_belief(vec![subject]).await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| format!("No Polymarket belief for {}", subject))?;
+        let observed_at = belief.adopted.observed_at
+            .zip(belief.rejected.observed_at)
+            .map(|(a, r)| a.min(r))
+            .ok_or_else(|| format!("Polymarket books for {} carry no update timestamp", subject))?;
+        Ok(OracleReading {
+            source: OracleSource::Polymarket,
+            subject: subject.to_string(),
+            value: adoption_share(belief.adopted.price, belief.rejected.price)?,
+            observed_at,
+        })
+    }
+}
+
+#[cfg(feature = "omen")]
+#[async_trait]
+impl BeliefSource for OmenOracle {
+    fn source(&self) -> OracleSource {
+        OracleSource::Omen
+    }
+
+    /// Yes price of a binary FPMM pool, as fresh as its last trade
+    async fn read_belief(&self, subject: &str) -> Result<OracleReading, String> {
+        let market = self.fetch_market(subject).await?;
+        if market.prices.len() != 2 {
+            return Err(format!("Omen market {} is not binary", market.id));
+        }
+        let yes = market.outcomes.iter().position(|o| o.eq_ignore_ascii_case("yes")).unwrap_or(0);
+        let last_trade = market.trades.iter().map(|t| t.timestamp).max()
+            .ok_or_else(|| format!("Omen market {} has no trades to date its prices", market.id))?;
+        Ok(OracleReading {
+            source: OracleSource::Omen,
+            subject: subject.to_string(),
+            value: adoption_share(market.prices[yes], market.prices[1 - yes])?,
+            observed_at: UNIX_EPOCH + Duration::from_secs(last_trade),
+        })
+    }
+}
+
+#[async_trait]
+impl BeliefSource for AugurOracle {
+    fn source(&self) -> OracleSource {
+        OracleSource::Augur
+    }
+
+    /// Last recorded Yes price of an archived yes/no market, dated by that trade
+    async fn read_belief(&self, subject: &str) -> Result<OracleReading, String> {
+        let market = self.store().market(subject)
+            .ok_or_else(|| format!("No archived Augur market: {}", subject))?;
+        if !market.is_yes_no() {
+            return Err(format!("Augur market {} is not yes/no", subject));
+        }
+        let (price, timestamp) = self.store().trades_for(subject)
+            .filter_map(|t| market.implied_yes_price(t).map(|p| (p, t.timestamp)))
+            .last()
+            .ok_or_else(|| format!("Augur market {} has no recorded trades", subject))?;
+        Ok(OracleReading {
+            source: OracleSource::Augur,
+            subject: subject.to_string(),
+            value: adoption_share(price, 1.0 - price)?,
+            observed_at: UNIX_EPOCH + Duration::from_secs(timestamp),
+        })
+    }
+}
+
+#[async_trait]
+impl BeliefSource for RwLock<PredictionMarket> {
+    fn source(&self) -> OracleSource {
+        OracleSource::Internal
+    }
+
+    /// Internal Yes price, as fresh as the market's last trade
+    async fn read_belief(&self, subject: &str) -> Result<OracleReading, String> {
+        let markets = self.read().map_err(|_| "Prediction market lock poisoned".to_string())?;
+        let market = markets.market(subject)
+            .ok_or_else(|| format!("No internal market for: {}", subject))?;
+        Ok(OracleReading {
+            source: OracleSource::Internal,
+            subject: subject.to_string(),
+            value: market.yes_price(),
+            observed_at: market.last_trade_at,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+    use crate::oracles::augur_history::{AugurMarket, AugurStore, AugurTrade, TradeSide};
+    use crate::truth::LocalTruth;
+
+    fn augur_oracle() -> AugurOracle {
+        let store = AugurStore {
+            markets: vec![AugurMarket {
+                id: "0xaugur".to_string(),
+                version: 2,
+                description: "Will the grant programme ship?".to_string(),
+                num_outcomes: 3,
+                end_time: 1_600_000_000,
+                final_outcome: None,
+                invalid: false,
+            }],
+            trades: vec![AugurTrade {
+                market_id: "0xaugur".to_string(),
+                timestamp: 1_590_000_000,
+                trader: "0xtrader".to_string(),
+                // v2 No at 0.3 → Yes 0.7
+                outcome: 1,
+                side: TradeSide::Buy,
+                price: 0.3,
+                amount: 10.0,
+            }],
+            ..AugurStore::default()
+        };
+        AugurOracle::with_store(store, LocalTruth::shared())
+    }
+
+    #[derive(Clone, Copy, Debug)]
+    enum StubMode {
+        Fresh,
+        Stale,
+        Down,
+    }
+
+    /// Belief source whose behaviour the test flips between fresh, stale and down
+    struct StubSource {
+        kind: OracleSource,
+        value: f64,
+        mode: Mutex<StubMode>,
+        reads: AtomicUsize,
+    }
+
+    impl StubSource {
+        fn new(kind: OracleSource, value: f64) -> Arc<Self> {
+            Arc::new(Self { kind, value, mode: Mutex::new(StubMode::Fresh), reads: AtomicUsize::new(0) })
+        }
+
+        fn set(&self, mode: StubMode) {
+            *self.mode.lock().unwrap() = mode;
+        }
+    }
+
+    #[async_trait]
+    impl BeliefSource for StubSource {
+        fn source(&self) -> OracleSource {
+            self.kind
+        }
+
+        async fn read_belief(&self, subject: &str) -> Result<OracleReading, String> {
+            self.reads.fetch_add(1, Ordering::SeqCst);
+            let observed_at = match *self.mode.lock().unwrap() {
+                StubMode::Fresh => SystemTime::now(),
+                StubMode::Stale => SystemTime::now() - Duration::from_secs(3_600),
+                StubMode::Down => return Err(format!("{:?} unreachable", self.kind)),
+            };
+            Ok(OracleReading { source: self.kind, subject: subject.to_string(), value: self.value, observed_at })
+        }
+    }
+
+    #[tokio::test]
+    async fn heartbeat_reports_dead_sources_and_chain_fails_over() {
+        let primary = StubSource::new(OracleSource::MetaDAO, 0.8);
+        let backup = StubSource::new(OracleSource::Polymarket, 0.6);
+        let chain = FailoverChain::new(FreshnessPolicy::default())
+            .then(primary.clone())
+            .then(backup.clone())
+            .with_heartbeat_timeout(Duration::from_millis(50));
+
+        // Nothing probed yet: every source counts as dead
+        assert_eq!(chain.dead_sources(), vec![OracleSource::MetaDAO, OracleSource::Polymarket]);
+        chain.heartbeat("grants").await;
+        assert!(chain.dead_sources().is_empty());
+
+        // A stale reading is skipped but still proves the venue answers
+        primary.set(StubMode::Stale);
+        match chain.fresh_belief("grants").await {
+            BeliefOutcome::Fresh { reading, skipped } => {
+                assert_eq!(reading.source, OracleSource::Polymarket);
+                assert!(matches!(skipped.as_slice(), [(OracleSource::MetaDAO, SkipReason::Stale { .. })]));
+            }
+            other => panic!("expected Polymarket fallback, got {:?}", other),
+        }
+        assert!(chain.dead_sources().is_empty());
+
+        // Primary goes down; once its last success ages past the timeout it is reported dead
+        primary.set(StubMode::Down);
+        tokio::time::sleep(Duration::from_millis(80)).await;
+        chain.heartbeat("grants").await;
+        chain.heartbeat("grants").await;
+        assert_eq!(chain.dead_sources(), vec![OracleSource::MetaDAO]);
+        let health = chain.health(OracleSource::MetaDAO);
+        assert_eq!(health.consecutive_failures, 2);
+        assert_eq!(health.last_error.unwrap().1, "MetaDAO unreachable");
+        assert_eq!(chain.health(OracleSource::Polymarket).consecutive_failures, 0);
+
+        match chain.fresh_belief("grants").await {
+            BeliefOutcome::Fresh { reading, skipped } => {
+                assert_eq!(reading.source, OracleSource::Polymarket);
+                assert_eq!(reading.value, 0.6);
+                assert!(matches!(skipped.as_slice(), [(OracleSource::MetaDAO, SkipReason::Failed(e))] if e == "MetaDAO unreachable"));
+            }
+            other => panic!("expected Polymarket fallback, got {:?}", other),
+        }
+
+        // Recovery resets the failure streak
+        primary.set(StubMode::Fresh);
+        assert_eq!(chain.fresh_belief("grants").await.reading().unwrap().source, OracleSource::MetaDAO);
+        assert_eq!(chain.health(OracleSource::MetaDAO).consecutive_failures, 0);
+
+        backup.set(StubMode::Down);
+        primary.set(StubMode::Down);
+        match chain.fresh_belief("grants").await {
+            BeliefOutcome::NoFreshBelief { attempts } => assert_eq!(attempts.len(), 2),
+            other => panic!("expected no fresh belief, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn spawned_heartbeat_keeps_probing_until_aborted() {
+        let primary = StubSource::new(OracleSource::MetaDAO, 0.8);
+        let chain = Arc::new(FailoverChain::new(FreshnessPolicy::default())
+            .then(primary.clone())
+            .with_heartbeat_timeout(Duration::from_millis(200)));
+
+        let handle = chain.spawn_heartbeat("grants".to_string(), Duration::from_millis(10));
+        tokio::time::sleep(Duration::from_millis(60)).await;
+        assert!(primary.reads.load(Ordering::SeqCst) >= 2);
+        assert!(chain.dead_sources().is_empty());
+
+        primary.set(StubMode::Down);
+        tokio::time::sleep(Duration::from_millis(60)).await;
+        assert!(chain.health(OracleSource::MetaDAO).consecutive_failures >= 2);
+
+        handle.abort();
+        assert!(handle.await.unwrap_err().is_cancelled());
+        let reads = primary.reads.load(Ordering::SeqCst);
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        assert_eq!(primary.reads.load(Ordering::SeqCst), reads);
+    }
+
+    #[test]
+    fn venues_share_one_scale() {
+        assert_eq!(adoption_share(0.3, 0.3).unwrap(), 0.5);
+        assert!((adoption_share(0.6, 0.2).unwrap() - 0.75).abs() < 1e-12);
+        // Single binary market: Yes vs 1 - Yes is the Yes price
+        assert!((adoption_share(0.7, 0.3).unwrap() - 0.7).abs() < 1e-12);
+        assert!(adoption_share(0.0, 0.0).is_err());
+        assert!(adoption_share(f64::NAN, 0.5).is_err());
+    }
+
+    #[tokio::test]
+    async fn augur_readings_are_dated_by_their_trade() {
+        let reading = augur_oracle().read_belief("0xaugur").await.unwrap();
+        assert!((reading.value - 0.7).abs() < 1e-12);
+        assert_eq!(reading.observed_at, UNIX_EPOCH + Duration::from_secs(1_590_000_000));
+    }
+
+    #[tokio::test]
+    async fn stale_history_fails_over_to_internal_market() {
+        let mut internal = PredictionMarket::new(LocalTruth::shared());
+        internal.open_market("0xaugur", 100.0).unwrap();
+        let chain = FailoverChain::new(FreshnessPolicy::default())
+            .then(Arc::new(augur_oracle()))
+            .then(Arc::new(RwLock::new(internal)));
+
+        match chain.fresh_belief("0xaugur").await {
+            BeliefOutcome::Fresh { reading, skipped } => {
+                assert_eq!(reading.source, OracleSource::Internal);
+                assert!(matches!(skipped.as_slice(), [(OracleSource::Augur, SkipReason::Stale { .. })]));
+            }
+            other => panic!("expected a fresh internal belief, got {:?}", other),
+        }
+    }
+
+    #[cfg(feature = "polymarket")]
+    #[tokio::test]
+    async fn polymarket_readings_use_book_timestamps() {
+        use serde_json::json;
+
+        use crate::oracles::stub_server::StubServer;
+
+        let server = StubServer::start(|path, _| {
+            if let Some(token) = path.strip_prefix("/book?token_id=") {
+                let timestamp = if token == "yes-a" { "1700000000000" } else { "1700000005000" };
+                return json!({ "timestamp": timestamp, "bids": [{ "price": "0.59", "size": "500" }], "asks": [{ "price": "0.61", "size": "500" }] });
+            }
+            let (id, price, token) = match path.trim_start_matches("/markets/") {
+                "adopt" => ("adopt", "0.6", "yes-a"),
+                _ => ("reject", "0.2", "yes-r"),
+            };
+            json!({ "id": id, "question": id, "outcomePrices": format!("[\"{}\", \"0.5\"]", price), "clobTokenIds": format!("[\"{}\"]", token) })
+        });
+        let mut oracle = PolymarketOracle::with_endpoints(&format!("{}/markets", server.url), &server.url, LocalTruth::shared());
+        oracle.register_conditional_pair("fund grants", "adopt", "reject");
+
+        let reading = oracle.read_belief("fund grants").await.unwrap();
+        assert!((reading.value - 0.75).abs() < 1e-12);
+        assert_eq!(reading.observed_at, UNIX_EPOCH + Duration::from_millis(1_700_000_000_000));
+    }
+}
@@ -0,0 +1,395 @@
+//! Polymarket Oracle — Live Conditional Market Integration for Futarchy
+//! Ultramasterful belief aggregation oracle
+
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use reqwest::Client;
+use serde::{de, Deserialize, Deserializer};
+
+use crate::truth::{SharedTruth, Verdict};
+
+const MARKETS_API: &str = "https://api.polymarket.com/markets";
+const CLOB_API: &str = "https://clob.polymarket.com";
+
+/// z-score for the 95% confidence band around each belief
+const CONFIDENCE_Z: f64 = 1.96;
+
+/// Gamma encodes some arrays as JSON strings (`"[\"0.5\", \"0.5\"]"`); accept either form
+fn json_list<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr,
+    T::Err: Display,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Raw {
+        Encoded(String),
+        List(Vec<serde_json::Value>),
+    }
+
+    let items = match Raw::deserialize(deserializer)? {
+        Raw::Encoded(text) if text.trim().is_empty() => Vec::new(),
+        Raw::Encoded(text) => serde_json::from_str::<Vec<serde_json::Value>>(&text).map_err(de::Error::custom)?,
+        Raw::List(items) => items,
+    };
+    items.into_iter()
+        .map(|item| {
+            let text = match item {
+                serde_json::Value::String(text) => text,
+                other => other.to_string(),
+            };
+            text.parse::<T>().map_err(de::Error::custom)
+        })
+        .collect()
+}
+
+#[derive(Deserialize)]
+pub struct PolymarketMarket {
+    pub id: String,
+    pub question: String,
+    #[serde(rename = "outcomePrices", deserialize_with = "json_list")]
+    pub outcome_prices: Vec<f64>,  // Yes/No prices
+    #[serde(rename = "clobTokenIds", default, deserialize_with = "json_list")]
+    pub clob_token_ids: Vec<String>,  // Yes/No outcome tokens on the CLOB
+}
+
+/// Single resting order level (CLOB returns decimals as strings)
+#[derive(Deserialize)]
+pub struct OrderLevel {
+    pub price: String,
+    pub size: String,
+}
+
+#[derive(Deserialize)]
+pub struct OrderBook {
+    pub bids: Vec<OrderLevel>,
+    pub asks: Vec<OrderLevel>,
+    /// Last book update, milliseconds since the epoch (string-encoded)
+    #[serde(default)]
+    pub timestamp: Option<String>,
+}
+
+impl OrderBook {
+    /// When the book last changed, if the CLOB reported it
+    pub fn updated_at(&self) -> Option<SystemTime> {
+        let millis: u64 = self.timestamp.as_deref()?.trim().parse().ok()?;
+        Some(UNIX_EPOCH + Duration::from_millis(millis))
+    }
+
+    fn levels(side: &[OrderLevel]) -> impl Iterator<Item = (f64, f64)> + '_ {
+        side.iter().filter_map(|l| Some((l.price.parse().ok()?, l.size.parse().ok()?)))
+    }
+
+    /// Parsed (price, size) bid levels
+    pub fn bid_levels(&self) -> Vec<(f64, f64)> {
+        Self::levels(&self.bids).collect()
+    }
+
+    /// Parsed (price, size) ask levels
+    pub fn ask_levels(&self) -> Vec<(f64, f64)> {
+        Self::levels(&self.asks).collect()
+    }
+
+    /// Best bid/ask spread (1.0 when one side is empty)
+    pub fn spread(&self) -> f64 {
+        let best_bid = Self::levels(&self.bids).map(|(p, _)| p).fold(f64::NAN, f64::max);
+        let best_ask = Self::levels(&self.asks).map(|(p, _)| p).fold(f64::NAN, f64::min);
+        if best_bid.is_nan() || best_ask.is_nan() {
+            1.0
+        } else {
+            (best_ask - best_bid).max(0.0)
+        }
+    }
+
+    /// Total notional resting on both sides of the book
+    pub fn depth(&self) -> f64 {
+        Self::levels(&self.bids)
+            .chain(Self::levels(&self.asks))
+            .map(|(p, s)| p * s)
+            .sum()
+    }
+}
+
+/// Conditional market pair backing one policy proposal
+#[derive(Clone, Debug)]
+pub struct ConditionalPair {
+    pub adopted_market_id: String,
+    pub rejected_market_id: String,
+}
+
+/// Snapshot of one side of a conditional pair
+#[derive(Clone, Debug)]
+pub struct ConditionalQuote {
+    pub market_id: String,
+    pub price: f64,
+    pub spread: f64,
+    pub depth: f64,
+    /// Book update time backing this quote
+    pub observed_at: Option<SystemTime>,
+}
+
+impl ConditionalQuote {
+    /// Half-width of the confidence band: sampling error scaled by book depth plus half the spread
+    pub fn half_width(&self) -> f64 {
+        let variance = self.price * (1.0 - self.price) / self.depth.max(1.0);
+        CONFIDENCE_Z * variance.sqrt() + self.spread / 2.0
+    }
+}
+
+/// Ranked futarchy belief for one proposal
+#[derive(Clone, Debug)]
+pub struct ProposalBelief {
+    pub proposal: String,
+    pub adopted: ConditionalQuote,
+    pub rejected: ConditionalQuote,
+    /// Implied expected-metric difference (adopted − rejected)
+    pub expected_difference: f64,
+    pub confidence_interval: (f64, f64),
+    pub mercy_verdict: Verdict,
+}
+
+pub struct PolymarketOracle {
+    client: Client,
+    truth: SharedTruth,
+    markets_api: String,
+    clob_api: String,
+    conditional_pairs: HashMap<String, ConditionalPair>,
+}
+
+impl PolymarketOracle {
+    pub fn new(truth: SharedTruth) -> Self {
+        Self::with_endpoints(MARKETS_API, CLOB_API, truth)
+    }
+
+    /// Point the oracle at alternative market/CLOB hosts (e.g. a local stand-in)
+    pub fn with_endpoints(markets_api: &str, clob_api: &str, truth: SharedTruth) -> Self {
+        PolymarketOracle {
+            client: Client::new(),
+            truth,
+            markets_api: markets_api.trim_end_matches('/').to_string(),
+            clob_api: clob_api.trim_end_matches('/').to_string(),
+            conditional_pairs: HashMap::new(),
+        }
+    }
+
+    /// Link a proposal to its if-adopted / if-not-adopted markets
+    pub fn register_conditional_pair(&mut self, proposal: &str, adopted_market_id: &str, rejected_market_id: &str) {
+        self.conditional_pairs.insert(
+            proposal.to_string(),
+            ConditionalPair {
+                adopted_market_id: adopted_market_id.to_string(),
+                rejected_market_id: rejected_market_id.to_string(),
+            },
+        );
+    }
+
+    pub fn conditional_pair(&self, proposal: &str) -> Option<&ConditionalPair> {
+        self.conditional_pairs.get(proposal)
+    }
+
+    pub async fn fetch_market(&self, market_id: &str) -> Result<PolymarketMarket, String> {
+        let url = format!("{}/{}", self.markets_api, market_id);
+        self.client.get(&url)
+            .send()
+            .await
+            .map_err(|e| format!("API error: {:?}", e))?
+            .json::<PolymarketMarket>()
+            .await
+            .map_err(|e| format!("Parse error: {:?}", e))
+    }
+
+    /// Fetch conditional market odds for futarchy belief aggregation
+    pub async fn fetch_conditional_odds(&self, market_id: &str) -> Result<Vec<f64>, String> {
+        let resp = self.fetch_market(market_id).await?;
+
+        // Mercy-gated: reject manipulative/low-volume markets
+        if resp.outcome_prices.iter().any(|p| !(0.01..=0.99).contains(p)) {
+            return Err("Mercy Shield: Suspicious odds detected".to_string());
+        }
+
+        Ok(resp.outcome_prices)
+    }
+
+    /// Fetch the CLOB order book for an outcome token
+    pub async fn fetch_order_book(&self, token_id: &str) -> Result<OrderBook, String> {
+        let url = format!("{}/book?token_id={}", self.clob_api, token_id);
+        self.client.get(&url)
+            .send()
+            .await
+            .map_err(|e| format!("API error: {:?}", e))?
+            .json::<OrderBook>()
+            .await
+            .map_err(|e| format!("Parse error: {:?}", e))
+    }
+
+    /// Quote the Yes side of a conditional market together with its book liquidity
+    pub async fn fetch_conditional_quote(&self, market_id: &str) -> Result<ConditionalQuote, String> {
+        let market = self.fetch_market(market_id).await?;
+        let price = *market.outcome_prices.first()
+            .ok_or_else(|| format!("Market {} has no outcome prices", market.id))?;
+        if !(0.01..=0.99).contains(&price) {
+            return Err("Mercy Shield: Suspicious odds detected".to_string());
+        }
+
+        let yes_token = market.clob_token_ids.first()
+            .ok_or_else(|| format!("Market {} has no CLOB tokens", market.id))?;
+        let book = self.fetch_order_book(yes_token).await?;
+
+        Ok(ConditionalQuote {
+            market_id: market.id,
+            price,
+            spread: book.spread(),
+            depth: book.depth(),
+            observed_at: book.updated_at(),
+        })
+    }
+
+    /// Futarchy belief aggregation with Mercy resonance — proposals ranked by expected metric gain
+    pub async fn aggregate_futarchy_belief(&self, policy_proposals: Vec<&str>) -> Result<Vec<ProposalBelief>, String> {
+        let mut beliefs = Vec::with_capacity(policy_proposals.len());
+
+        for proposal in policy_proposals {
+            let pair = self.conditional_pairs.get(proposal)
+                .ok_or_else(|| format!("No conditional markets registered for proposal: {}", proposal))?;
+
+            let (adopted, rejected) = tokio::try_join!(
+                self.fetch_conditional_quote(&pair.adopted_market_id),
+                self.fetch_conditional_quote(&pair.rejected_market_id),
+            )?;
+
+            let expected_difference = adopted.price - rejected.price;
+            let half_width = adopted.half_width().hypot(rejected.half_width());
+
+            beliefs.push(ProposalBelief {
+                proposal: proposal.to_string(),
+                expected_difference,
+                confidence_interval: (expected_difference - half_width, expected_difference + half_width),
+                mercy_verdict: self.truth.evaluate(proposal),
+                adopted,
+                rejected,
+            });
+        }
+
+        beliefs.sort_by(|a, b| b.expected_difference.total_cmp(&a.expected_difference));
+        Ok(beliefs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::oracles::stub_server::StubServer;
+    use crate::truth::LocalTruth;
+
+    /// Yes price per stub market; each book rests 100 shares one cent either side of it
+    const PRICES: [(&str, f64); 5] = [("a1", 0.70), ("r1", 0.40), ("a2", 0.55), ("r2", 0.50), ("odd", 0.995)];
+
+    fn gamma_and_clob() -> StubServer {
+        StubServer::start(|path, _| {
+            if let Some(id) = path.strip_prefix("/markets/") {
+                let (_, price) = PRICES.iter().find(|(m, _)| *m == id).unwrap();
+                return json!({
+                    "id": id,
+                    "question": format!("Market {}", id),
+                    "outcomePrices": format!("[\"{}\", \"{}\"]", price, 1.0 - price),
+                    "clobTokenIds": format!("[\"tok-{}\", \"tok-{}-no\"]", id, id),
+                });
+            }
+            let token = path.split("token_id=tok-").nth(1).unwrap();
+            let (_, price) = PRICES.iter().find(|(m, _)| *m == token).unwrap();
+            json!({
+                "bids": [{ "price": format!("{:.2}", price - 0.01), "size": "100" }],
+                "asks": [{ "price": format!("{:.2}", price + 0.01), "size": "100" }],
+                "timestamp": "1700000000000",
+            })
+        })
+    }
+
+    fn oracle(server: &StubServer) -> PolymarketOracle {
+        let mut oracle = PolymarketOracle::with_endpoints(&format!("{}/markets", server.url), &server.url, LocalTruth::shared());
+        oracle.register_conditional_pair("Fund the library", "a2", "r2");
+        oracle.register_conditional_pair("Fund public goods", "a1", "r1");
+        oracle
+    }
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    #[tokio::test]
+    async fn proposals_rank_by_expected_difference_with_confidence_bands() {
+        let server = gamma_and_clob();
+        let beliefs = oracle(&server).aggregate_futarchy_belief(vec!["Fund the library", "Fund public goods"]).await.unwrap();
+
+        assert_eq!(beliefs.iter().map(|b| b.proposal.as_str()).collect::<Vec<_>>(), vec!["Fund public goods", "Fund the library"]);
+        let top = &beliefs[0];
+        assert_eq!((top.adopted.market_id.as_str(), top.rejected.market_id.as_str()), ("a1", "r1"));
+        assert!(close(top.expected_difference, 0.30));
+        // Spread 0.02, depth 200·p: half width = 1.96·√((1 − p)/200) + 0.01
+        assert!(close(top.adopted.spread, 0.02) && close(top.adopted.depth, 140.0));
+        assert!((top.adopted.half_width() - 0.085_910_473_6).abs() < 1e-9);
+        assert!((top.rejected.half_width() - 0.117_353_621_3).abs() < 1e-9);
+        assert!((top.confidence_interval.1 - top.confidence_interval.0 - 2.0 * 0.145_438_928_4).abs() < 1e-9);
+        assert_eq!(top.adopted.observed_at, Some(UNIX_EPOCH + Duration::from_millis(1_700_000_000_000)));
+
+        let second = &beliefs[1];
+        assert!(close(second.expected_difference, 0.05));
+        assert!(close(second.rejected.half_width(), 0.108));
+        assert!((second.confidence_interval.1 - second.expected_difference - 0.149_221_376_7).abs() < 1e-9);
+        assert!(second.mercy_verdict.verified);
+    }
+
+    #[tokio::test]
+    async fn unregistered_proposals_and_extreme_odds_are_rejected() {
+        let server = gamma_and_clob();
+        let mut oracle = oracle(&server);
+        let err = oracle.aggregate_futarchy_belief(vec!["Unknown"]).await.unwrap_err();
+        assert_eq!(err, "No conditional markets registered for proposal: Unknown");
+
+        oracle.register_conditional_pair("Lopsided", "odd", "r2");
+        let err = oracle.aggregate_futarchy_belief(vec!["Lopsided"]).await.unwrap_err();
+        assert_eq!(err, "Mercy Shield: Suspicious odds detected");
+    }
+
+    #[test]
+    fn order_book_spread_and_depth() {
+        let level = |price: &str, size: &str| OrderLevel { price: price.to_string(), size: size.to_string() };
+        let book = OrderBook {
+            bids: vec![level("0.48", "100"), level("0.50", "50"), level("bad", "1")],
+            asks: vec![level("0.53", "40"), level("0.55", "10")],
+            timestamp: None,
+        };
+        assert!(close(book.spread(), 0.03));
+        assert!(close(book.depth(), 48.0 + 25.0 + 21.2 + 5.5));
+        assert!(book.updated_at().is_none());
+        let one_sided = OrderBook { bids: Vec::new(), ..book };
+        assert_eq!(one_sided.spread(), 1.0);
+    }
+
+    #[test]
+    fn gamma_market_decodes_json_encoded_arrays() {
+        let raw = r#"{
+            "id": "253591",
+            "question": "Will the proposal pass?",
+            "outcomePrices": "[\"0.615\", \"0.385\"]",
+            "clobTokenIds": "[\"7132104567\", \"9912004421\"]"
+        }"#;
+        let market: PolymarketMarket = serde_json::from_str(raw).unwrap();
+        assert_eq!(market.outcome_prices, vec![0.615, 0.385]);
+        assert_eq!(market.clob_token_ids, vec!["7132104567", "9912004421"]);
+    }
+
+    #[test]
+    fn gamma_market_accepts_plain_arrays_and_missing_tokens() {
+        let raw = r#"{"id": "1", "question": "q", "outcomePrices": [0.5, "0.5"]}"#;
+        let market: PolymarketMarket = serde_json::from_str(raw).unwrap();
+        assert_eq!(market.outcome_prices, vec![0.5, 0.5]);
+        assert!(market.clob_token_ids.is_empty());
+    }
+}
@@ -1,52 +0,0 @@
-//! Polymarket Oracle — Live Conditional Market Integration for Futarchy
-//! Ultramasterful belief aggregation oracle
-
-use reqwest::Client;
-use serde::Deserialize;
-use nexi::lattice::Nexus;
-
-#[derive(Deserialize)]
-pub struct PolymarketMarket {
-    pub id: String,
-    pub question: String,
-    pub outcome_prices: Vec<f64>,  // Yes/No prices
-}
-
-pub struct PolymarketOracle {
-    client: Client,
-    nexus: Nexus,
-}
-
-impl PolymarketOracle {
-    pub fn new() -> Self {
-        PolymarketOracle {
-            client: Client::new(),
-            nexus: Nexus::init_with_mercy(),
-        }
-    }
-
-    /// Fetch conditional market odds for futarchy belief aggregation
-    pub async fn fetch_conditional_odds(&self, market_id: &str) -> Result<Vec<f64>, String> {
-        let url = format!("https://api.polymarket.com/markets/{}", market_id);
-        let resp = self.client.get(&url)
-            .send()
-            .await
-            .map_err(|e| format!("API error: {:?}", e))?
-            .json::<PolymarketMarket>()
-            .await
-            .map_err(|e| format!("Parse error: {:?}", e))?;
-
-        // Mercy-gated: reject manipulative/low-volume markets
-        if resp.outcome_prices.iter().any(|&p| p < 0.01 || p > 0.99) {
-            return Err("Mercy Shield: Suspicious odds detected".to_string());
-        }
-
-        Ok(resp.outcome_prices)
-    }
-
-    /// Futarchy belief aggregation with Mercy resonance
-    pub async fn aggregate_futarchy_belief(&self, policy_proposals: Vec<&str>) -> String {
-        // Stub — expand with full conditional market creation/lookup
-        self.nexus.distill_truth("Futarchy belief aggregation via Polymarket oracle complete")
-    }
-}
@@ -1,21 +0,0 @@
-//! Prediction Market Examples — Belief Aggregation Stub for Futarchy
-//! Ultramasterful core simulation
-
-use nexi::lattice::Nexus;
-
-pub struct PredictionMarket {
-    nexus: Nexus,
-}
-
-impl PredictionMarket {
-    pub fn new() -> Self {
-        PredictionMarket {
-            nexus: Nexus::init_with_mercy(),
-        }
-    }
-
-    pub fn aggregate_belief(&self, event: &str) -> String {
-        // Simulate market probability + truth check
-        self.nexus.distill_truth(event)
-    }
-}
@@ -1,4 +1,4 @@
-// GrokArena-Pinnacle/src/quadratic_voting_sim.rs
+// src/qv.rs
 //
 // Quadratic Voting (QV) Simulator Stub – With Stats Collector Module
 // =================================================================
@@ -15,18 +15,33 @@
 //
 // Perfect for Eternal Thriving analysis: Quantify coordination power, sybil impact,
 // and system robustness under different resistance modes.
+// Demo: `cargo run --example quadratic_voting`.
 
 use rand::prelude::*;
 use std::collections::{HashMap, HashSet};
 
-/// Voice credit budget per voter (f64)
-type Credits = f64;
-
-/// Votes on an issue (fractional)
-type Votes = f64;
+use crate::types::{Credits, Id, Proposal, Votes};
 
 /// Issue identifier
-type IssueId = u64;
+pub type IssueId = Id;
+
+/// Issue put to a quadratic vote
+pub type Issue = Proposal;
+
+/// How a voter spends their credits
+#[derive(Debug, Clone, PartialEq)]
+pub enum Strategy {
+    /// Random signed votes spread over a random subset of issues
+    Random { max_issues: usize },
+    /// Votes proportional to private utility (the QV optimum: v ∝ u)
+    Optimal,
+    /// Whole bloc spends everything on one issue
+    Coordinated { issue: IssueId, support: bool },
+    /// Spends everything against the target issues
+    Malicious { targets: Vec<IssueId> },
+    /// Malicious, amplified through `num_sybils` fake identities when sybil resistance is off
+    SybilAttack { targets: Vec<IssueId>, num_sybils: u64 },
+}
 
 /// Voter Strategy Enum
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -49,35 +64,91 @@ fn strategy_type(strategy: &Strategy) -> StrategyType {
     }
 }
 
-/// Simple Issue/Proposal
-#[derive(Debug, Clone)]
-struct Issue {
-    id: IssueId,
-    description: String,
+/// Credits needed to cast `votes` (either sign) on one issue
+pub fn quadratic_cost(votes: Votes) -> Credits {
+    votes * votes
+}
+
+/// Most votes `credits` can buy on one issue
+pub fn affordable_votes(credits: Credits) -> Votes {
+    credits.max(0.0).sqrt()
 }
 
 /// Voter state
 #[derive(Debug)]
-struct Voter {
-    id: u64,
-    strategy: Strategy,
-    original_budget: Credits,        // Track starting budget
-    remaining_credits: Credits,
-    allocations: HashMap<IssueId, Votes>,
+pub struct Voter {
+    pub id: u64,
+    pub strategy: Strategy,
+    pub original_budget: Credits,        // Track starting budget
+    pub remaining_credits: Credits,
+    pub allocations: HashMap<IssueId, Votes>,
 }
 
 impl Voter {
-    fn new(id: u64, strategy: Strategy, budget: Credits) -> Self {
+    pub fn new(id: u64, strategy: Strategy, budget: Credits) -> Self {
         Self {
             id,
-            strategy: strategy.clone(),
+            strategy,
             original_budget: budget,
             remaining_credits: budget,
             allocations: HashMap::new(),
         }
     }
 
-    // allocate, optimal_allocate, etc. unchanged...
+    /// Cast `votes` on an issue at quadratic cost, scaled down to what the voter can afford.
+    /// `amplification` multiplies the counted votes without extra cost (sybil identities).
+    fn allocate(&mut self, issue: IssueId, votes: Votes, amplification: f64) {
+        let affordable = affordable_votes(self.remaining_credits);
+        let votes = votes.clamp(-affordable, affordable);
+        self.remaining_credits -= quadratic_cost(votes);
+        *self.allocations.entry(issue).or_insert(0.0) += votes * amplification;
+    }
+
+    /// Spend the budget across `issues` according to the voter's strategy
+    pub fn vote<R: Rng>(&mut self, issues: &[IssueId], sybil_resistance_enabled: bool, rng: &mut R) {
+        if issues.is_empty() {
+            return;
+        }
+        let budget = self.remaining_credits;
+        match self.strategy.clone() {
+            Strategy::Random { max_issues } => {
+                let chosen: Vec<IssueId> = issues.choose_multiple(rng, max_issues.clamp(1, issues.len())).copied().collect();
+                let weights: Vec<f64> = chosen.iter().map(|_| rng.gen_range(0.0..1.0)).collect();
+                let total: f64 = weights.iter().sum::<f64>().max(f64::EPSILON);
+                for (issue, w) in chosen.into_iter().zip(weights) {
+                    let sign = if rng.gen_bool(0.5) { 1.0 } else { -1.0 };
+                    self.allocate(issue, sign * (budget * w / total).sqrt(), 1.0);
+                }
+            }
+            Strategy::Optimal => {
+                // Private utilities in [-1, 1]; v_i = u_i · sqrt(B / Σu²) spends exactly B
+                let utilities: Vec<f64> = issues.iter().map(|_| rng.gen_range(-1.0..1.0)).collect();
+                let norm: f64 = utilities.iter().map(|u| u * u).sum::<f64>().max(f64::EPSILON);
+                let scale = (budget / norm).sqrt();
+                for (&issue, u) in issues.iter().zip(utilities) {
+                    self.allocate(issue, u * scale, 1.0);
+                }
+            }
+            Strategy::Coordinated { issue, support } => {
+                let sign = if support { 1.0 } else { -1.0 };
+                self.allocate(issue, sign * budget.sqrt(), 1.0);
+            }
+            Strategy::Malicious { targets } => {
+                let per_target = (budget / targets.len().max(1) as f64).sqrt();
+                for target in targets {
+                    self.allocate(target, -per_target, 1.0);
+                }
+            }
+            Strategy::SybilAttack { targets, num_sybils } => {
+                // Each fake identity gets its own budget unless sybil resistance collapses them
+                let amplification = if sybil_resistance_enabled { 1.0 } else { num_sybils.max(1) as f64 };
+                let per_target = (budget / targets.len().max(1) as f64).sqrt();
+                for target in targets {
+                    self.allocate(target, -per_target, amplification);
+                }
+            }
+        }
+    }
 }
 
 /// Stats Collector
@@ -186,7 +257,7 @@ impl StatsCollector {
         }
         println!("Issue Outcomes:");
         for (&issue_id, &(net, passed)) in &self.issue_outcomes {
-            let desc = &issues[issue_id as usize].description;
+            let desc = issues.get(issue_id as usize).map_or("unknown issue", |issue| issue.description.as_str());
             println!("  Issue {} ({}): Net {:.2} → {}", issue_id, desc, net, if passed { "PASS" } else { "REJECT" });
         }
         if self.malicious_targets_total > 0 {
@@ -202,37 +273,87 @@ impl StatsCollector {
 /// Quadratic Voting Simulator – With StatsCollector
 #[derive(Debug)]
 pub struct QVSimulator {
-    // ... existing fields ...
+    pub issues: Vec<Issue>,
+    pub voters: Vec<Voter>,
+    pub tallies: HashMap<IssueId, Votes>,
     pub sybil_resistance_enabled: bool,
     pub stats: StatsCollector,
+    rng: ThreadRng,
+}
+
+impl Default for QVSimulator {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl QVSimulator {
     pub fn new() -> Self {
         Self {
-            // ... existing init ...
+            issues: Vec::new(),
+            voters: Vec::new(),
+            tallies: HashMap::new(),
             sybil_resistance_enabled: true,
             stats: StatsCollector::new(),
+            rng: thread_rng(),
+        }
+    }
+
+    pub fn add_issue(&mut self, description: String) -> IssueId {
+        let id = self.issues.len() as IssueId;
+        self.issues.push(Issue { id, description });
+        id
+    }
+
+    pub fn add_voter(&mut self, strategy: Strategy, budget: Credits) -> u64 {
+        let id = self.voters.len() as u64;
+        self.voters.push(Voter::new(id, strategy, budget));
+        id
+    }
+
+    /// Every voter spends their credits according to their strategy
+    pub fn vote_according_to_strategy(&mut self) {
+        let issue_ids: Vec<IssueId> = self.issues.iter().map(|i| i.id).collect();
+        for voter in &mut self.voters {
+            voter.vote(&issue_ids, self.sybil_resistance_enabled, &mut self.rng);
         }
     }
 
+    /// Net votes per issue
+    pub fn tally(&mut self) {
+        self.tallies = self.issues.iter().map(|i| (i.id, 0.0)).collect();
+        for voter in &self.voters {
+            for (&issue, &votes) in &voter.allocations {
+                *self.tallies.entry(issue).or_insert(0.0) += votes;
+            }
+        }
+    }
+
+    /// Refund every voter and clear the tallies (e.g. to rerun with resistance toggled)
+    pub fn reset_votes(&mut self) {
+        for voter in &mut self.voters {
+            voter.remaining_credits = voter.original_budget;
+            voter.allocations.clear();
+        }
+        self.tallies.clear();
+    }
+
     // After vote_according_to_strategy and tally:
     pub fn collect_and_print_stats(&mut self) {
-        self.stats.collect(self);
+        let mut stats = std::mem::take(&mut self.stats);
+        stats.collect(self);
+        self.stats = stats;
     }
 }
 
-/// Example: Full demo with stats
-fn main() {
-    let mut sim = QVSimulator::new();
-
-    // Issues...
-    // Voters (mixed strategies including sybil)...
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    sim.vote_according_to_strategy();
-    sim.tally();
-    sim.collect_and_print_stats();
-
-    // Reset and run with resistance off
-    // ...
+    #[test]
+    fn summary_tolerates_outcomes_for_unknown_issues() {
+        let mut stats = StatsCollector::new();
+        stats.issue_outcomes.insert(7, (3.0, true));
+        stats.print_summary(&[]);
+    }
 }
@@ -0,0 +1,372 @@
+// src/sim/dispute_sim.rs
+//
+// Augur-Style Reporting, Dispute & Fork Simulator
+// ===============================================
+//
+// Futarchy outcome measurement needs someone to report what actually happened.
+// This models Augur v2's answer (see AUGUR_FUTARCHY.md):
+// - Designated reporter posts the tentative outcome (or no-shows → open reporting).
+// - Anyone can dispute by staking the bond B(o) = 2·A − 3·S(o)
+//   (A = total stake on all outcomes, S(o) = stake already on o).
+// - Each successful dispute flips the tentative outcome and opens a new window.
+// - A bond reaching the fork threshold (2.5% of REP supply) forks the universe:
+//   REP holders migrate to the outcome they believe, the majority universe wins.
+//
+// Plugs into FutarchySimulator as its OutcomeResolver, and collects stats on
+// resolution latency, error rate and what it cost an attacker to corrupt outcomes.
+// Demo: `cargo run --example dispute_sim`.
+
+use rand::prelude::*;
+
+use crate::sim::futarchy_sim::OutcomeResolver;
+
+/// Safety cap on dispute rounds; real escalations fork within a few dozen
+const MAX_DISPUTE_ROUNDS: u32 = 1_000;
+
+/// Mechanism parameters (durations in hours, stakes in REP)
+#[derive(Debug, Clone)]
+pub struct DisputeConfig {
+    pub rep_supply: f64,
+    pub designated_report_stake: f64,
+    pub designated_reporter_honesty: f64,
+    pub no_show_probability: f64,
+    pub designated_report_window_hours: f64,
+    pub dispute_window_hours: f64,
+    pub fork_threshold_fraction: f64,
+    pub fork_duration_hours: f64,
+    /// Chance honest REP holders fund a dispute against a wrong tentative outcome each round
+    pub honest_dispute_probability: f64,
+    /// Chance the truthful universe attracts the REP majority in a fork
+    pub honest_fork_share: f64,
+    /// REP an attacker is willing to stake to corrupt outcomes (0 = no attacker)
+    pub attacker_budget: f64,
+    /// Fraction of losing-side stake paid out to winning stakers
+    pub dispute_reward_fraction: f64,
+}
+
+impl Default for DisputeConfig {
+    fn default() -> Self {
+        Self {
+            rep_supply: 11_000_000.0,
+            designated_report_stake: 0.35,
+            designated_reporter_honesty: 0.97,
+            no_show_probability: 0.05,
+            designated_report_window_hours: 24.0,
+            dispute_window_hours: 7.0 * 24.0,
+            fork_threshold_fraction: 0.025,
+            fork_duration_hours: 60.0 * 24.0,
+            honest_dispute_probability: 0.9,
+            honest_fork_share: 0.9,
+            attacker_budget: 0.0,
+            dispute_reward_fraction: 0.5,
+        }
+    }
+}
+
+impl DisputeConfig {
+    /// Positive supply and stakes, non-negative durations and budget, probabilities in [0, 1]
+    pub fn validate(&self) -> Result<(), String> {
+        let positive = [
+            ("rep_supply", self.rep_supply),
+            ("designated_report_stake", self.designated_report_stake),
+            ("fork_threshold_fraction", self.fork_threshold_fraction),
+        ];
+        for (name, value) in positive {
+            if !(value.is_finite() && value > 0.0) {
+                return Err(format!("{} must be positive, got {}", name, value));
+            }
+        }
+        let non_negative = [
+            ("designated_report_window_hours", self.designated_report_window_hours),
+            ("dispute_window_hours", self.dispute_window_hours),
+            ("fork_duration_hours", self.fork_duration_hours),
+            ("attacker_budget", self.attacker_budget),
+        ];
+        for (name, value) in non_negative {
+            if !(value.is_finite() && value >= 0.0) {
+                return Err(format!("{} must be non-negative, got {}", name, value));
+            }
+        }
+        let probabilities = [
+            ("designated_reporter_honesty", self.designated_reporter_honesty),
+            ("no_show_probability", self.no_show_probability),
+            ("fork_threshold_fraction", self.fork_threshold_fraction),
+            ("honest_dispute_probability", self.honest_dispute_probability),
+            ("honest_fork_share", self.honest_fork_share),
+            ("dispute_reward_fraction", self.dispute_reward_fraction),
+        ];
+        for (name, value) in probabilities {
+            if !(0.0..=1.0).contains(&value) {
+                return Err(format!("{} must be within [0, 1], got {}", name, value));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Result of resolving one binary outcome
+#[derive(Debug, Clone)]
+pub struct ResolutionResult {
+    pub truth: bool,
+    pub reported: bool,
+    pub rounds: u32,
+    pub forked: bool,
+    pub latency_hours: f64,
+    pub attacker_staked: f64,
+    /// Net REP the attacker lost (negative = profit)
+    pub attacker_loss: f64,
+}
+
+impl ResolutionResult {
+    pub fn correct(&self) -> bool {
+        self.truth == self.reported
+    }
+}
+
+/// Aggregate resolution stats
+#[derive(Debug, Default)]
+pub struct DisputeStats {
+    pub resolutions: u64,
+    pub incorrect: u64,
+    pub forks: u64,
+    pub disputed: u64,
+    pub total_latency_hours: f64,
+    pub total_rounds: u64,
+    pub attacker_staked: f64,
+    pub attacker_loss: f64,
+    /// Attacker REP staked in resolutions it successfully corrupted
+    pub corruption_stake: f64,
+    pub corruptions: u64,
+}
+
+impl DisputeStats {
+    fn record(&mut self, result: &ResolutionResult) {
+        self.resolutions += 1;
+        self.total_latency_hours += result.latency_hours;
+        self.total_rounds += result.rounds as u64;
+        self.attacker_staked += result.attacker_staked;
+        self.attacker_loss += result.attacker_loss;
+        if !result.correct() {
+            self.incorrect += 1;
+        }
+        if result.forked {
+            self.forks += 1;
+        }
+        if result.rounds > 0 {
+            self.disputed += 1;
+        }
+        if result.attacker_staked > 0.0 && !result.correct() {
+            self.corruptions += 1;
+            self.corruption_stake += result.attacker_staked;
+        }
+    }
+
+    pub fn mean_latency_hours(&self) -> f64 {
+        self.total_latency_hours / self.resolutions.max(1) as f64
+    }
+
+    /// Mean REP an attacker staked per corrupted outcome (None if never corrupted)
+    pub fn cost_per_corruption(&self) -> Option<f64> {
+        (self.corruptions > 0).then(|| self.corruption_stake / self.corruptions as f64)
+    }
+
+    pub fn print_summary(&self) {
+        println!("\n=== Dispute Resolution Summary ===");
+        println!("Resolutions: {} ({} disputed, {} forked)", self.resolutions, self.disputed, self.forks);
+        println!("Incorrect outcomes: {} ({:.1}%)", self.incorrect, self.incorrect as f64 / self.resolutions.max(1) as f64 * 100.0);
+        println!("Mean latency: {:.1} days", self.mean_latency_hours() / 24.0);
+        println!("Mean dispute rounds: {:.2}", self.total_rounds as f64 / self.resolutions.max(1) as f64);
+        if self.attacker_staked > 0.0 {
+            println!("Attacker staked {:.2} REP, net loss {:.2} REP", self.attacker_staked, self.attacker_loss);
+            match self.cost_per_corruption() {
+                Some(cost) => println!("Cost per corrupted outcome: {:.2} REP", cost),
+                None => println!("Attacker never corrupted an outcome"),
+            }
+        }
+    }
+}
+
+/// Augur-style reporting / dispute / fork simulator
+#[derive(Debug)]
+pub struct DisputeSimulator {
+    pub config: DisputeConfig,
+    pub stats: DisputeStats,
+    attacker_remaining: f64,
+    rng: ThreadRng,
+}
+
+impl DisputeSimulator {
+    pub fn new(config: DisputeConfig) -> Result<Self, String> {
+        config.validate()?;
+        Ok(Self {
+            attacker_remaining: config.attacker_budget,
+            config,
+            stats: DisputeStats::default(),
+            rng: thread_rng(),
+        })
+    }
+
+    /// Bernoulli draw that tolerates out-of-range probabilities (`config` is public)
+    fn chance(&mut self, p: f64) -> bool {
+        let p = if p.is_nan() { 0.0 } else { p.clamp(0.0, 1.0) };
+        self.rng.gen_bool(p)
+    }
+
+    /// Dispute bond to move the tentative outcome to `outcome`: 2·A − 3·S(o)
+    pub fn dispute_bond(stakes: &[f64; 2], outcome: bool) -> f64 {
+        let total = stakes[0] + stakes[1];
+        (2.0 * total - 3.0 * stakes[outcome as usize]).max(0.0)
+    }
+
+    pub fn fork_threshold(&self) -> f64 {
+        self.config.rep_supply * self.config.fork_threshold_fraction
+    }
+
+    /// REP an attacker must stake to escalate an honest outcome all the way to a fork,
+    /// assuming honest stakers answer every round
+    pub fn cost_to_force_fork(&self) -> Result<f64, String> {
+        let mut stakes = [0.0; 2];
+        let mut tentative = true;
+        stakes[tentative as usize] = self.config.designated_report_stake;
+        let mut attacker_cost = 0.0;
+        for _ in 0..MAX_DISPUTE_ROUNDS {
+            let bond = Self::dispute_bond(&stakes, !tentative);
+            // Attacker disputes away from the honest outcome; honest stakers dispute back
+            if tentative {
+                attacker_cost += bond;
+            }
+            if bond >= self.fork_threshold() {
+                return Ok(attacker_cost);
+            }
+            tentative = !tentative;
+            stakes[tentative as usize] += bond;
+        }
+        Err(format!("No fork within {} dispute rounds", MAX_DISPUTE_ROUNDS))
+    }
+
+    /// Resolve a binary outcome whose truth is `truth`
+    pub fn resolve_outcome(&mut self, truth: bool) -> ResolutionResult {
+        let cfg = self.config.clone();
+        let mut latency = cfg.designated_report_window_hours;
+
+        let mut tentative = if self.chance(cfg.no_show_probability) {
+            // Open reporting: first public reporter claims the no-show bond, assumed honest
+            latency += cfg.designated_report_window_hours;
+            truth
+        } else if self.chance(cfg.designated_reporter_honesty) {
+            truth
+        } else {
+            !truth
+        };
+
+        let mut stakes = [0.0; 2];
+        stakes[tentative as usize] = cfg.designated_report_stake;
+        let mut attacker_stakes = [0.0; 2];
+        let mut rounds = 0;
+        let mut forked = false;
+
+        let attacker_active = cfg.attacker_budget > 0.0;
+        let reported = loop {
+            // Escalation that never reaches the fork threshold finalises as it stands
+            if rounds >= MAX_DISPUTE_ROUNDS {
+                break tentative;
+            }
+            latency += cfg.dispute_window_hours;
+            let challenger = !tentative;
+            let bond = Self::dispute_bond(&stakes, challenger);
+
+            // Attacker pushes for the false outcome, honest holders for the truth
+            let disputes = if challenger == truth {
+                self.chance(cfg.honest_dispute_probability)
+            } else if attacker_active && self.attacker_remaining >= bond {
+                self.attacker_remaining -= bond;
+                attacker_stakes[challenger as usize] += bond;
+                true
+            } else {
+                false
+            };
+            if !disputes {
+                break tentative;
+            }
+
+            rounds += 1;
+            if bond >= self.fork_threshold() {
+                forked = true;
+                latency += cfg.fork_duration_hours;
+                break if self.chance(cfg.honest_fork_share) { truth } else { !truth };
+            }
+            stakes[challenger as usize] += bond;
+            tentative = challenger;
+        };
+
+        // Stakes on the losing outcome are forfeited; winners split a share of them
+        let attacker_staked = attacker_stakes[0] + attacker_stakes[1];
+        let losing_stake = stakes[!reported as usize];
+        let attacker_winning = attacker_stakes[reported as usize];
+        let attacker_reward = if stakes[reported as usize] > 0.0 {
+            losing_stake * cfg.dispute_reward_fraction * attacker_winning / stakes[reported as usize]
+        } else {
+            0.0
+        };
+        let attacker_loss = attacker_stakes[!reported as usize] - attacker_reward;
+        self.attacker_remaining += attacker_winning + attacker_reward;
+
+        let result = ResolutionResult {
+            truth,
+            reported,
+            rounds,
+            forked,
+            latency_hours: latency,
+            attacker_staked,
+            attacker_loss,
+        };
+        self.stats.record(&result);
+        result
+    }
+}
+
+impl OutcomeResolver for DisputeSimulator {
+    fn resolve(&mut self, truth: bool) -> bool {
+        self.resolve_outcome(truth).reported
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_rejects_bad_parameters() {
+        assert!(DisputeConfig::default().validate().is_ok());
+        let bad = [
+            DisputeConfig { designated_report_stake: 0.0, ..DisputeConfig::default() },
+            DisputeConfig { rep_supply: f64::NAN, ..DisputeConfig::default() },
+            DisputeConfig { no_show_probability: 1.5, ..DisputeConfig::default() },
+            DisputeConfig { honest_fork_share: -0.1, ..DisputeConfig::default() },
+            DisputeConfig { attacker_budget: -1.0, ..DisputeConfig::default() },
+        ];
+        for config in bad {
+            assert!(DisputeSimulator::new(config).is_err());
+        }
+    }
+
+    #[test]
+    fn escalation_reaches_fork_threshold() {
+        let sim = DisputeSimulator::new(DisputeConfig::default()).unwrap();
+        let cost = sim.cost_to_force_fork().unwrap();
+        assert!(cost > 0.0 && cost < sim.fork_threshold() * 2.0, "{}", cost);
+    }
+
+    #[test]
+    fn mutated_config_cannot_panic_or_spin() {
+        let mut sim = DisputeSimulator::new(DisputeConfig::default()).unwrap();
+        // Zero stake keeps every bond at zero: the fork is never reached
+        sim.config.designated_report_stake = 0.0;
+        sim.config.honest_dispute_probability = 2.0;
+        sim.config.no_show_probability = f64::NAN;
+        assert!(sim.cost_to_force_fork().is_err());
+        let result = sim.resolve_outcome(true);
+        assert!(result.rounds <= MAX_DISPUTE_ROUNDS);
+        assert!(!result.forked);
+    }
+}
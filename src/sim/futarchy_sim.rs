@@ -0,0 +1,240 @@
+// src/sim/futarchy_sim.rs
+//
+// Basic Futarchy Simulator Stub
+// ============================
+//
+// This is a minimal, extensible stub for simulating futarchy governance.
+// Key concepts:
+// - A single measurable metric (e.g., "welfare" or token price).
+// - Proposals create two conditional markets: Pass vs. Reject.
+// - Traders buy/sell shares in markets (simple LMSR-inspired pricing for liquidity).
+// - Decision: Proposal passes if Pass market price > Reject market price.
+// - Post-decision resolution based on simulated "true" outcome.
+//
+// Designed for Eternal Thriving Grandmasterism: modular, safe Rust, easy to expand
+// into full prediction market engine, quadratic integrations, or Grok API hooks.
+//
+// Next steps:
+// - Add real LMSR (Logarithmic Market Scoring Rule) for better liquidity.
+// - Multi-proposal cadence.
+// - Trader strategies (rational, noisy, manipulative).
+// - Visualization hooks (plot market prices over time).
+// - On-chain simulation bridges (Solana/Move later).
+
+use rand::prelude::*;
+use std::collections::HashMap;
+
+use crate::markets::fpmm::{self, FixedProductMarketMaker};
+use crate::types::{Metric, Price, Proposal};
+
+/// Pricing engine behind each conditional market (naive `Market` or a real AMM)
+pub trait MarketBackend {
+    /// Fresh market seeded with `liquidity` collateral
+    fn with_liquidity(liquidity: f64) -> Result<Self, String>
+    where
+        Self: Sized;
+    /// Probability-like price of the market's favoured outcome
+    fn price(&self) -> Price;
+    /// Bet `amount` collateral on the outcome (raises price)
+    fn buy(&mut self, amount: f64) -> Result<(), String>;
+    /// Bet `amount` collateral against the outcome (lowers price)
+    fn sell(&mut self, amount: f64) -> Result<(), String>;
+}
+
+/// Conditional Market (one for Pass, one for Reject)
+#[derive(Debug, Clone)]
+pub struct Market {
+    shares_outstanding: f64, // Total shares in circulation
+    liquidity: f64,          // Constant for simple constant-product feel (expand to LMSR)
+}
+
+impl Market {
+    fn new(initial_liquidity: f64) -> Self {
+        Self {
+            shares_outstanding: 0.0,
+            liquidity: initial_liquidity,
+        }
+    }
+
+    /// Naive pricing: probability = shares / (shares + liquidity)
+    fn price(&self) -> Price {
+        self.shares_outstanding / (self.shares_outstanding + self.liquidity)
+    }
+
+    /// Buy shares (increases price)
+    fn buy(&mut self, amount: f64) {
+        self.shares_outstanding += amount;
+    }
+
+    /// Sell shares (decreases price)
+    fn sell(&mut self, amount: f64) {
+        self.shares_outstanding = (self.shares_outstanding - amount).max(0.0);
+    }
+}
+
+impl MarketBackend for Market {
+    fn with_liquidity(liquidity: f64) -> Result<Self, String> {
+        Ok(Market::new(liquidity))
+    }
+
+    fn price(&self) -> Price {
+        Market::price(self)
+    }
+
+    fn buy(&mut self, amount: f64) -> Result<(), String> {
+        Market::buy(self, amount);
+        Ok(())
+    }
+
+    fn sell(&mut self, amount: f64) -> Result<(), String> {
+        Market::sell(self, amount);
+        Ok(())
+    }
+}
+
+/// Fee charged by simulated FPMM pools (2%, Omen's common default)
+const SIM_FPMM_FEE: u128 = fpmm::ONE / 50;
+
+/// Binary FPMM backend: outcome 0 = metric improves, outcome 1 = it does not
+impl MarketBackend for FixedProductMarketMaker {
+    fn with_liquidity(liquidity: f64) -> Result<Self, String> {
+        let mut pool = FixedProductMarketMaker::new(2, SIM_FPMM_FEE.into())?;
+        pool.add_funding(0, fpmm::from_f64(liquidity), &[])?;
+        Ok(pool)
+    }
+
+    fn price(&self) -> Price {
+        self.prices()[0]
+    }
+
+    fn buy(&mut self, amount: f64) -> Result<(), String> {
+        FixedProductMarketMaker::buy(self, fpmm::from_f64(amount), 0, 0.into()).map(drop)
+    }
+
+    /// Simulated traders hold no inventory, so "selling" buys the opposing outcome
+    fn sell(&mut self, amount: f64) -> Result<(), String> {
+        FixedProductMarketMaker::buy(self, fpmm::from_f64(amount), 1, 0.into()).map(drop)
+    }
+}
+
+/// Resolution step reporting whether a decision's measured outcome was favourable
+pub trait OutcomeResolver: std::fmt::Debug {
+    /// Reported outcome given the true one (may differ if the mechanism is corrupted)
+    fn resolve(&mut self, truth: bool) -> bool;
+}
+
+/// Futarchy Governance Simulator
+#[derive(Debug)]
+pub struct FutarchySimulator<M: MarketBackend = Market> {
+    current_metric: Metric,
+    proposals: Vec<Proposal>,
+    markets: HashMap<u64, (M, M)>, // (Pass, Reject) per proposal
+    resolver: Option<Box<dyn OutcomeResolver>>,
+    reported_outcomes: HashMap<u64, bool>, // Outcome the resolver reported, per proposal
+    rng: ThreadRng,
+}
+
+impl FutarchySimulator<Market> {
+    pub fn new(initial_metric: Metric) -> Self {
+        Self::with_backend(initial_metric)
+    }
+}
+
+impl<M: MarketBackend> FutarchySimulator<M> {
+    /// Simulator whose conditional markets are priced by backend `M`
+    pub fn with_backend(initial_metric: Metric) -> Self {
+        Self {
+            current_metric: initial_metric,
+            proposals: Vec::new(),
+            markets: HashMap::new(),
+            resolver: None,
+            reported_outcomes: HashMap::new(),
+            rng: thread_rng(),
+        }
+    }
+
+    pub fn current_metric(&self) -> Metric {
+        self.current_metric
+    }
+
+    pub fn proposal(&self, proposal_id: u64) -> Option<&Proposal> {
+        self.proposals.get(proposal_id as usize)
+    }
+
+    /// Route outcome measurement through a resolution mechanism (e.g. DisputeSimulator)
+    pub fn set_resolver(&mut self, resolver: Box<dyn OutcomeResolver>) {
+        self.resolver = Some(resolver);
+    }
+
+    /// Outcome reported for a resolved proposal (true = metric improved)
+    pub fn reported_outcome(&self, proposal_id: u64) -> Option<bool> {
+        self.reported_outcomes.get(&proposal_id).copied()
+    }
+
+    /// Submit a new proposal → creates conditional markets
+    pub fn submit_proposal(&mut self, description: String, liquidity: f64) -> Result<u64, String> {
+        let pass_market = M::with_liquidity(liquidity)?;
+        let reject_market = M::with_liquidity(liquidity)?;
+
+        let id = self.proposals.len() as u64;
+        let proposal = Proposal { id, description };
+        self.proposals.push(proposal);
+
+        self.markets.insert(id, (pass_market, reject_market));
+        Ok(id)
+    }
+
+    /// Simulate a trading round (random traders for stub)
+    pub fn simulate_trading(&mut self, proposal_id: u64, rounds: usize) -> Result<(), String> {
+        let (pass, reject) = self.markets.get_mut(&proposal_id)
+            .ok_or_else(|| format!("Proposal not found: {}", proposal_id))?;
+
+        for _ in 0..rounds {
+            // Random trader decides to bet on pass or reject
+            if self.rng.gen_bool(0.5) {
+                let amount: f64 = self.rng.gen_range(1.0..10.0);
+                pass.buy(amount)?;
+            } else {
+                let amount: f64 = self.rng.gen_range(1.0..10.0);
+                reject.buy(amount)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Resolve proposal based on market prices
+    pub fn resolve_proposal(&mut self, proposal_id: u64) -> bool {
+        let (pass, reject) = self.markets.get(&proposal_id).expect("Proposal not found");
+        let pass_price = pass.price();
+        let reject_price = reject.price();
+
+        println!("Proposal {}: Pass price = {:.4}, Reject price = {:.4}", proposal_id, pass_price, reject_price);
+
+        // Decision rule: pass if expected metric higher under pass
+        let passes = pass_price > reject_price;
+
+        // Simulate "true" outcome impact (stub: random +/- based on decision)
+        let impact: f64 = if passes {
+            if self.rng.gen_bool(0.7) { 10.0 } else { -5.0 } // 70% chance good if market favored
+        } else {
+            if self.rng.gen_bool(0.6) { 5.0 } else { -10.0 }
+        };
+
+        self.current_metric += impact;
+
+        // Markets settle on the reported outcome, which a corrupted resolver may get wrong
+        let truth = impact > 0.0;
+        let reported = match self.resolver.as_mut() {
+            Some(resolver) => resolver.resolve(truth),
+            None => truth,
+        };
+        if reported != truth {
+            println!("Proposal {}: resolution mis-reported (true impact {:+.1})", proposal_id, impact);
+        }
+        self.reported_outcomes.insert(proposal_id, reported);
+
+        println!("Proposal {} {}. New metric: {:.2}", proposal_id, if passes { "PASSED" } else { "REJECTED" }, self.current_metric);
+
+        passes
+    }
+}
@@ -1,4 +1,4 @@
-// GrokArena-Pinnacle/src/hybrid_sim.rs
+// src/sim/hybrid_sim.rs
 //
 // Hybrid QV + Futarchy Governance Simulator Stub
 // =============================================
@@ -10,8 +10,9 @@
 // 1. QV Phase: Voters (with strategies, sybil resistance) allocate on metric options.
 //    - Winning metric(s) weighted by net votes (or top one if single-winner).
 // 2. Futarchy Phase: Proposals create conditional markets (Pass/Reject).
-//    - Traders (separate or reused voters) buy/sell shares → prices reflect expected metric impact.
-//    - Policy with higher expected metric wins.
+//    - Traders buy "metric improves" shares in both the if-pass and if-reject markets, leaning
+//      on a belief (adoption share, 0.5 = indifferent) → prices reflect expected metric impact.
+//    - Policy with higher expected metric wins (pass price vs reject price).
 //    - Simulate "true" outcome → update composite metric.
 //    - Simple LMSR-inspired pricing for better liquidity (logarithmic scoring rule stub).
 //
@@ -24,32 +25,14 @@
 // - Advanced trader strategies (mirror QV strategies).
 // - Full StatsCollector integration for both phases.
 // - Sybil resistance applies to both phases.
+//
+// Demo: `cargo run --example hybrid_sim`.
 
 use rand::prelude::*;
 use std::collections::HashMap;
 
-/// Composite welfare metric (starts at 100.0)
-type Metric = f64;
-
-/// Votes / Prices
-type Votes = f64;
-
-/// Issue/Proposal/Metric ID
-type Id = u64;
-
-/// Simple Metric Option (for QV phase)
-#[derive(Debug, Clone)]
-struct MetricOption {
-    id: Id,
-    description: String,
-}
-
-/// Proposal for Futarchy phase
-#[derive(Debug, Clone)]
-struct PolicyProposal {
-    id: Id,
-    description: String,
-}
+use crate::qv::{Strategy, Voter};
+use crate::types::{Credits, Id, Metric, Proposal, Votes};
 
 /// Basic LMSR Market (Logarithmic Market Scoring Rule stub for liquidity)
 #[derive(Debug)]
@@ -68,8 +51,8 @@ impl LMSRMarket {
 
     /// Price for outcome (probability)
     fn price(&self, outcome: Id) -> f64 {
-        let total = self.shares.values().map(|&s| s.exp()).sum::<f64>();
-        self.shares[&outcome].exp() / total
+        let total = self.shares.values().map(|&s| (s / self.b).exp()).sum::<f64>();
+        (self.shares[&outcome] / self.b).exp() / total
     }
 
     /// Buy shares for outcome
@@ -82,14 +65,14 @@ impl LMSRMarket {
 #[derive(Debug)]
 pub struct HybridSimulator {
     // QV components (reusing types from quadratic_voting_sim)
-    metric_options: Vec<MetricOption>,
-    voters: Vec<Voter>, // Reuse Voter + Strategy from QV sim (assume imported or duplicated)
+    metric_options: Vec<Proposal>,
+    voters: Vec<Voter>, // Shared Voter + Strategy from the QV simulator
     qv_tallies: HashMap<Id, Votes>,
     sybil_resistance_enabled: bool,
 
     // Futarchy components
     current_metric: Metric,
-    proposals: Vec<PolicyProposal>,
+    proposals: Vec<Proposal>,
     markets: HashMap<Id, (LMSRMarket, LMSRMarket)>, // Conditional: if_pass, if_reject (or single market)
     rng: ThreadRng,
 }
@@ -111,17 +94,34 @@ impl HybridSimulator {
     /// QV Phase: Add metric options
     pub fn add_metric_option(&mut self, description: String) -> Id {
         let id = self.metric_options.len() as Id;
-        self.metric_options.push(MetricOption { id, description });
+        self.metric_options.push(Proposal { id, description });
         self.qv_tallies.insert(id, 0.0);
         id
     }
 
-    /// Reuse voter addition from QV (assume Strategy enum available)
+    /// QV Phase: Add a voter whose strategy targets metric option IDs
+    pub fn add_voter(&mut self, strategy: Strategy, budget: Credits) -> u64 {
+        let id = self.voters.len() as u64;
+        self.voters.push(Voter::new(id, strategy, budget));
+        id
+    }
+
+    pub fn current_metric(&self) -> Metric {
+        self.current_metric
+    }
 
     /// Run QV phase to select metric weights
     pub fn run_qv_phase(&mut self) -> HashMap<Id, f64> {
-        // Assume vote_according_to_strategy() from QV sim tallies qv_tallies
-        // ... (integrate full QV voting here)
+        let option_ids: Vec<Id> = self.metric_options.iter().map(|o| o.id).collect();
+        for tally in self.qv_tallies.values_mut() {
+            *tally = 0.0;
+        }
+        for voter in &mut self.voters {
+            voter.vote(&option_ids, self.sybil_resistance_enabled, &mut self.rng);
+            for (&option, &votes) in &voter.allocations {
+                *self.qv_tallies.entry(option).or_insert(0.0) += votes;
+            }
+        }
 
         // Normalize to weights (positive only for simplicity)
         let mut weights = HashMap::new();
@@ -139,27 +139,36 @@ impl HybridSimulator {
                 weights.insert(opt.id, 1.0 / n);
             }
         }
-        println!("Selected Metric Weights: {:?}", weights);
+        log::debug!("Selected metric weights: {:?}", weights);
         weights
     }
 
     /// Futarchy Phase: Submit policy proposal
     pub fn submit_proposal(&mut self, description: String, liquidity: f64) -> Id {
         let id = self.proposals.len() as Id;
-        self.proposals.push(PolicyProposal { id, description });
+        self.proposals.push(Proposal { id, description });
         let pass_market = LMSRMarket::new(liquidity);
         let reject_market = LMSRMarket::new(liquidity);
         self.markets.insert(id, (pass_market, reject_market));
         id
     }
 
-    /// Simulate trading on proposal (random for stub, extend with strategies)
-    pub fn simulate_futarchy_trading(&mut self, proposal_id: Id, rounds: usize) {
-        let (pass, _reject) = self.markets.get_mut(&proposal_id).unwrap(); // Simplified single market for binary
+    /// Current (pass, reject) market probabilities for a proposal
+    pub fn prices(&self, proposal_id: Id) -> Option<(f64, f64)> {
+        self.markets.get(&proposal_id).map(|(pass, reject)| (pass.price(1), reject.price(1)))
+    }
+
+    /// Simulate trading on both conditional markets. `belief` is the traders' adoption share
+    /// (0.5 = indifferent): each round one trader per market buys "metric improves" with
+    /// probability `belief` if the proposal passes and `1 - belief` if it is rejected.
+    pub fn simulate_futarchy_trading(&mut self, proposal_id: Id, rounds: usize, belief: f64) {
+        let belief = belief.clamp(0.0, 1.0);
+        let (pass, reject) = self.markets.get_mut(&proposal_id).unwrap();
         for _ in 0..rounds {
-            let outcome = if self.rng.gen_bool(0.6) { 1 } else { 0 }; // Biased belief
-            let amount = self.rng.gen_range(1.0..20.0);
-            pass.buy(outcome, amount);
+            let outcome = if self.rng.gen_bool(belief) { 1 } else { 0 };
+            pass.buy(outcome, self.rng.gen_range(1.0..20.0));
+            let outcome = if self.rng.gen_bool(1.0 - belief) { 1 } else { 0 };
+            reject.buy(outcome, self.rng.gen_range(1.0..20.0));
         }
     }
 
@@ -167,9 +176,9 @@ impl HybridSimulator {
     pub fn resolve_proposal(&mut self, proposal_id: Id, metric_weights: &HashMap<Id, f64>) -> bool {
         let (pass, reject) = self.markets.get(&proposal_id).unwrap();
         let pass_prob = pass.price(1);
-        let reject_prob = reject.price(1); // Simplified
+        let reject_prob = reject.price(1);
 
-        let passes = pass_prob > 0.5; // Or compare expected values
+        let passes = pass_prob > reject_prob;
 
         // Simulate outcome impact on composite metric
         let impact = if passes {
@@ -179,28 +188,10 @@ impl HybridSimulator {
         };
         self.current_metric += impact * metric_weights.values().sum::<f64>();
 
-        println!("Proposal {} {} → New metric: {:.2}", proposal_id, if passes { "PASSED" } else { "REJECTED" }, self.current_metric);
+        log::info!(
+            "Proposal {} {} (pass {:.3} vs reject {:.3}) → new metric: {:.2}",
+            proposal_id, if passes { "passed" } else { "rejected" }, pass_prob, reject_prob, self.current_metric
+        );
         passes
     }
 }
-
-/// Example hybrid run
-fn main() {
-    let mut hybrid = HybridSimulator::new(100.0);
-
-    // QV Phase: Metric options
-    hybrid.add_metric_option("Eternal Thriving Index".to_string());
-    hybrid.add_metric_option("GDP Growth".to_string());
-    hybrid.add_metric_option("Quantum Mercy Balance".to_string());
-
-    // Add voters & run QV (stub – integrate full QV voting)
-
-    let weights = hybrid.run_qv_phase();
-
-    // Futarchy Phase
-    let prop_id = hybrid.submit_proposal("Launch GrokArena Global Council".to_string(), 1000.0);
-    hybrid.simulate_futarchy_trading(prop_id, 200);
-    hybrid.resolve_proposal(prop_id, &weights);
-
-    println!("Final Composite Metric: {:.2}", hybrid.current_metric);
-}
@@ -0,0 +1,6 @@
+//! Simulators — Futarchy, Dispute Resolution and Hybrid QV + Futarchy
+//! Ultramasterful sandboxes; runnable demos live in `examples/`
+
+pub mod dispute_sim;
+pub mod futarchy_sim;
+pub mod hybrid_sim;
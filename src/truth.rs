@@ -0,0 +1,212 @@
+//! Truth Engine — Pluggable Mercy Verification for Every Component
+//! Ultramasterful seam: one shared engine, typed verdicts, no string matching downstream
+//!
+//! `NexiTruth` (feature `nexi`) delegates to the NEXi lattice; `LocalTruth` is a deterministic
+//! lexicon check for offline builds, simulations and tests.
+
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "nexi")]
+use nexi::lattice::Nexus;
+
+/// Typed outcome of a truth / mercy check
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Verdict {
+    pub verified: bool,
+    /// Mercy valence in [0, 1] (1 = fully aligned)
+    pub valence: f64,
+    pub reasons: Vec<String>,
+}
+
+impl Verdict {
+    pub fn verified(valence: f64, reason: &str) -> Self {
+        Self { verified: true, valence, reasons: vec![reason.to_string()] }
+    }
+
+    pub fn rejected(valence: f64, reasons: Vec<String>) -> Self {
+        Self { verified: false, valence, reasons }
+    }
+}
+
+pub trait TruthEngine: Send + Sync {
+    /// Evaluate a claim, proposal, idea or transcript
+    fn evaluate(&self, claim: &str) -> Verdict;
+}
+
+/// The single engine instance shared by every component
+pub type SharedTruth = Arc<dyn TruthEngine>;
+
+/// NEXi lattice-backed engine
+#[cfg(feature = "nexi")]
+pub struct NexiTruth {
+    nexus: Nexus,
+}
+
+#[cfg(feature = "nexi")]
+impl NexiTruth {
+    pub fn new() -> Self {
+        NexiTruth { nexus: Nexus::init_with_mercy() }
+    }
+
+    pub fn shared() -> SharedTruth {
+        Arc::new(Self::new())
+    }
+}
+
+#[cfg(feature = "nexi")]
+impl Default for NexiTruth {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "nexi")]
+impl TruthEngine for NexiTruth {
+    fn evaluate(&self, claim: &str) -> Verdict {
+        // The lattice only speaks strings; this is the one place they are interpreted
+        let distilled = self.nexus.distill_truth(claim);
+        if distilled.contains("Verified") {
+            Verdict::verified(1.0, &distilled)
+        } else {
+            Verdict::rejected(0.0, vec![distilled])
+        }
+    }
+}
+
+/// Lower-cased words of `text`; hyphens and apostrophes stay inside a word ("anti-manipulation")
+pub(crate) fn words(text: &str) -> Vec<String> {
+    text.to_lowercase()
+        .split(|c: char| !(c.is_alphanumeric() || c == '-' || c == '\''))
+        .map(|w| w.trim_matches(|c| c == '-' || c == '\''))
+        .filter(|w| !w.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Whether `words` contain `term`. Term words match whole (a plural "s" is allowed) unless they
+/// end in `*`, which matches a stem ("manipulat*" → "manipulation"); multi-word terms must appear
+/// consecutively
+pub(crate) fn contains_term(words: &[String], term: &str) -> bool {
+    let parts: Vec<&str> = term.split_whitespace().collect();
+    if parts.is_empty() {
+        return false;
+    }
+    let matches = |word: &str, part: &str| match part.strip_suffix('*') {
+        Some(stem) => word.starts_with(stem),
+        None => word == part || word.strip_suffix('s') == Some(part),
+    };
+    words.windows(parts.len())
+        .any(|window| window.iter().zip(&parts).all(|(w, p)| matches(w, p)))
+}
+
+/// Terms that lower valence in the local engine (`contains_term` syntax)
+const DEFAULT_BLOCKED_TERMS: &[&str] = &["scam*", "fraud*", "manipulat*", "rug pull*", "hate*", "threat*", "violence", "dox*"];
+
+/// Deterministic offline engine: each blocked term found costs `penalty` valence, and a claim
+/// is verified only while its valence stays at or above `min_valence`
+pub struct LocalTruth {
+    blocked_terms: Vec<String>,
+    penalty: f64,
+    min_valence: f64,
+}
+
+impl LocalTruth {
+    pub fn new() -> Self {
+        LocalTruth {
+            blocked_terms: DEFAULT_BLOCKED_TERMS.iter().map(|t| t.to_string()).collect(),
+            penalty: 0.5,
+            min_valence: 1.0,
+        }
+    }
+
+    pub fn shared() -> SharedTruth {
+        Arc::new(Self::new())
+    }
+
+    /// Block `term` (whole words, `*` marks a stem)
+    pub fn with_blocked_term(mut self, term: &str) -> Self {
+        self.blocked_terms.push(term.to_lowercase());
+        self
+    }
+
+    pub fn with_min_valence(mut self, min_valence: f64) -> Self {
+        self.min_valence = min_valence;
+        self
+    }
+}
+
+impl Default for LocalTruth {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TruthEngine for LocalTruth {
+    fn evaluate(&self, claim: &str) -> Verdict {
+        if claim.trim().is_empty() {
+            return Verdict::rejected(0.0, vec!["Empty claim".to_string()]);
+        }
+        let words = words(claim);
+        let hits: Vec<String> = self.blocked_terms.iter()
+            .filter(|term| contains_term(&words, term))
+            .map(|term| format!("Contains blocked term: {}", term.trim_end_matches('*')))
+            .collect();
+        let valence = (1.0 - self.penalty * hits.len() as f64).max(0.0);
+        if hits.is_empty() {
+            Verdict::verified(valence, "No blocked terms")
+        } else if valence >= self.min_valence {
+            Verdict { verified: true, valence, reasons: hits }
+        } else {
+            Verdict::rejected(valence, hits)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn blocked_terms_match_on_word_boundaries() {
+        let truth = LocalTruth::new();
+        for clean in [
+            "Whatever happens, fund the commons",
+            "A paradox of thrift",
+            "Ship the anti-manipulation safeguards",
+            "Chatelaine rugged pullover drive",
+        ] {
+            assert!(truth.evaluate(clean).verified, "{}", clean);
+        }
+        for blocked in ["They hate the plan", "Doxxing is banned", "Market manipulation ring", "A rug pulled overnight", "SCAMS everywhere"] {
+            let verdict = truth.evaluate(blocked);
+            assert!(!verdict.verified, "{}", blocked);
+            assert!(verdict.valence < 1.0);
+        }
+    }
+
+    #[test]
+    fn verified_verdicts_carry_full_valence() {
+        let truth = LocalTruth::new();
+        assert_eq!(truth.evaluate("Whatever happens, keep building").valence, 1.0);
+        // Tolerating one hit is opt-in, and then it is verified at the reduced valence
+        let lenient = LocalTruth::new().with_min_valence(0.5);
+        let verdict = lenient.evaluate("A threat to the budget");
+        assert!(verdict.verified);
+        assert_eq!(verdict.valence, 0.5);
+    }
+
+    #[test]
+    fn terms_follow_stem_and_phrase_syntax() {
+        let w = words("Long-term, phased rollout; don't rush");
+        assert_eq!(w, vec!["long-term", "phased", "rollout", "don't", "rush"]);
+        assert!(contains_term(&w, "long-term"));
+        assert!(contains_term(&w, "phase*"));
+        assert!(!contains_term(&w, "phase"));
+        assert!(contains_term(&w, "phased rollout"));
+        assert!(!contains_term(&w, "rollout phased"));
+        assert!(contains_term(&words("two caps"), "cap"));
+        assert!(!contains_term(&words("capital"), "cap"));
+    }
+}
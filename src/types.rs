@@ -0,0 +1,24 @@
+//! Core Types — Shared Across Voting, Futarchy and Simulation
+//! Ultramasterful common vocabulary, defined once
+
+/// Welfare metric a futarchy maximises (e.g. eternal thriving index)
+pub type Metric = f64;
+
+/// Quadratic votes on an issue (fractional, signed)
+pub type Votes = f64;
+
+/// Voice credit budget
+pub type Credits = f64;
+
+/// Share price in a conditional market (0.0 to 1.0 probability)
+pub type Price = f64;
+
+/// Issue / proposal / metric option identifier
+pub type Id = u64;
+
+/// Anything put to a vote or a market: an issue, a policy proposal or a metric option
+#[derive(Debug, Clone)]
+pub struct Proposal {
+    pub id: Id,
+    pub description: String,
+}